@@ -45,8 +45,115 @@ pub static SYMBOL_MAP: phf::Map<&'static str, SymbolDef> = phf_map! {
     // *
     "Asterisk" => SymbolDef::new(&[], 0),
 
-    // <query expression> ::= [ <with clause> ] <query expression body>
-    "QueryExpression" => SymbolDef::new(&["QueryExpressionBody"], 0),
+    /*
+        <query expression> ::=
+            [ <with clause> ] <query expression body>
+            [ <order by clause> ] [ <result offset clause> ] [ <fetch first clause> ]
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        The <order by clause>, <result offset clause> and <fetch first clause> are part of
+        the query expression, as in ISO/IEC 9075-2:2008.
+    */
+    "QueryExpression" => SymbolDef::new(&["QueryExpressionBody"], WITH_HELPERS | WITH_REQUIRED_HELPERS_METHOD),
+
+    /*
+        <with clause> ::= WITH [ RECURSIVE ] <with list>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        The with clause can also prefix the insert, update and delete statements.
+    */
+    "WithClause" => SymbolDef::new(&[], WITH_BLANK_IMPL),
+
+    /*
+        <with list> ::= <with list element> [ { <comma> <with list element> }... ]
+    */
+    "WithList" => SymbolDef::new(&["WithListElement"], WITH_HELPERS),
+
+    /*
+        <with list element> ::=
+            <query name> [ <left paren> <with column list> <right paren> ]
+            AS <left paren> <query expression> <right paren>
+    */
+    "WithListElement" => SymbolDef::new(&[], 0),
+
+    /*
+        <with column list> ::= <column name list>
+    */
+    "WithColumnList" => SymbolDef::new(&["ColumnNameList"], WITH_BLANK_IMPL),
+
+    /*
+        <order by clause> ::= ORDER BY <sort specification list>
+    */
+    "OrderByClause" => SymbolDef::new(&[], WITH_BLANK_IMPL),
+
+    /*
+        <result offset clause> ::= OFFSET <offset row count> { ROW | ROWS }
+    */
+    "ResultOffsetClause" => SymbolDef::new(&[], WITH_BLANK_IMPL | WITH_HELPERS | WITH_REQUIRED_HELPERS_METHOD),
+
+    /*
+        <fetch first clause> ::=
+            FETCH { FIRST | NEXT } [ <fetch first quantity> ] { ROW | ROWS } { ONLY | WITH TIES }
+
+        Difference with the ISO/IEC 9075-2:2008 :
+        The non-standard LIMIT <fetch first row count> [ OFFSET <offset row count> ] is also
+        a fetch first clause.
+    */
+    "FetchFirstClause" => SymbolDef::new(&[], WITH_BLANK_IMPL | WITH_HELPERS | WITH_REQUIRED_HELPERS_METHOD),
+
+    /*
+        <offset row count> ::= <simple value specification>
+    */
+    "OffsetRowCount" => SymbolDef::new(&[
+        "UnsignedNumericLiteral",
+        "DynamicParameterSpecification"
+    ], WITH_BLANK_IMPL),
+
+    /*
+        <fetch first row count> ::= <simple value specification>
+    */
+    "FetchFirstRowCount" => SymbolDef::new(&[
+        "UnsignedNumericLiteral",
+        "DynamicParameterSpecification"
+    ], 0),
+
+    /*
+        <sort specification list> ::= <sort specification> [ { <comma> <sort specification> }... ]
+    */
+    "SortSpecificationList" => SymbolDef::new(&["SortSpecification"], WITH_HELPERS),
+
+    /*
+        <sort specification> ::= <sort key> [ <ordering specification> ] [ <null ordering> ]
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        The <collate clause> of a character sort key is held by the sort specification.
+    */
+    "SortSpecification" => SymbolDef::new(&["SortKey"], 0),
+
+    /*
+        <sort key> ::= <value expression>
+    */
+    "SortKey" => SymbolDef::new(&["ValueExpression"], 0),
+
+    /*
+        <ordering specification> ::= ASC | DESC
+    */
+    "OrderingSpecification" => SymbolDef::new(&[], WITH_BLANK_IMPL),
+
+    /*
+        <null ordering> ::= NULLS FIRST | NULLS LAST
+    */
+    "NullOrdering" => SymbolDef::new(&[], WITH_BLANK_IMPL),
+
+    /*
+        <collate clause> ::= COLLATE <collation name>
+    */
+    "CollateClause" => SymbolDef::new(&[], WITH_BLANK_IMPL),
+
+    /*
+        <collation name> ::= <schema qualified name>
+    */
+    "CollationName" => SymbolDef::new(&["Identifier"], 0),
 
     // <query expression body> ::= <non-join query expression> | <joined table>
     "QueryExpressionBody" => SymbolDef::new(&[
@@ -99,6 +206,27 @@ pub static SYMBOL_MAP: phf::Map<&'static str, SymbolDef> = phf_map! {
     // <join column list> ::= <column name list>
     "JoinColumnList" => SymbolDef::new(&["ColumnNameList"], 0),
 
+    /*
+        <query term> ::= <non-join query term> | <joined table>
+    */
+    "QueryTerm" => SymbolDef::new(&["NonJoinQueryTerm", "JoinedTable"], 0),
+
+    /*
+        <query primary> ::= <non-join query primary> | <joined table>
+    */
+    "QueryPrimary" => SymbolDef::new(&["NonJoinQueryPrimary", "JoinedTable"], 0),
+
+    /*
+        <corresponding spec> ::=
+            CORRESPONDING [ BY <left paren> <corresponding column list> <right paren> ]
+    */
+    "CorrespondingSpec" => SymbolDef::new(&[], WITH_BLANK_IMPL),
+
+    /*
+        <corresponding column list> ::= <column name list>
+    */
+    "CorrespondingColumnList" => SymbolDef::new(&["ColumnNameList"], WITH_BLANK_IMPL),
+
     /*
     * <non-join query expression>    ::=
            <non-join query term>
@@ -118,6 +246,9 @@ pub static SYMBOL_MAP: phf::Map<&'static str, SymbolDef> = phf_map! {
         <non-join query primary> ::=
             <simple table>
             | <left paren> <non-join query expression> <right paren>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        Any query expression can be parenthesized, as in ISO/IEC 9075-2:2008.
     */
     "NonJoinQueryPrimary" => SymbolDef::new(&["SimpleTable"], 0),
 
@@ -165,6 +296,76 @@ pub static SYMBOL_MAP: phf::Map<&'static str, SymbolDef> = phf_map! {
     */
     "GroupByClause" => SymbolDef::new(&[], WITH_BLANK_IMPL),
 
+    /*
+        <grouping element list> ::= <grouping element> [ { <comma> <grouping element> }... ]
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        Also used as the <grouping set list> of a <grouping sets specification>,
+        as <grouping set> has the same productions than <grouping element>.
+    */
+    "GroupingElementList" => SymbolDef::new(&["GroupingElement"], WITH_HELPERS),
+
+    /*
+        <grouping element> ::=
+            <ordinary grouping set>
+            | <rollup list>
+            | <cube list>
+            | <grouping sets specification>
+            | <empty grouping set>
+    */
+    "GroupingElement" => SymbolDef::new(&[
+        "OrdinaryGroupingSet",
+        "RollupList",
+        "CubeList",
+        "GroupingSetsSpecification",
+        "EmptyGroupingSet"
+    ], 0),
+
+    /*
+        <ordinary grouping set list> ::= <ordinary grouping set> [ { <comma> <ordinary grouping set> }... ]
+    */
+    "OrdinaryGroupingSetList" => SymbolDef::new(&["OrdinaryGroupingSet"], WITH_HELPERS),
+
+    /*
+        <ordinary grouping set> ::=
+            <grouping column reference>
+            | <left paren> <grouping column reference list> <right paren>
+    */
+    "OrdinaryGroupingSet" => SymbolDef::new(&["GroupingColumnReference"], 0),
+
+    /*
+        <grouping column reference list> ::= <grouping column reference> [ { <comma> <grouping column reference> }... ]
+    */
+    "GroupingColumnReferenceList" => SymbolDef::new(&["GroupingColumnReference"], WITH_HELPERS),
+
+    /*
+        <grouping column reference> ::= <column reference> [ <collate clause> ]
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        Any <value expression> can be used, as most of the databases allow it.
+    */
+    "GroupingColumnReference" => SymbolDef::new(&["ValueExpression"], 0),
+
+    /*
+        <rollup list> ::= ROLLUP <left paren> <ordinary grouping set list> <right paren>
+    */
+    "RollupList" => SymbolDef::new(&[], 0),
+
+    /*
+        <cube list> ::= CUBE <left paren> <ordinary grouping set list> <right paren>
+    */
+    "CubeList" => SymbolDef::new(&[], 0),
+
+    /*
+        <grouping sets specification> ::= GROUPING SETS <left paren> <grouping set list> <right paren>
+    */
+    "GroupingSetsSpecification" => SymbolDef::new(&[], 0),
+
+    /*
+        <empty grouping set> ::= <left paren> <right paren>
+    */
+    "EmptyGroupingSet" => SymbolDef::new(&[], 0),
+
     /*
         <having clause> ::= HAVING <search condition>
     */
@@ -264,9 +465,179 @@ pub static SYMBOL_MAP: phf::Map<&'static str, SymbolDef> = phf_map! {
             | <numeric value expression> <minus sign> <term>
     */
     "NumericValueExpression" => SymbolDef::new(&["Term"], 0),
-    "StringValueExpression" => SymbolDef::new(&[], 0),
-    "DatetimeValueExpression" => SymbolDef::new(&[], 0),
-    "IntervalValueExpression" => SymbolDef::new(&[], 0),
+    /*
+        <string value expression> ::=
+            <character value expression>
+            | <blob value expression>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        <blob value expression> is not implemented.
+    */
+    "StringValueExpression" => SymbolDef::new(&["CharacterValueExpression"], 0),
+    /*
+        <character value expression> ::=
+            <concatenation>
+            | <character factor>
+    */
+    "CharacterValueExpression" => SymbolDef::new(&["Concatenation", "CharacterFactor"], 0),
+    /*
+        <concatenation> ::= <character value expression> <concatenation operator> <character factor>
+
+        <concatenation operator> ::= ||
+    */
+    "Concatenation" => SymbolDef::new(&[], 0),
+    /*
+        <character factor> ::= <character primary> [ <collate clause> ]
+    */
+    "CharacterFactor" => SymbolDef::new(&["CharacterPrimary"], 0),
+    /*
+        <character primary> ::=
+            <value expression primary>
+            | <string value function>
+    */
+    "CharacterPrimary" => SymbolDef::new(&["ValueExpressionPrimary", "StringValueFunction"], 0),
+    /*
+        <string value function> ::=
+            <character value function>
+            | <blob value function>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        <blob value function> is not implemented.
+    */
+    "StringValueFunction" => SymbolDef::new(&["CharacterValueFunction"], 0),
+    /*
+        <character value function> ::=
+            <character substring function>
+            | <regular expression substring function>
+            | <fold>
+            | <transcoding>
+            | <character transliteration>
+            | <trim function>
+            | <character overlay function>
+            | <normalize function>
+            | <specific type method>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        Only <character substring function>, <fold>, <trim function> and
+        <character overlay function> are implemented.
+    */
+    "CharacterValueFunction" => SymbolDef::new(&[
+        "CharacterSubstringFunction",
+        "Fold",
+        "TrimFunction",
+        "CharacterOverlayFunction"
+    ], 0),
+    /*
+        <character substring function> ::=
+            SUBSTRING ( <character value expression> FROM <start position>
+            [ FOR <string length> ] [ USING <char length units> ] )
+
+        <start position> ::= <numeric value expression>
+    */
+    "CharacterSubstringFunction" => SymbolDef::new(&[], 0),
+    /*
+        <string length> ::= <numeric value expression>
+    */
+    "StringLength" => SymbolDef::new(&["NumericValueExpression"], WITH_BLANK_IMPL),
+    /*
+        <fold> ::= { UPPER | LOWER } ( <character value expression> )
+    */
+    "Fold" => SymbolDef::new(&[], 0),
+    /*
+        <trim function> ::= TRIM ( <trim operands> )
+
+        <trim operands> ::= [ [ <trim specification> ] [ <trim character> ] FROM ] <trim source>
+
+        <trim source> ::= <character value expression>
+
+        <trim specification> ::= LEADING | TRAILING | BOTH
+    */
+    "TrimFunction" => SymbolDef::new(&[], 0),
+    /*
+        <trim character> ::= <character value expression>
+    */
+    "TrimCharacter" => SymbolDef::new(&["CharacterValueExpression"], WITH_BLANK_IMPL),
+    /*
+        <character overlay function> ::=
+            OVERLAY ( <character value expression> PLACING <character value expression>
+            FROM <start position> [ FOR <string length> ] [ USING <char length units> ] )
+    */
+    "CharacterOverlayFunction" => SymbolDef::new(&[], 0),
+    /*
+        <datetime value expression> ::=
+            <datetime term>
+            | <interval value expression> <plus sign> <datetime term>
+            | <datetime value expression> <plus sign> <interval term>
+            | <datetime value expression> <minus sign> <interval term>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        <interval value expression> <plus sign> <datetime term> is not implemented.
+    */
+    "DatetimeValueExpression" => SymbolDef::new(&["DatetimeTerm"], 0),
+    /*
+        <datetime term> ::= <datetime factor>
+    */
+    "DatetimeTerm" => SymbolDef::new(&["DatetimeFactor"], 0),
+    /*
+        <datetime factor> ::= <datetime primary> [ <time zone> ]
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        <time zone> is not implemented.
+    */
+    "DatetimeFactor" => SymbolDef::new(&["DatetimePrimary"], 0),
+    /*
+        <datetime primary> ::=
+            <value expression primary>
+            | <datetime value function>
+    */
+    "DatetimePrimary" => SymbolDef::new(&["ValueExpressionPrimary", "DatetimeValueFunction"], 0),
+    /*
+        <datetime value function> ::=
+            CURRENT_DATE
+            | CURRENT_TIME [ <left paren> <time precision> <right paren> ]
+            | LOCALTIME [ <left paren> <time precision> <right paren> ]
+            | CURRENT_TIMESTAMP [ <left paren> <timestamp precision> <right paren> ]
+            | LOCALTIMESTAMP [ <left paren> <timestamp precision> <right paren> ]
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        The precisions are not implemented.
+    */
+    "DatetimeValueFunction" => SymbolDef::new(&[], 0),
+    /*
+        <interval value expression> ::=
+            <interval term>
+            | <interval value expression 1> <plus sign> <interval term 1>
+            | <interval value expression 1> <minus sign> <interval term 1>
+            | <left paren> <datetime value expression> <minus sign> <datetime term> <right paren> <interval qualifier>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        Only <interval term> is implemented.
+    */
+    "IntervalValueExpression" => SymbolDef::new(&["IntervalTerm"], 0),
+    /*
+        <interval term> ::=
+            <interval factor>
+            | <interval term 2> <asterisk> <factor>
+            | <interval term 2> <solidus> <factor>
+            | <term> <asterisk> <interval factor>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        Only <interval factor> is implemented.
+    */
+    "IntervalTerm" => SymbolDef::new(&["IntervalFactor"], 0),
+    /*
+        <interval factor> ::= [ <sign> ] <interval primary>
+    */
+    "IntervalFactor" => SymbolDef::new(&["IntervalPrimary"], 0),
+    /*
+        <interval primary> ::=
+            <value expression primary> [ <interval qualifier> ]
+            | <interval value function>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        Only <value expression primary> is implemented.
+    */
+    "IntervalPrimary" => SymbolDef::new(&["ValueExpressionPrimary"], 0),
     "UserDefinedTypeValueExpression" => SymbolDef::new(&[], 0),
     "ReferenceValueExpression" => SymbolDef::new(&[], 0),
     "CollectionValueExpression" => SymbolDef::new(&[], 0),
@@ -281,6 +652,8 @@ pub static SYMBOL_MAP: phf::Map<&'static str, SymbolDef> = phf_map! {
 
     /*
         <factor> ::= [ <sign> ] <numeric primary>
+
+        <sign> ::= <plus sign> | <minus sign>
     */
     "Factor" => SymbolDef::new(&["NumericPrimary"], 0),
 
@@ -352,7 +725,7 @@ pub static SYMBOL_MAP: phf::Map<&'static str, SymbolDef> = phf_map! {
     */
     "WidthBucketCount" => SymbolDef::new(&[], 0),
     /*
-        <ceiling function> ::= FLOOR (
+        <ceiling function> ::= { CEIL | CEILING } (
             <numeric value expression>
         )
     */
@@ -417,13 +790,24 @@ pub static SYMBOL_MAP: phf::Map<&'static str, SymbolDef> = phf_map! {
         )
     */
     "ExtractExpression" => SymbolDef::new(&[], 0),
+    /*
+        <extract source> ::=
+            <datetime value expression>
+            | <interval value expression>
+
+        <extract field> ::= <primary datetime field> | <time zone field>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        <time zone field> is not implemented.
+    */
+    "ExtractSource" => SymbolDef::new(&["DatetimeValueExpression", "IntervalValueExpression"], 0),
 
     /*
         <length expression> ::=
             <char length expression>
      |      <octet length expression>
     */
-    "LengthExpression" => SymbolDef::new(&[], 0),
+    "LengthExpression" => SymbolDef::new(&["CharLengthExpression", "OctetLengthExpression"], 0),
 
     /*
         <char length expression> ::=
@@ -547,6 +931,21 @@ pub static SYMBOL_MAP: phf::Map<&'static str, SymbolDef> = phf_map! {
     */
     "AggregateFunction" => SymbolDef::new(&[], 0),
 
+    /*
+        <general set function> ::=
+            <set function type> <left paren> [ <set quantifier> ] <value expression> <right paren>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        The argument of an aggregate function is either a <value expression>
+        or an <asterisk>, COUNT(*) being handled as a <general set function>.
+    */
+    "SetFunctionArgument" => SymbolDef::new(&["ValueExpression", "Asterisk"], 0),
+
+    /*
+        <filter clause> ::= FILTER <left paren> WHERE <search condition> <right paren>
+    */
+    "FilterClause" => SymbolDef::new(&[], WITH_BLANK_IMPL),
+
     /*
         <unsigned value specification> ::=
             <unsigned literal>
@@ -716,7 +1115,9 @@ pub static SYMBOL_MAP: phf::Map<&'static str, SymbolDef> = phf_map! {
             | <boolean literal>
     */
     "GeneralLiteral" => SymbolDef::new(&[
-        "CharacterStringLiteral"
+        "CharacterStringLiteral",
+        "DatetimeLiteral",
+        "IntervalLiteral"
     ], 0),
 
     /*
@@ -727,6 +1128,27 @@ pub static SYMBOL_MAP: phf::Map<&'static str, SymbolDef> = phf_map! {
     */
     "CharacterStringLiteral" => SymbolDef::new(&[], 0),
 
+    /*
+        <datetime literal> ::= <date literal> | <time literal> | <timestamp literal>
+    */
+    "DatetimeLiteral" => SymbolDef::new(&["DateLiteral", "TimeLiteral", "TimestampLiteral"], 0),
+    /*
+        <date literal> ::= DATE <date string>
+    */
+    "DateLiteral" => SymbolDef::new(&[], 0),
+    /*
+        <time literal> ::= TIME <time string>
+    */
+    "TimeLiteral" => SymbolDef::new(&[], 0),
+    /*
+        <timestamp literal> ::= TIMESTAMP <timestamp string>
+    */
+    "TimestampLiteral" => SymbolDef::new(&[], 0),
+    /*
+        <interval literal> ::= INTERVAL [ <sign> ] <interval string> <interval qualifier>
+    */
+    "IntervalLiteral" => SymbolDef::new(&[], 0),
+
 
 
     ///////////////////////////
@@ -831,7 +1253,7 @@ pub static SYMBOL_MAP: phf::Map<&'static str, SymbolDef> = phf_map! {
     /*
         <lateral derived table> ::= LATERAL <table subquery>
     */
-    "LateralDerivedTable" => SymbolDef::new(&["TableSubquery"], 0),
+    "LateralDerivedTable" => SymbolDef::new(&[], 0),
 
     /*
         <collection derived table> ::= UNNEST
@@ -859,8 +1281,22 @@ pub static SYMBOL_MAP: phf::Map<&'static str, SymbolDef> = phf_map! {
 
     /*
         <derived table> ::= <table subquery>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        The derived table holds its [ AS ] <correlation name> [ <left paren> <derived column list> <right paren> ],
+        as a table subquery is not a table primary without them.
      */
-    "DerivedTable" => SymbolDef::new(&["TableSubquery"], 0),
+    "DerivedTable" => SymbolDef::new(&[], 0),
+
+    /*
+        <correlation name> ::= <identifier>
+    */
+    "CorrelationName" => SymbolDef::new(&["Identifier"], 0),
+
+    /*
+        <derived column list> ::= <column name list>
+    */
+    "DerivedColumnList" => SymbolDef::new(&["ColumnNameList"], WITH_BLANK_IMPL),
     /*
         <table subquery> ::= <subquery>
      */
@@ -907,6 +1343,12 @@ pub static SYMBOL_MAP: phf::Map<&'static str, SymbolDef> = phf_map! {
         "FromConstructor",
         "FromDefault"
     ], WITH_BLANK_IMPL),
+    /*
+        <from subquery> ::=
+            [ <left paren> <insert column list> <right paren> ]
+            [ <override clause> ]
+            <query expression>
+    */
     "FromSubQuery" => SymbolDef::new(&[], 0),
     "FromConstructor" => SymbolDef::new(&[
         "ContextuallyTypedTableValueConstructor"
@@ -916,8 +1358,50 @@ pub static SYMBOL_MAP: phf::Map<&'static str, SymbolDef> = phf_map! {
     "OverridingUserValue" => SymbolDef::new(&[], 0),
     "OverridingSystemValue" => SymbolDef::new(&[], 0),
 
+    /*
+        <from default> ::= DEFAULT VALUES
+    */
     "FromDefault" => SymbolDef::new(&[], 0),
 
+    /*
+        <returning clause> ::= RETURNING <select list>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        The returning clause is not part of the standard, it is supported by PostgreSQL and SQLite
+        to return the inserted, updated or deleted rows.
+    */
+    "ReturningClause" => SymbolDef::new(&[], WITH_BLANK_IMPL),
+
+    /*
+        <update statement: searched> ::=
+            UPDATE <target table>
+            SET <set clause list>
+            [ WHERE <search condition> ]
+    */
+    "UpdateStatement" => SymbolDef::new(&[], WITH_HELPERS | WITH_REQUIRED_HELPERS_METHOD),
+
+    /*
+        <delete statement: searched> ::=
+            DELETE FROM <target table>
+            [ WHERE <search condition> ]
+    */
+    "DeleteStatement" => SymbolDef::new(&[], WITH_HELPERS | WITH_REQUIRED_HELPERS_METHOD),
+
+    /*
+        <set clause list> ::= <set clause> [ { <comma> <set clause> }... ]
+    */
+    "SetClauseList" => SymbolDef::new(&["SetClause"], 0),
+
+    /*
+        <set clause> ::= <update target> <equals operator> <update source>
+    */
+    "SetClause" => SymbolDef::new(&[], 0),
+
+    /*
+        <update source> ::= <value expression> | <contextually typed value specification>
+    */
+    "UpdateSource" => SymbolDef::new(&["ValueExpression", "ContextuallyTypedValueSpecification"], 0),
+
     "ContextuallyTypedTableValueConstructor" => SymbolDef::new(&["ContextuallyTypedRowValueExpressionList"], 0),
     "ContextuallyTypedRowValueExpressionList" => SymbolDef::new(&["ContextuallyTypedRowValueExpression"], WITH_HELPERS),
     "ContextuallyTypedRowValueExpression" => SymbolDef::new(&[
@@ -935,6 +1419,7 @@ pub static SYMBOL_MAP: phf::Map<&'static str, SymbolDef> = phf_map! {
     "ContextuallyTypedRowValueConstructor" => SymbolDef::new(&[
         "CommonValueExpression",
         "BooleanValueExpression",
+        "ContextuallyTypedValueSpecification",
         "ContextuallyTypedRowValueConstructorElementList"
     ], 0),
 
@@ -953,9 +1438,25 @@ pub static SYMBOL_MAP: phf::Map<&'static str, SymbolDef> = phf_map! {
             | <contextually typed value specification>
     */
     "ContextuallyTypedRowValueConstructorElement" => SymbolDef::new(&[
-        "ValueExpression"
+        "ValueExpression",
+        "ContextuallyTypedValueSpecification"
     ], 0),
 
+    /*
+        <contextually typed value specification> ::=
+            <implicitly typed value specification>
+            | <default specification>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        - <implicitly typed value specification> (NULL, ARRAY[], MULTISET[]) is not supported.
+    */
+    "ContextuallyTypedValueSpecification" => SymbolDef::new(&["DefaultSpecification"], 0),
+
+    /*
+        <default specification> ::= DEFAULT
+    */
+    "DefaultSpecification" => SymbolDef::new(&[], 0),
+
     "ValueSpecification" => SymbolDef::new(&[], 0),
 
     /*
@@ -1062,36 +1563,85 @@ pub static SYMBOL_MAP: phf::Map<&'static str, SymbolDef> = phf_map! {
         <between predicate> ::=
             <row value predicand>
             <between predicate part 2>
+
+        <between predicate part 2> ::=
+            [ NOT ] BETWEEN [ ASYMMETRIC | SYMMETRIC ]
+            <row value predicand> AND <row value predicand>
     */
     "BetweenPredicate" => SymbolDef::new(&[], 0),
     /*
         <in predicate> ::=
             <row value predicand>
             <in predicate part 2>
+
+        <in predicate part 2> ::= [ NOT ] IN <in predicate value>
     */
     "InPredicate" => SymbolDef::new(&[], 0),
+    /*
+        <in predicate value> ::=
+            <table subquery>
+            | <left paren> <in value list> <right paren>
+    */
+    "InPredicateValue" => SymbolDef::new(&["TableSubquery"], 0),
+    /*
+        <in value list> ::=
+            <row value expression> [ { <comma> <row value expression> }... ]
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        Recursive <in value list> chaining, the elements are <row value predicand>.
+    */
+    "InValueList" => SymbolDef::new(&["RowValuePredicand"], WITH_HELPERS),
     /*
         <like predicate> ::=
             <character like predicate>
             | <octet like predicate>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        <octet like predicate> is not implemented.
+    */
+    "LikePredicate" => SymbolDef::new(&["CharacterLikePredicate"], 0),
+    /*
+        <character like predicate> ::=
+            <row value predicand>
+            [ NOT ] LIKE <character pattern> [ ESCAPE <escape character> ]
+
+        <character pattern> ::= <character value expression>
     */
-    "LikePredicate" => SymbolDef::new(&[], 0),
+    "CharacterLikePredicate" => SymbolDef::new(&[], 0),
+    /*
+        <escape character> ::= <character value expression>
+    */
+    "EscapeCharacter" => SymbolDef::new(&["CharacterValueExpression"], WITH_BLANK_IMPL),
     /*
         <similar predicate> ::=
             <row value predicand>
             <similar predicate part 2>
+
+        <similar predicate part 2> ::=
+            [ NOT ] SIMILAR TO <similar pattern> [ ESCAPE <escape character> ]
+
+        <similar pattern> ::= <character value expression>
     */
     "SimilarPredicate" => SymbolDef::new(&[], 0),
     /*
         <null predicate> ::=
             <row value predicand>
             <null predicate part 2>
+
+        <null predicate part 2> ::= IS [ NOT ] NULL
     */
     "NullPredicate" => SymbolDef::new(&[], 0),
     /*
         <quantified comparison predicate> ::=
             <row value predicand>
             <quantified comparison predicate part 2>
+
+        <quantified comparison predicate part 2> ::=
+            <comp op> <quantifier> <table subquery>
+
+        <quantifier> ::= <all> | <some>
+        <all> ::= ALL
+        <some> ::= SOME | ANY
     */
     "QuantifiedComparisonPredicate" => SymbolDef::new(&[], 0),
     /*
@@ -1125,6 +1675,12 @@ pub static SYMBOL_MAP: phf::Map<&'static str, SymbolDef> = phf_map! {
         <distinct predicate> ::=
             <row value predicand 3>
             <distinct predicate part 2>
+
+        <distinct predicate part 2> ::=
+            IS [ NOT ] DISTINCT FROM <row value predicand 4>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        <row value predicand 3> and <row value predicand 4> are <row value predicand>.
     */
     "DistinctPredicate" => SymbolDef::new(&[], 0),
     /*
@@ -1161,7 +1717,7 @@ pub static SYMBOL_MAP: phf::Map<&'static str, SymbolDef> = phf_map! {
     "RowValuePredicand" => SymbolDef::new(&[
         "RowValueSpecialCase",
         "RowValueConstructorPredicand"
-    ], 0),
+    ], WITH_HELPERS),
 
     /*
         <row value special case> ::=
@@ -1209,16 +1765,181 @@ pub static SYMBOL_MAP: phf::Map<&'static str, SymbolDef> = phf_map! {
     "RowValueConstructorElement" => SymbolDef::new(&[], 0),
     "RowValueExpression" => SymbolDef::new(&[], 0),
 
+    /*
+        <window function> ::= <window function type> OVER <window name or specification>
+    */
     "WindowFunction" => SymbolDef::new(&[], 0),
+    /*
+        <window function type> ::=
+            <rank function type> <left paren> <right paren>
+            | ROW_NUMBER <left paren> <right paren>
+            | <aggregate function>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        LAG and LEAD (ISO/IEC 9075-2:2011) are window function types.
+    */
+    "WindowFunctionType" => SymbolDef::new(&["AggregateFunction"], WITH_HELPERS),
+    /*
+        <lead or lag function> ::=
+            <lead or lag> <left paren> <lead or lag extent>
+            [ <comma> <offset> [ <comma> <default expression> ] ] <right paren>
+    */
+    "LeadOrLagDefault" => SymbolDef::new(&["ValueExpression"], WITH_BLANK_IMPL),
+    /*
+        <window name or specification> ::= <window name> | <in-line window specification>
+    */
+    "WindowNameOrSpecification" => SymbolDef::new(&["WindowName", "WindowSpecification"], 0),
+    /*
+        <window name> ::= <identifier>
+    */
+    "WindowName" => SymbolDef::new(&["Identifier"], 0),
+    /*
+        <window specification> ::= <left paren> <window specification details> <right paren>
+
+        <window specification details> ::=
+            [ <existing window name> ]
+            [ <window partition clause> ]
+            [ <window order clause> ]
+            [ <window frame clause> ]
+
+        <window order clause> ::= ORDER BY <sort specification list>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        <window order clause> is an <order by clause>.
+    */
+    "WindowSpecification" => SymbolDef::new(&[], 0),
+    /*
+        <existing window name> ::= <window name>
+    */
+    "ExistingWindowName" => SymbolDef::new(&["WindowName"], WITH_BLANK_IMPL),
+    /*
+        <window partition clause> ::= PARTITION BY <window partition column reference list>
+    */
+    "WindowPartitionClause" => SymbolDef::new(&[], WITH_BLANK_IMPL),
+    /*
+        <window partition column reference list> ::=
+            <window partition column reference> [ { <comma> <window partition column reference> }... ]
+
+        <window partition column reference> ::= <column reference> [ <collate clause> ]
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        Recursive <window partition column reference list> chaining.
+    */
+    "WindowPartitionColumnReferenceList" => SymbolDef::new(&["ColumnReference"], WITH_HELPERS),
+    /*
+        <window frame clause> ::=
+            <window frame units> <window frame extent> [ <window frame exclusion> ]
+    */
+    "WindowFrameClause" => SymbolDef::new(&[], WITH_BLANK_IMPL),
+    /*
+        <window clause> ::= WINDOW <window definition list>
+    */
+    "WindowClause" => SymbolDef::new(&[], WITH_BLANK_IMPL),
+    /*
+        <window definition list> ::= <window definition> [ { <comma> <window definition> }... ]
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        Recursive <window definition list> chaining.
+    */
+    "WindowDefinitionList" => SymbolDef::new(&["WindowDefinition"], WITH_HELPERS),
+    /*
+        <window definition> ::= <new window name> AS <window specification>
+    */
+    "WindowDefinition" => SymbolDef::new(&[], 0),
     "NonparenthesizedValueExpressionPrimary" => SymbolDef::new(&[], 0),
     "DynamicParameterSpecification" => SymbolDef::new(&[], 0),
-    "QueryName" => SymbolDef::new(&[], 0),
+    /*
+        <query name> ::= <identifier>
+    */
+    "QueryName" => SymbolDef::new(&["Identifier"], 0),
     "CurrentDefaultTransformGroup" => SymbolDef::new(&[], 0),
     "GroupingOperation" => SymbolDef::new(&[], 0),
     "SystemUser" => SymbolDef::new(&[], 0),
-    "ScalarSubquery" => SymbolDef::new(&[], 0),
-    "CaseExpression" => SymbolDef::new(&[], 0),
+    /*
+        <scalar subquery> ::= <subquery>
+    */
+    "ScalarSubquery" => SymbolDef::new(&["Subquery"], 0),
+    /*
+        <case expression> ::= <case abbreviation> | <case specification>
+    */
+    "CaseExpression" => SymbolDef::new(&["CaseAbbreviation", "CaseSpecification"], 0),
+    /*
+        <case abbreviation> ::=
+            NULLIF <left paren> <value expression> <comma> <value expression> <right paren>
+            | COALESCE <left paren> <value expression> { <comma> <value expression> }... <right paren>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        The arguments of COALESCE are a <SQL argument list>.
+    */
+    "CaseAbbreviation" => SymbolDef::new(&[], 0),
+    /*
+        <case specification> ::= <simple case> | <searched case>
+    */
+    "CaseSpecification" => SymbolDef::new(&["SimpleCase", "SearchedCase"], 0),
+    /*
+        <simple case> ::= CASE <case operand> <simple when clause>... [ <else clause> ] END
+
+        <case operand> ::= <row value predicand> | <overlaps predicate part 1>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        <case operand> is a <row value predicand>.
+    */
+    "SimpleCase" => SymbolDef::new(&[], 0),
+    /*
+        <searched case> ::= CASE <searched when clause>... [ <else clause> ] END
+    */
+    "SearchedCase" => SymbolDef::new(&[], 0),
+    /*
+        Difference with the ISO/IEC 9075-2:2003 :
+        Recursive <simple when clause> chaining.
+    */
+    "SimpleWhenClauseList" => SymbolDef::new(&["SimpleWhenClause"], 0),
+    /*
+        <simple when clause> ::= WHEN <when operand> THEN <result>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        <when operand> is a <row value predicand>, <result> is a <value expression>.
+    */
+    "SimpleWhenClause" => SymbolDef::new(&[], 0),
+    /*
+        Difference with the ISO/IEC 9075-2:2003 :
+        Recursive <searched when clause> chaining.
+    */
+    "SearchedWhenClauseList" => SymbolDef::new(&["SearchedWhenClause"], 0),
+    /*
+        <searched when clause> ::= WHEN <search condition> THEN <result>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        <result> is a <value expression>.
+    */
+    "SearchedWhenClause" => SymbolDef::new(&[], 0),
+    /*
+        <else clause> ::= ELSE <result>
+    */
+    "ElseClause" => SymbolDef::new(&[], WITH_BLANK_IMPL),
+    /*
+        <cast specification> ::=
+            CAST <left paren> <cast operand> AS <cast target> <right paren>
+
+        <cast operand> ::= <value expression> | <implicitly typed value specification>
+
+        Difference with the ISO/IEC 9075-2:2003 :
+        <cast operand> is a <value expression>.
+    */
     "CastSpecification" => SymbolDef::new(&[], 0),
+    /*
+        <cast target> ::= <domain name> | <data type>
+    */
+    "CastTarget" => SymbolDef::new(&["DataType"], 0),
+    /*
+        <data type> ::=
+            <predefined type>
+            | <row type>
+            | <path-resolved user-defined type name>
+            | <reference type>
+            | <collection type>
+    */
+    "DataType" => SymbolDef::new(&[], 0),
     "FieldReference" => SymbolDef::new(&[], 0),
     "SubtypeTreatment" => SymbolDef::new(&[], 0),
     "MethodInvocation" => SymbolDef::new(&[], 0),
@@ -1,15 +1,7 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 use quote::ToTokens;
-use quote::TokenStreamExt;
-use regex::Regex;
-use syn::{parse::Parse, punctuated::Punctuated, Expr, Token};
-
-fn check_if_valid_identifer(id: &str) -> bool {
-    let re = Regex::new("^[A-Za-z_]([A-Za-z0-9_])*").unwrap();
-
-    re.is_match(id)
-}
+use syn::{parse::Parse, punctuated::Punctuated, Token};
 
 /// A SQL identifier.
 pub struct Identifier(syn::Ident);
@@ -1,11 +1,15 @@
 mod column_name_list;
 mod identifier_chain;
+mod in_value_list;
+mod literal;
 mod row_value;
 mod select_sublist;
 
 use column_name_list::ColumnNameList;
 use identifier_chain::IdentifierChain;
+use in_value_list::InValueList;
 use itertools::Itertools;
+use literal::Literal;
 use proc_macro::{self, TokenStream};
 use proc_macro2::Span;
 use quote::{quote, ToTokens as _};
@@ -61,46 +65,14 @@ pub fn bind(input: TokenStream) -> TokenStream {
 
 #[proc_macro]
 /// Creates an SQL literal.
+///
+/// Datetime and interval literals are prefixed with their type, e.g.
+/// `lit!(DATE "2024-02-29")` or `lit!(INTERVAL "1 12:00:00" DAY TO SECOND)`.
 pub fn lit(input: TokenStream) -> TokenStream {
-    let lit: syn::Lit = parse_macro_input!(input);
-    match lit {
-        syn::Lit::Str(lit) => quote! {
-            sql_builder::char_str_lit(#lit)
-        },
-        syn::Lit::Float(lit) => {
-            if let Ok(double) = lit.base10_parse::<f64>() {
-                if double.is_sign_positive() {
-                    quote! {
-                        sql_builder::unsigned_numeric_lit(#double)
-                    }
-                } else {
-                    quote! {
-                        sql_builder::signed_numeric_lit(#double)
-                    }
-                }
-            } else {
-                unreachable!()
-            }
-        }
-        syn::Lit::Int(lit) => {
-            if let Ok(unsigned_int) = lit.base10_parse::<u64>() {
-                quote! {
-                    sql_builder::unsigned_numeric_lit(#unsigned_int)
-                }
-            } else if let Ok(signed_int) = lit.base10_parse::<i64>() {
-                quote! {
-                    sql_builder::signed_numeric_lit(#signed_int)
-                }
-            } else {
-                unreachable!()
-            }
-        }
-        _ => quote! {
-            compile_error!("literal not implemented yet")
-        },
-    }
-    .into()
+    let lit: Literal = parse_macro_input!(input);
+    quote! {#lit}.into()
 }
+
 #[proc_macro]
 /// Construct a row value based on a list of element.
 pub fn row_value(input: TokenStream) -> TokenStream {
@@ -122,6 +94,13 @@ pub fn select_columns(input: TokenStream) -> TokenStream {
     sublist.to_token_stream().into()
 }
 
+#[proc_macro]
+/// Generates a list of values for an IN predicate.
+pub fn in_values(input: TokenStream) -> TokenStream {
+    let list: InValueList = parse_macro_input!(input);
+    list.to_token_stream().into()
+}
+
 #[proc_macro]
 /// Checks if the implemented grammar has loops.
 /// Used for debugging.
@@ -202,7 +181,10 @@ pub fn derive_either(input: TokenStream) -> TokenStream {
             let symbol_ident = Ident::new(key, Span::call_site());
 
             let body_impl = if flags.with_blank_impl() {
-                quote! {const IS_IMPL: bool = true;}
+                quote! {
+                    const IS_IMPL: bool = <Lhs as crate::grammar:: #symbol_ident>::IS_IMPL
+                        || <Rhs as crate::grammar:: #symbol_ident>::IS_IMPL;
+                }
             } else {
                 quote! {}
             };
@@ -0,0 +1,181 @@
+use quote::quote;
+use quote::ToTokens;
+use syn::{parse::Parse, Ident, LitStr};
+
+/// An SQL literal, either a Rust literal or a typed string.
+///
+/// # Syntax
+/// ```text
+/// lit!(10)
+/// lit!("text")
+/// lit!(DATE "2024-02-29")
+/// lit!(TIME "12:30:00")
+/// lit!(TIMESTAMP "2024-02-29 12:30:00")
+/// lit!(INTERVAL "1 12:00:00" DAY TO SECOND)
+/// ```
+pub enum Literal {
+    Lit(syn::Lit),
+    Date(LitStr),
+    Time(LitStr),
+    Timestamp(LitStr),
+    Interval(LitStr, IntervalQualifier),
+}
+
+pub struct IntervalQualifier {
+    start: DatetimeField,
+    end: Option<DatetimeField>,
+}
+
+/// A datetime field, with its rank from the most (YEAR) to the least
+/// (SECOND) significant.
+pub struct DatetimeField(Ident, u8);
+
+impl Parse for DatetimeField {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let field: Ident = input.parse()?;
+
+        let (variant, rank) = match field.to_string().as_str() {
+            "YEAR" => ("Year", 0),
+            "MONTH" => ("Month", 1),
+            "DAY" => ("Day", 2),
+            "HOUR" => ("Hour", 3),
+            "MINUTE" => ("Minute", 4),
+            "SECOND" => ("Second", 5),
+            _ => {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "expecting YEAR, MONTH, DAY, HOUR, MINUTE or SECOND",
+                ))
+            }
+        };
+
+        Ok(Self(Ident::new(variant, field.span()), rank))
+    }
+}
+
+impl ToTokens for DatetimeField {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let variant = &self.0;
+        tokens.extend(quote! {
+            sql_builder::data_type::DatetimeField::#variant
+        });
+    }
+}
+
+impl Parse for IntervalQualifier {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let start: DatetimeField = input.parse()?;
+
+        let end = if input.peek(Ident) {
+            let to: Ident = input.parse()?;
+
+            if to != "TO" {
+                return Err(syn::Error::new(to.span(), "expecting TO"));
+            }
+
+            let end: DatetimeField = input.parse()?;
+
+            if start.1 >= end.1 || (start.1 < 2) != (end.1 < 2) {
+                return Err(syn::Error::new(
+                    end.0.span(),
+                    "expecting YEAR TO MONTH, or a range of day-time fields from the most to the least significant",
+                ));
+            }
+
+            Some(end)
+        } else {
+            None
+        };
+
+        Ok(Self { start, end })
+    }
+}
+
+impl ToTokens for IntervalQualifier {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let start = &self.start;
+
+        tokens.extend(match &self.end {
+            Some(end) => quote! {
+                sql_builder::data_type::IntervalQualifier::range(#start, #end).unwrap()
+            },
+            None => quote! {
+                sql_builder::data_type::IntervalQualifier::single(#start)
+            },
+        });
+    }
+}
+
+impl Parse for Literal {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if !input.peek(Ident) {
+            return Ok(Self::Lit(input.parse()?));
+        }
+
+        let kind: Ident = input.parse()?;
+
+        match kind.to_string().as_str() {
+            "DATE" => Ok(Self::Date(input.parse()?)),
+            "TIME" => Ok(Self::Time(input.parse()?)),
+            "TIMESTAMP" => Ok(Self::Timestamp(input.parse()?)),
+            "INTERVAL" => Ok(Self::Interval(input.parse()?, input.parse()?)),
+            _ => Err(syn::Error::new(
+                kind.span(),
+                "expecting DATE, TIME, TIMESTAMP or INTERVAL",
+            )),
+        }
+    }
+}
+
+impl ToTokens for Literal {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        tokens.extend(match self {
+            Self::Lit(syn::Lit::Str(lit)) => quote! {
+                sql_builder::char_str_lit(#lit)
+            },
+            Self::Lit(syn::Lit::Float(lit)) => {
+                if let Ok(double) = lit.base10_parse::<f64>() {
+                    if double.is_sign_positive() {
+                        quote! {
+                            sql_builder::unsigned_numeric_lit(#double)
+                        }
+                    } else {
+                        quote! {
+                            sql_builder::signed_numeric_lit(#double)
+                        }
+                    }
+                } else {
+                    unreachable!()
+                }
+            }
+            Self::Lit(syn::Lit::Int(lit)) => {
+                if let Ok(unsigned_int) = lit.base10_parse::<u64>() {
+                    quote! {
+                        sql_builder::unsigned_numeric_lit(#unsigned_int)
+                    }
+                } else if let Ok(signed_int) = lit.base10_parse::<i64>() {
+                    quote! {
+                        sql_builder::signed_numeric_lit(#signed_int)
+                    }
+                } else {
+                    unreachable!()
+                }
+            }
+            Self::Lit(_) => quote! {
+                compile_error!("literal not implemented yet")
+            },
+            Self::Date(value) => quote! {
+                sql_builder::date_lit(#value)
+            },
+            Self::Time(value) => quote! {
+                sql_builder::time_lit(#value)
+            },
+            Self::Timestamp(value) => quote! {
+                sql_builder::timestamp_lit(#value)
+            },
+            Self::Interval(value, qualifier) => quote! {
+                sql_builder::interval_lit(#value, #qualifier)
+            },
+        });
+    }
+}
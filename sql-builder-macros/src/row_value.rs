@@ -16,7 +16,7 @@ impl ToTokens for RowValue {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         if self.0.len() == 1 {
             let lhs = self.0.first().unwrap();
-            tokens.extend(quote! {#lhs});
+            tokens.extend(quote! {#lhs.into_row_value()});
         } else if self.0.len() > 1 {
             let lhs = self.0.first().unwrap();
             let rhs = self.0[1..]
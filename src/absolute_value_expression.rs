@@ -0,0 +1,52 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::AbsoluteValueExpression;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, AbsoluteValueExpression)]
+/// ABS(<numeric value expression>)
+///
+/// See [self::abs]
+pub struct Abs<Value>
+where
+    Value: G::NumericValueExpression,
+{
+    value: Value,
+}
+
+impl<Value> ::std::fmt::Display for Abs<Value>
+where
+    Value: G::NumericValueExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ABS({})", self.value)
+    }
+}
+
+impl<'q, DB, Value> ToQuery<'q, DB> for Abs<Value>
+where
+    DB: Database,
+    Value: G::NumericValueExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "ABS(")?;
+        self.value.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Returns the absolute value of a number.
+///
+/// # Example
+/// ```
+/// use sql_builder::{abs, id, sub, prelude::*};
+///
+/// let expr = abs(sub(id!(expected), id!(actual)));
+/// assert_eq!(expr.to_string(), "ABS(expected - actual)");
+/// ```
+pub fn abs<Value>(value: Value) -> Abs<Value>
+where
+    Value: G::NumericValueExpression,
+{
+    Abs { value }
+}
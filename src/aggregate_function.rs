@@ -0,0 +1,220 @@
+use crate::{
+    blank::Blank, filter_clause::Filter, grammar as G, select::SetQuantifier, Database, ToQuery,
+};
+use sql_builder_macros::AggregateFunction;
+use std::fmt::Write;
+
+#[derive(Clone, Copy)]
+/// <set function type>
+pub enum SetFunctionType {
+    Count,
+    Sum,
+    Avg,
+    Min,
+    Max,
+    Every,
+}
+
+impl AsRef<str> for SetFunctionType {
+    fn as_ref(&self) -> &str {
+        match self {
+            SetFunctionType::Count => "COUNT",
+            SetFunctionType::Sum => "SUM",
+            SetFunctionType::Avg => "AVG",
+            SetFunctionType::Min => "MIN",
+            SetFunctionType::Max => "MAX",
+            SetFunctionType::Every => "EVERY",
+        }
+    }
+}
+
+impl std::fmt::Display for SetFunctionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Clone, Copy, AggregateFunction)]
+/// <set function type> ([ <set quantifier> ] <value expression>) [ <filter clause> ]
+///
+/// See [self::count], [self::sum], [self::avg], [self::min], [self::max] and [self::every]
+pub struct Aggregate<Arg, FilterCl>
+where
+    Arg: G::SetFunctionArgument,
+    FilterCl: G::FilterClause,
+{
+    function: SetFunctionType,
+    quantifier: Option<SetQuantifier>,
+    arg: Arg,
+    filter: FilterCl,
+}
+
+impl<Arg, FilterCl> Aggregate<Arg, FilterCl>
+where
+    Arg: G::ValueExpression,
+    FilterCl: G::FilterClause,
+{
+    /// Aggregate only the distinct values.
+    ///
+    /// Only available on a value expression argument, COUNT(DISTINCT *) is not valid SQL.
+    ///
+    /// ```compile_fail
+    /// use sql_builder::{asterisk::Asterisk, count};
+    ///
+    /// count(Asterisk).distinct();
+    /// ```
+    pub fn distinct(self) -> Self {
+        Self {
+            quantifier: Some(SetQuantifier::Distinct),
+            ..self
+        }
+    }
+
+    /// Aggregate all the values, including duplicates.
+    pub fn all(self) -> Self {
+        Self {
+            quantifier: Some(SetQuantifier::All),
+            ..self
+        }
+    }
+}
+
+impl<Arg, FilterCl> Aggregate<Arg, FilterCl>
+where
+    Arg: G::SetFunctionArgument,
+    FilterCl: G::FilterClause,
+{
+    /// Aggregate only the rows matching the condition.
+    pub fn filter<Cond>(self, condition: Cond) -> Aggregate<Arg, Filter<Cond>>
+    where
+        Cond: G::SearchCondition,
+    {
+        Aggregate {
+            function: self.function,
+            quantifier: self.quantifier,
+            arg: self.arg,
+            filter: Filter::new(condition),
+        }
+    }
+}
+
+impl<Arg, FilterCl> ::std::fmt::Display for Aggregate<Arg, FilterCl>
+where
+    Arg: G::SetFunctionArgument + std::fmt::Display,
+    FilterCl: G::FilterClause + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}(", self.function)?;
+
+        if let Some(quantifier) = &self.quantifier {
+            write!(f, "{} ", quantifier)?;
+        }
+
+        write!(f, "{})", self.arg)?;
+
+        if FilterCl::IS_IMPL {
+            write!(f, " {}", self.filter)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'q, DB, Arg, FilterCl> ToQuery<'q, DB> for Aggregate<Arg, FilterCl>
+where
+    DB: Database,
+    Arg: G::SetFunctionArgument + ToQuery<'q, DB>,
+    FilterCl: G::FilterClause + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "{}(", self.function)?;
+
+        if let Some(quantifier) = &self.quantifier {
+            write!(ctx, "{} ", quantifier)?;
+        }
+
+        self.arg.write(ctx)?;
+        write!(ctx, ")")?;
+
+        if FilterCl::IS_IMPL {
+            write!(ctx, " ")?;
+            self.filter.write(ctx)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn aggregate<Arg>(function: SetFunctionType, arg: Arg) -> Aggregate<Arg, Blank>
+where
+    Arg: G::SetFunctionArgument,
+{
+    Aggregate {
+        function,
+        quantifier: None,
+        arg,
+        filter: Blank,
+    }
+}
+
+#[inline]
+/// Counts the rows, or the non-null values.
+///
+/// # Example
+/// ```
+/// use sql_builder::{asterisk::Asterisk, count, id, prelude::*};
+///
+/// assert_eq!(count(Asterisk).to_string(), "COUNT(*)");
+/// assert_eq!(count(id!(email)).distinct().to_string(), "COUNT(DISTINCT email)");
+/// ```
+pub fn count<Arg>(arg: Arg) -> Aggregate<Arg, Blank>
+where
+    Arg: G::SetFunctionArgument,
+{
+    aggregate(SetFunctionType::Count, arg)
+}
+
+#[inline]
+/// Sums the values.
+pub fn sum<Arg>(arg: Arg) -> Aggregate<Arg, Blank>
+where
+    Arg: G::ValueExpression,
+{
+    aggregate(SetFunctionType::Sum, arg)
+}
+
+#[inline]
+/// Averages the values.
+pub fn avg<Arg>(arg: Arg) -> Aggregate<Arg, Blank>
+where
+    Arg: G::ValueExpression,
+{
+    aggregate(SetFunctionType::Avg, arg)
+}
+
+#[inline]
+/// Returns the minimum value.
+pub fn min<Arg>(arg: Arg) -> Aggregate<Arg, Blank>
+where
+    Arg: G::ValueExpression,
+{
+    aggregate(SetFunctionType::Min, arg)
+}
+
+#[inline]
+/// Returns the maximum value.
+pub fn max<Arg>(arg: Arg) -> Aggregate<Arg, Blank>
+where
+    Arg: G::ValueExpression,
+{
+    aggregate(SetFunctionType::Max, arg)
+}
+
+#[inline]
+/// Checks if the condition is true for all the values.
+pub fn every<Arg>(arg: Arg) -> Aggregate<Arg, Blank>
+where
+    Arg: G::ValueExpression,
+{
+    aggregate(SetFunctionType::Every, arg)
+}
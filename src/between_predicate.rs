@@ -0,0 +1,165 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::BetweenPredicate;
+use std::fmt::Write;
+
+#[derive(Clone, Copy)]
+/// Symmetry of the BETWEEN range.
+pub enum BetweenSymmetry {
+    /// The lower bound must come first.
+    Asymmetric,
+    /// The bounds are swapped if the lower bound is greater than the upper bound.
+    Symmetric,
+}
+
+impl AsRef<str> for BetweenSymmetry {
+    fn as_ref(&self) -> &str {
+        match self {
+            BetweenSymmetry::Asymmetric => "ASYMMETRIC",
+            BetweenSymmetry::Symmetric => "SYMMETRIC",
+        }
+    }
+}
+
+impl std::fmt::Display for BetweenSymmetry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Clone, Copy, BetweenPredicate)]
+/// <row value predicand> [ NOT ] BETWEEN [ ASYMMETRIC | SYMMETRIC ] <row value predicand> AND <row value predicand>
+///
+/// See [self::between] and [self::not_between]
+pub struct Between<Value, Low, High>
+where
+    Value: G::RowValuePredicand,
+    Low: G::RowValuePredicand,
+    High: G::RowValuePredicand,
+{
+    value: Value,
+    low: Low,
+    high: High,
+    negated: bool,
+    symmetry: Option<BetweenSymmetry>,
+}
+
+impl<Value, Low, High> Between<Value, Low, High>
+where
+    Value: G::RowValuePredicand,
+    Low: G::RowValuePredicand,
+    High: G::RowValuePredicand,
+{
+    /// The bounds can be given in any order.
+    pub fn symmetric(self) -> Self {
+        Self {
+            symmetry: Some(BetweenSymmetry::Symmetric),
+            ..self
+        }
+    }
+
+    /// The lower bound must be given first.
+    pub fn asymmetric(self) -> Self {
+        Self {
+            symmetry: Some(BetweenSymmetry::Asymmetric),
+            ..self
+        }
+    }
+}
+
+impl<Value, Low, High> ::std::fmt::Display for Between<Value, Low, High>
+where
+    Value: G::RowValuePredicand + std::fmt::Display,
+    Low: G::RowValuePredicand + std::fmt::Display,
+    High: G::RowValuePredicand + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ", self.value)?;
+
+        if self.negated {
+            write!(f, "NOT ")?;
+        }
+
+        write!(f, "BETWEEN ")?;
+
+        if let Some(symmetry) = &self.symmetry {
+            write!(f, "{} ", symmetry)?;
+        }
+
+        write!(f, "{} AND {}", self.low, self.high)
+    }
+}
+
+impl<'q, DB, Value, Low, High> ToQuery<'q, DB> for Between<Value, Low, High>
+where
+    DB: Database,
+    Value: G::RowValuePredicand + ToQuery<'q, DB>,
+    Low: G::RowValuePredicand + ToQuery<'q, DB>,
+    High: G::RowValuePredicand + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        self.value.write(ctx)?;
+        write!(ctx, " ")?;
+
+        if self.negated {
+            write!(ctx, "NOT ")?;
+        }
+
+        write!(ctx, "BETWEEN ")?;
+
+        if let Some(symmetry) = &self.symmetry {
+            write!(ctx, "{} ", symmetry)?;
+        }
+
+        self.low.write(ctx)?;
+        write!(ctx, " AND ")?;
+        self.high.write(ctx)
+    }
+}
+
+#[inline]
+/// Checks if the value is within the range, bounds included
+///
+/// # SQL
+/// ```sql
+/// <value> BETWEEN <low> AND <high>
+/// ```
+pub fn between<Value, Low, High>(value: Value, low: Low, high: High) -> Between<Value, Low, High>
+where
+    Value: G::RowValuePredicand,
+    Low: G::RowValuePredicand,
+    High: G::RowValuePredicand,
+{
+    Between {
+        value,
+        low,
+        high,
+        negated: false,
+        symmetry: None,
+    }
+}
+
+#[inline]
+/// Checks if the value is outside the range
+///
+/// # SQL
+/// ```sql
+/// <value> NOT BETWEEN <low> AND <high>
+/// ```
+pub fn not_between<Value, Low, High>(
+    value: Value,
+    low: Low,
+    high: High,
+) -> Between<Value, Low, High>
+where
+    Value: G::RowValuePredicand,
+    Low: G::RowValuePredicand,
+    High: G::RowValuePredicand,
+{
+    Between {
+        value,
+        low,
+        high,
+        negated: true,
+        symmetry: None,
+    }
+}
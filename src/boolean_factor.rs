@@ -29,6 +29,9 @@ where
 }
 
 #[inline]
-pub fn not(value: impl G::BooleanTest) -> impl G::BooleanFactor {
+pub fn not<BoolTest>(value: BoolTest) -> Not<BoolTest>
+where
+    BoolTest: G::BooleanTest,
+{
     Not(value)
 }
@@ -0,0 +1,104 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::CaseAbbreviation;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, CaseAbbreviation)]
+/// NULLIF(<value expression>, <value expression>)
+///
+/// See [self::nullif]
+pub struct NullIf<Lhs, Rhs>
+where
+    Lhs: G::ValueExpression,
+    Rhs: G::ValueExpression,
+{
+    lhs: Lhs,
+    rhs: Rhs,
+}
+
+impl<Lhs, Rhs> ::std::fmt::Display for NullIf<Lhs, Rhs>
+where
+    Lhs: G::ValueExpression + std::fmt::Display,
+    Rhs: G::ValueExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NULLIF({}, {})", self.lhs, self.rhs)
+    }
+}
+
+impl<'q, DB, Lhs, Rhs> ToQuery<'q, DB> for NullIf<Lhs, Rhs>
+where
+    DB: Database,
+    Lhs: G::ValueExpression + ToQuery<'q, DB>,
+    Rhs: G::ValueExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "NULLIF(")?;
+        self.lhs.write(ctx)?;
+        write!(ctx, ", ")?;
+        self.rhs.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[derive(Clone, Copy, CaseAbbreviation)]
+/// COALESCE(<value expression>, ...)
+///
+/// See [self::coalesce]
+pub struct Coalesce<Values>(Values)
+where
+    Values: G::SQLArgumentList;
+
+impl<Values> ::std::fmt::Display for Coalesce<Values>
+where
+    Values: G::SQLArgumentList + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "COALESCE({})", self.0)
+    }
+}
+
+impl<'q, DB, Values> ToQuery<'q, DB> for Coalesce<Values>
+where
+    DB: Database,
+    Values: G::SQLArgumentList + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "COALESCE(")?;
+        self.0.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Returns null if both values are equals, the first value otherwise.
+///
+/// # SQL
+/// ```sql
+/// NULLIF(<lhs>, <rhs>)
+/// ```
+pub fn nullif<Lhs, Rhs>(lhs: Lhs, rhs: Rhs) -> NullIf<Lhs, Rhs>
+where
+    Lhs: G::ValueExpression,
+    Rhs: G::ValueExpression,
+{
+    NullIf { lhs, rhs }
+}
+
+#[inline]
+/// Returns the first non-null value.
+///
+/// # Example
+/// ```
+/// use sql_builder::{coalesce, id, lit, prelude::*};
+///
+/// let expr = coalesce(id!(nickname).add_sql_argument(id!(name)).add_sql_argument(lit!("anonymous")));
+///
+/// let sql = expr.to_string();
+/// assert_eq!(sql, "COALESCE(nickname, name, 'anonymous')");
+/// ```
+pub fn coalesce<Values>(values: Values) -> Coalesce<Values>
+where
+    Values: G::SQLArgumentList,
+{
+    Coalesce(values)
+}
@@ -0,0 +1,59 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::CastSpecification;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, CastSpecification)]
+/// CAST(<cast operand> AS <cast target>)
+///
+/// See [self::cast]
+pub struct Cast<Operand, Target>
+where
+    Operand: G::ValueExpression,
+    Target: G::CastTarget,
+{
+    operand: Operand,
+    target: Target,
+}
+
+impl<Operand, Target> ::std::fmt::Display for Cast<Operand, Target>
+where
+    Operand: G::ValueExpression + std::fmt::Display,
+    Target: G::CastTarget + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CAST({} AS {})", self.operand, self.target)
+    }
+}
+
+impl<'q, DB, Operand, Target> ToQuery<'q, DB> for Cast<Operand, Target>
+where
+    DB: Database,
+    Operand: G::ValueExpression + ToQuery<'q, DB>,
+    Target: G::CastTarget + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "CAST(")?;
+        self.operand.write(ctx)?;
+        write!(ctx, " AS ")?;
+        self.target.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Converts the value into the target data type.
+///
+/// # Example
+/// ```
+/// use sql_builder::{cast, data_type::DataType, id, prelude::*};
+///
+/// let expr = cast(id!(price), DataType::numeric(10, 2));
+/// assert_eq!(expr.to_string(), "CAST(price AS NUMERIC(10, 2))");
+/// ```
+pub fn cast<Operand, Target>(operand: Operand, target: Target) -> Cast<Operand, Target>
+where
+    Operand: G::ValueExpression,
+    Target: G::CastTarget,
+{
+    Cast { operand, target }
+}
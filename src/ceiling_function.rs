@@ -0,0 +1,44 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::CeilingFunction;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, CeilingFunction)]
+/// CEILING(<numeric value expression>)
+///
+/// See [self::ceiling]
+pub struct Ceiling<Value>
+where
+    Value: G::NumericValueExpression,
+{
+    value: Value,
+}
+
+impl<Value> ::std::fmt::Display for Ceiling<Value>
+where
+    Value: G::NumericValueExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CEILING({})", self.value)
+    }
+}
+
+impl<'q, DB, Value> ToQuery<'q, DB> for Ceiling<Value>
+where
+    DB: Database,
+    Value: G::NumericValueExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "CEILING(")?;
+        self.value.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Returns the smallest integer greater than or equal to a number.
+pub fn ceiling<Value>(value: Value) -> Ceiling<Value>
+where
+    Value: G::NumericValueExpression,
+{
+    Ceiling { value }
+}
@@ -0,0 +1,44 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::CharLengthExpression;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, CharLengthExpression)]
+/// CHAR_LENGTH(<string value expression>)
+///
+/// See [self::char_length]
+pub struct CharLength<Value>
+where
+    Value: G::StringValueExpression,
+{
+    value: Value,
+}
+
+impl<Value> ::std::fmt::Display for CharLength<Value>
+where
+    Value: G::StringValueExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CHAR_LENGTH({})", self.value)
+    }
+}
+
+impl<'q, DB, Value> ToQuery<'q, DB> for CharLength<Value>
+where
+    DB: Database,
+    Value: G::StringValueExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "CHAR_LENGTH(")?;
+        self.value.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Returns the number of characters of the string.
+pub fn char_length<Value>(value: Value) -> CharLength<Value>
+where
+    Value: G::StringValueExpression,
+{
+    CharLength { value }
+}
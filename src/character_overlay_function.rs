@@ -0,0 +1,92 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::CharacterOverlayFunction;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, CharacterOverlayFunction)]
+/// OVERLAY(<character value expression> PLACING <character value expression> FROM <start position> [ FOR <string length> ])
+///
+/// See [self::overlay]
+pub struct Overlay<Value, Placing, Start, Length>
+where
+    Value: G::CharacterValueExpression,
+    Placing: G::CharacterValueExpression,
+    Start: G::NumericValueExpression,
+    Length: G::StringLength,
+{
+    value: Value,
+    placing: Placing,
+    start: Start,
+    length: Length,
+}
+
+impl<Value, Placing, Start, Length> ::std::fmt::Display for Overlay<Value, Placing, Start, Length>
+where
+    Value: G::CharacterValueExpression + std::fmt::Display,
+    Placing: G::CharacterValueExpression + std::fmt::Display,
+    Start: G::NumericValueExpression + std::fmt::Display,
+    Length: G::StringLength + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "OVERLAY({} PLACING {} FROM {}",
+            self.value, self.placing, self.start
+        )?;
+
+        if Length::IS_IMPL {
+            write!(f, " FOR {}", self.length)?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+impl<'q, DB, Value, Placing, Start, Length> ToQuery<'q, DB>
+    for Overlay<Value, Placing, Start, Length>
+where
+    DB: Database,
+    Value: G::CharacterValueExpression + ToQuery<'q, DB>,
+    Placing: G::CharacterValueExpression + ToQuery<'q, DB>,
+    Start: G::NumericValueExpression + ToQuery<'q, DB>,
+    Length: G::StringLength + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "OVERLAY(")?;
+        self.value.write(ctx)?;
+        write!(ctx, " PLACING ")?;
+        self.placing.write(ctx)?;
+        write!(ctx, " FROM ")?;
+        self.start.write(ctx)?;
+
+        if Length::IS_IMPL {
+            write!(ctx, " FOR ")?;
+            self.length.write(ctx)?;
+        }
+
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Replaces the characters from the start position with the placed string,
+/// `length` of them if the length is not [crate::blank::Blank], as many as
+/// the placed characters otherwise.
+pub fn overlay<Value, Placing, Start, Length>(
+    value: Value,
+    placing: Placing,
+    start: Start,
+    length: Length,
+) -> Overlay<Value, Placing, Start, Length>
+where
+    Value: G::CharacterValueExpression,
+    Placing: G::CharacterValueExpression,
+    Start: G::NumericValueExpression,
+    Length: G::StringLength,
+{
+    Overlay {
+        value,
+        placing,
+        start,
+        length,
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::CharacterSubstringFunction;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, CharacterSubstringFunction)]
+/// SUBSTRING(<character value expression> FROM <start position> [ FOR <string length> ])
+///
+/// See [self::substring]
+pub struct Substring<Value, Start, Length>
+where
+    Value: G::CharacterValueExpression,
+    Start: G::NumericValueExpression,
+    Length: G::StringLength,
+{
+    value: Value,
+    start: Start,
+    length: Length,
+}
+
+impl<Value, Start, Length> ::std::fmt::Display for Substring<Value, Start, Length>
+where
+    Value: G::CharacterValueExpression + std::fmt::Display,
+    Start: G::NumericValueExpression + std::fmt::Display,
+    Length: G::StringLength + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SUBSTRING({} FROM {}", self.value, self.start)?;
+
+        if Length::IS_IMPL {
+            write!(f, " FOR {}", self.length)?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+impl<'q, DB, Value, Start, Length> ToQuery<'q, DB> for Substring<Value, Start, Length>
+where
+    DB: Database,
+    Value: G::CharacterValueExpression + ToQuery<'q, DB>,
+    Start: G::NumericValueExpression + ToQuery<'q, DB>,
+    Length: G::StringLength + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "SUBSTRING(")?;
+        self.value.write(ctx)?;
+        write!(ctx, " FROM ")?;
+        self.start.write(ctx)?;
+
+        if Length::IS_IMPL {
+            write!(ctx, " FOR ")?;
+            self.length.write(ctx)?;
+        }
+
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Extracts the characters from the start position, at most `length` of
+/// them if the length is not [crate::blank::Blank].
+///
+/// # Example
+/// ```
+/// use sql_builder::{blank::Blank, id, lit, substring, prelude::*};
+///
+/// assert_eq!(substring(id!(code), lit!(1), lit!(3)).to_string(), "SUBSTRING(code FROM 1 FOR 3)");
+/// assert_eq!(substring(id!(code), lit!(4), Blank).to_string(), "SUBSTRING(code FROM 4)");
+/// ```
+pub fn substring<Value, Start, Length>(
+    value: Value,
+    start: Start,
+    length: Length,
+) -> Substring<Value, Start, Length>
+where
+    Value: G::CharacterValueExpression,
+    Start: G::NumericValueExpression,
+    Length: G::StringLength,
+{
+    Substring {
+        value,
+        start,
+        length,
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::CollateClause;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, CollateClause)]
+/// COLLATE <collation name>
+pub struct Collate<Name>(Name)
+where
+    Name: G::CollationName;
+
+impl<Name> Collate<Name>
+where
+    Name: G::CollationName,
+{
+    pub fn new(name: Name) -> Self {
+        Self(name)
+    }
+}
+
+impl<Name> ::std::fmt::Display for Collate<Name>
+where
+    Name: G::CollationName + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "COLLATE {}", self.0)
+    }
+}
+
+impl<'q, DB, Name> ToQuery<'q, DB> for Collate<Name>
+where
+    DB: Database,
+    Name: G::CollationName + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "COLLATE ")?;
+        self.0.write(ctx)
+    }
+}
@@ -3,7 +3,8 @@ use sql_builder_macros::ComparisonPredicate;
 use std::fmt::Write;
 
 #[derive(Clone, Copy)]
-enum ComparisonKind {
+/// <comp op> ::= = | <> | < | > | <= | >=
+pub enum ComparisonKind {
     Equals,
     NotEquals,
     LessThan,
@@ -0,0 +1,57 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::Concatenation;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, Concatenation)]
+/// <character value expression> || <character factor>
+///
+/// See [self::concat]
+pub struct Concat<Lhs, Rhs>
+where
+    Lhs: G::CharacterValueExpression,
+    Rhs: G::CharacterFactor,
+{
+    lhs: Lhs,
+    rhs: Rhs,
+}
+
+impl<Lhs, Rhs> ::std::fmt::Display for Concat<Lhs, Rhs>
+where
+    Lhs: G::CharacterValueExpression + std::fmt::Display,
+    Rhs: G::CharacterFactor + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} || {}", self.lhs, self.rhs)
+    }
+}
+
+impl<'q, DB, Lhs, Rhs> ToQuery<'q, DB> for Concat<Lhs, Rhs>
+where
+    DB: Database,
+    Lhs: G::CharacterValueExpression + ToQuery<'q, DB>,
+    Rhs: G::CharacterFactor + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        self.lhs.write(ctx)?;
+        write!(ctx, " || ")?;
+        self.rhs.write(ctx)
+    }
+}
+
+#[inline]
+/// Concatenates two character strings.
+///
+/// # Example
+/// ```
+/// use sql_builder::{concat, id, lit, prelude::*};
+///
+/// let expr = concat(concat(id!(first_name), lit!(" ")), id!(last_name));
+/// assert_eq!(expr.to_string(), "first_name || ' ' || last_name");
+/// ```
+pub fn concat<Lhs, Rhs>(lhs: Lhs, rhs: Rhs) -> Concat<Lhs, Rhs>
+where
+    Lhs: G::CharacterValueExpression,
+    Rhs: G::CharacterFactor,
+{
+    Concat { lhs, rhs }
+}
@@ -44,3 +44,199 @@ where
         self.tail.write(ctx)
     }
 }
+
+/// A row of values known at runtime, each value is bound to its own dynamic
+/// parameter.
+///
+/// Implemented for tuples of up to 12 values.
+pub trait BoundRow {
+    /// The number of values in the row.
+    const LEN: usize;
+}
+
+/// Write the values of the row as arguments of the query, separated by a comma.
+///
+/// # Example
+/// ```
+/// use sql_builder::{contextually_typed_row_value_expression_list::{BoundRow, BoundRowArguments}, Database, ToQueryContext};
+/// use std::fmt::Write;
+///
+/// struct User {
+///     name: String,
+///     age: i32,
+/// }
+///
+/// impl BoundRow for User {
+///     const LEN: usize = 2;
+/// }
+///
+/// impl<'q, DB> BoundRowArguments<'q, DB> for User
+/// where
+///     DB: Database,
+///     String: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+///     i32: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+/// {
+///     fn write_arguments(&'q self, ctx: &mut ToQueryContext<'q, DB>) -> std::fmt::Result {
+///         ctx.write_argument(&self.name)?;
+///         write!(ctx, ", ")?;
+///         ctx.write_argument(&self.age)
+///     }
+/// }
+/// ```
+pub trait BoundRowArguments<'q, DB>: BoundRow
+where
+    DB: Database,
+{
+    fn write_arguments(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result;
+}
+
+macro_rules! impl_bound_row_for_tuple {
+    ($len:literal; $first:ident $(, $rest:ident)*) => {
+        impl<$first $(, $rest)*> BoundRow for ($first, $($rest,)*) {
+            const LEN: usize = $len;
+        }
+
+        impl<'q, DB, $first $(, $rest)*> BoundRowArguments<'q, DB> for ($first, $($rest,)*)
+        where
+            DB: Database,
+            $first: ::sqlx::Encode<'q, DB> + ::sqlx::Type<DB>,
+            $($rest: ::sqlx::Encode<'q, DB> + ::sqlx::Type<DB>,)*
+        {
+            #[allow(non_snake_case)]
+            fn write_arguments(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+                let ($first, $($rest,)*) = self;
+                ctx.write_argument($first)?;
+                $(
+                    write!(ctx, ", ")?;
+                    ctx.write_argument($rest)?;
+                )*
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_bound_row_for_tuple!(1; T1);
+impl_bound_row_for_tuple!(2; T1, T2);
+impl_bound_row_for_tuple!(3; T1, T2, T3);
+impl_bound_row_for_tuple!(4; T1, T2, T3, T4);
+impl_bound_row_for_tuple!(5; T1, T2, T3, T4, T5);
+impl_bound_row_for_tuple!(6; T1, T2, T3, T4, T5, T6);
+impl_bound_row_for_tuple!(7; T1, T2, T3, T4, T5, T6, T7);
+impl_bound_row_for_tuple!(8; T1, T2, T3, T4, T5, T6, T7, T8);
+impl_bound_row_for_tuple!(9; T1, T2, T3, T4, T5, T6, T7, T8, T9);
+impl_bound_row_for_tuple!(10; T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
+impl_bound_row_for_tuple!(11; T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
+impl_bound_row_for_tuple!(12; T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
+
+#[derive(Clone, ContextuallyTypedRowValueExpressionList)]
+/// (?, ...), (?, ...), ...
+///
+/// A list of rows known at runtime, each value is bound to its own dynamic
+/// parameter.
+///
+/// See [crate::insert::InsertFromConstructorFragment::values_from_iter]
+pub struct BoundRows<Row>
+where
+    Row: BoundRow,
+{
+    rows: Vec<Row>,
+}
+
+impl<Row> BoundRows<Row>
+where
+    Row: BoundRow,
+{
+    /// Returns None if there is no row, an empty list is not valid SQL.
+    pub fn new(rows: impl IntoIterator<Item = Row>) -> Option<Self> {
+        let rows: Vec<Row> = rows.into_iter().collect();
+
+        if rows.is_empty() {
+            return None;
+        }
+
+        Some(Self { rows })
+    }
+
+    /// The number of rows.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Always false, there is at least one row.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Split the rows in lists, each binding at most `max_parameters` values.
+    ///
+    /// Fails if a single row binds more values than the limit.
+    pub fn batches(self, max_parameters: usize) -> Result<Vec<Self>, crate::Error> {
+        if Row::LEN > max_parameters {
+            return Err(crate::Error::too_many_parameters(format!(
+                "a row binds {} values, the limit is {}",
+                Row::LEN,
+                max_parameters
+            )));
+        }
+
+        let rows_per_batch = (max_parameters / Row::LEN.max(1)).max(1);
+        let mut batches = Vec::with_capacity(self.rows.len().div_ceil(rows_per_batch));
+        let mut rows = self.rows.into_iter().peekable();
+
+        while rows.peek().is_some() {
+            batches.push(Self {
+                rows: rows.by_ref().take(rows_per_batch).collect(),
+            });
+        }
+
+        Ok(batches)
+    }
+}
+
+impl<Row> std::fmt::Display for BoundRows<Row>
+where
+    Row: BoundRow,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for i in 0..self.rows.len() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "(")?;
+
+            for j in 0..Row::LEN {
+                if j > 0 {
+                    write!(f, ", ")?;
+                }
+
+                write!(f, "?")?;
+            }
+
+            write!(f, ")")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'q, DB, Row> ToQuery<'q, DB> for BoundRows<Row>
+where
+    DB: Database,
+    Row: BoundRowArguments<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> ::std::fmt::Result {
+        for (i, row) in self.rows.iter().enumerate() {
+            if i > 0 {
+                write!(ctx, ", ")?;
+            }
+
+            write!(ctx, "(")?;
+            row.write_arguments(ctx)?;
+            write!(ctx, ")")?;
+        }
+
+        Ok(())
+    }
+}
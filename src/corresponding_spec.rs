@@ -0,0 +1,54 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::CorrespondingSpec;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, CorrespondingSpec)]
+/// CORRESPONDING [ BY (<corresponding column list>) ]
+pub struct Corresponding<Columns>
+where
+    Columns: G::CorrespondingColumnList,
+{
+    columns: Columns,
+}
+
+impl<Columns> Corresponding<Columns>
+where
+    Columns: G::CorrespondingColumnList,
+{
+    pub fn new(columns: Columns) -> Self {
+        Self { columns }
+    }
+}
+
+impl<Columns> ::std::fmt::Display for Corresponding<Columns>
+where
+    Columns: G::CorrespondingColumnList + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CORRESPONDING")?;
+
+        if Columns::IS_IMPL {
+            write!(f, " BY ({})", self.columns)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'q, DB, Columns> ToQuery<'q, DB> for Corresponding<Columns>
+where
+    DB: Database,
+    Columns: G::CorrespondingColumnList + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "CORRESPONDING")?;
+
+        if Columns::IS_IMPL {
+            write!(ctx, " BY (")?;
+            self.columns.write(ctx)?;
+            write!(ctx, ")")?;
+        }
+
+        Ok(())
+    }
+}
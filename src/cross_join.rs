@@ -1,3 +1,4 @@
+use crate::{blank::Blank, helpers as H, query_expression::QueryExpr};
 use crate::{grammar as G, ToQuery};
 use sql_builder_macros::CrossJoin;
 use std::fmt::Write;
@@ -44,3 +45,44 @@ where
         self.rhs.write(ctx)
     }
 }
+
+impl<Lhs, Rhs> H::QueryExpression for CrossJoin<Lhs, Rhs>
+where
+    Lhs: G::TableReference,
+    Rhs: G::TablePrimary,
+{
+    type WithClause = Blank;
+    type Body = Self;
+    type OrderByClause = Blank;
+    type ResultOffsetClause = Blank;
+    type FetchFirstClause = Blank;
+    type IntoQueryExpressionBody = Self;
+    type IntoQueryTerm = Self;
+    type IntoQueryPrimary = Self;
+
+    #[inline]
+    fn unwrap(self) -> QueryExpr<Blank, Self, Blank, Blank, Blank> {
+        QueryExpr {
+            with_clause: Blank,
+            body: self,
+            order_by: Blank,
+            offset: Blank,
+            fetch: Blank,
+        }
+    }
+
+    #[inline]
+    fn into_query_expression_body(self) -> Self {
+        self
+    }
+
+    #[inline]
+    fn into_query_term(self) -> Self {
+        self
+    }
+
+    #[inline]
+    fn into_query_primary(self) -> Self {
+        self
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::CubeList;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, CubeList)]
+/// CUBE <left paren> <ordinary grouping set list> <right paren>
+pub struct Cube<List>(List)
+where
+    List: G::OrdinaryGroupingSetList;
+
+impl<List> ::std::fmt::Display for Cube<List>
+where
+    List: G::OrdinaryGroupingSetList + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CUBE({})", self.0)
+    }
+}
+
+impl<'q, DB, List> ToQuery<'q, DB> for Cube<List>
+where
+    DB: Database,
+    List: G::OrdinaryGroupingSetList + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "CUBE(")?;
+        self.0.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Groups the rows on every combination of the grouping sets.
+pub fn cube<List>(list: List) -> Cube<List>
+where
+    List: G::OrdinaryGroupingSetList,
+{
+    Cube(list)
+}
@@ -0,0 +1,326 @@
+//! The SQL data types.
+use crate::{Database, ToQuery};
+use sql_builder_macros::DataType;
+use std::fmt::Write;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// A field of a datetime or interval value, from the most to the least significant.
+pub enum DatetimeField {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl AsRef<str> for DatetimeField {
+    fn as_ref(&self) -> &str {
+        match self {
+            DatetimeField::Year => "YEAR",
+            DatetimeField::Month => "MONTH",
+            DatetimeField::Day => "DAY",
+            DatetimeField::Hour => "HOUR",
+            DatetimeField::Minute => "MINUTE",
+            DatetimeField::Second => "SECOND",
+        }
+    }
+}
+
+impl DatetimeField {
+    fn is_year_month(self) -> bool {
+        matches!(self, DatetimeField::Year | DatetimeField::Month)
+    }
+}
+
+impl std::fmt::Display for DatetimeField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// <interval qualifier> ::= <start field> TO <end field> | <single datetime field>
+pub struct IntervalQualifier {
+    pub(crate) start: DatetimeField,
+    pub(crate) end: Option<DatetimeField>,
+}
+
+impl IntervalQualifier {
+    /// A single datetime field, e.g. DAY.
+    pub fn single(field: DatetimeField) -> Self {
+        Self {
+            start: field,
+            end: None,
+        }
+    }
+
+    /// A range of datetime fields, e.g. DAY TO SECOND.
+    ///
+    /// The start field must be more significant than the end field, and
+    /// both must be either year-month fields or day-time fields.
+    pub fn range(start: DatetimeField, end: DatetimeField) -> Result<Self, crate::Error> {
+        if start >= end || start.is_year_month() != end.is_year_month() {
+            return Err(crate::Error::invalid_interval_qualifier(format!(
+                "{} TO {}",
+                start, end
+            )));
+        }
+
+        Ok(Self {
+            start,
+            end: Some(end),
+        })
+    }
+
+    /// The start field.
+    pub fn start(&self) -> DatetimeField {
+        self.start
+    }
+
+    /// The end field, if this is a range.
+    pub fn end(&self) -> Option<DatetimeField> {
+        self.end
+    }
+}
+
+impl std::fmt::Display for IntervalQualifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.start)?;
+
+        if let Some(end) = &self.end {
+            write!(f, " TO {}", end)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, DataType)]
+/// <data type>
+///
+/// # Example
+/// ```
+/// use sql_builder::data_type::DataType;
+///
+/// let ty = DataType::Numeric(Some((10, Some(2)))).array(None);
+/// assert_eq!(ty.to_string(), "NUMERIC(10, 2) ARRAY");
+/// ```
+pub enum DataType {
+    /// CHARACTER [ (<length>) ]
+    Character(Option<u32>),
+    /// CHARACTER VARYING (<length>)
+    CharacterVarying(u32),
+    /// CHARACTER LARGE OBJECT [ (<length>) ]
+    CharacterLargeObject(Option<u32>),
+    /// BINARY LARGE OBJECT [ (<length>) ]
+    BinaryLargeObject(Option<u32>),
+    /// NUMERIC [ (<precision> [, <scale>]) ]
+    Numeric(Option<(u32, Option<u32>)>),
+    /// DECIMAL [ (<precision> [, <scale>]) ]
+    Decimal(Option<(u32, Option<u32>)>),
+    /// SMALLINT
+    SmallInt,
+    /// INTEGER
+    Integer,
+    /// BIGINT
+    BigInt,
+    /// FLOAT [ (<precision>) ]
+    Float(Option<u32>),
+    /// REAL
+    Real,
+    /// DOUBLE PRECISION
+    DoublePrecision,
+    /// BOOLEAN
+    Boolean,
+    /// DATE
+    Date,
+    /// TIME [ (<precision>) ] [ WITH TIME ZONE ]
+    Time {
+        precision: Option<u32>,
+        with_time_zone: bool,
+    },
+    /// TIMESTAMP [ (<precision>) ] [ WITH TIME ZONE ]
+    Timestamp {
+        precision: Option<u32>,
+        with_time_zone: bool,
+    },
+    /// INTERVAL <interval qualifier>
+    Interval(IntervalQualifier),
+    /// <data type> ARRAY [ [<maximum cardinality>] ]
+    Array(Box<DataType>, Option<u32>),
+    /// <data type> MULTISET
+    Multiset(Box<DataType>),
+}
+
+impl DataType {
+    /// CHARACTER VARYING(<length>)
+    pub fn varchar(length: u32) -> Self {
+        Self::CharacterVarying(length)
+    }
+
+    /// NUMERIC(<precision>, <scale>)
+    pub fn numeric(precision: u32, scale: u32) -> Self {
+        Self::Numeric(Some((precision, Some(scale))))
+    }
+
+    /// TIMESTAMP WITH TIME ZONE
+    pub fn timestamp_with_time_zone() -> Self {
+        Self::Timestamp {
+            precision: None,
+            with_time_zone: true,
+        }
+    }
+
+    /// An array of elements of this type.
+    pub fn array(self, max_cardinality: Option<u32>) -> Self {
+        Self::Array(Box::new(self), max_cardinality)
+    }
+
+    /// A multiset of elements of this type.
+    pub fn multiset(self) -> Self {
+        Self::Multiset(Box::new(self))
+    }
+}
+
+fn fmt_length(f: &mut std::fmt::Formatter<'_>, length: &Option<u32>) -> std::fmt::Result {
+    if let Some(length) = length {
+        write!(f, "({})", length)?;
+    }
+
+    Ok(())
+}
+
+fn fmt_precision_scale(
+    f: &mut std::fmt::Formatter<'_>,
+    precision_scale: &Option<(u32, Option<u32>)>,
+) -> std::fmt::Result {
+    match precision_scale {
+        Some((precision, Some(scale))) => write!(f, "({}, {})", precision, scale),
+        Some((precision, None)) => write!(f, "({})", precision),
+        None => Ok(()),
+    }
+}
+
+fn fmt_time_zone(f: &mut std::fmt::Formatter<'_>, with_time_zone: bool) -> std::fmt::Result {
+    if with_time_zone {
+        write!(f, " WITH TIME ZONE")?;
+    }
+
+    Ok(())
+}
+
+impl std::fmt::Display for DataType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataType::Character(length) => {
+                write!(f, "CHARACTER")?;
+                fmt_length(f, length)
+            }
+            DataType::CharacterVarying(length) => write!(f, "CHARACTER VARYING({})", length),
+            DataType::CharacterLargeObject(length) => {
+                write!(f, "CHARACTER LARGE OBJECT")?;
+                fmt_length(f, length)
+            }
+            DataType::BinaryLargeObject(length) => {
+                write!(f, "BINARY LARGE OBJECT")?;
+                fmt_length(f, length)
+            }
+            DataType::Numeric(precision_scale) => {
+                write!(f, "NUMERIC")?;
+                fmt_precision_scale(f, precision_scale)
+            }
+            DataType::Decimal(precision_scale) => {
+                write!(f, "DECIMAL")?;
+                fmt_precision_scale(f, precision_scale)
+            }
+            DataType::SmallInt => write!(f, "SMALLINT"),
+            DataType::Integer => write!(f, "INTEGER"),
+            DataType::BigInt => write!(f, "BIGINT"),
+            DataType::Float(precision) => {
+                write!(f, "FLOAT")?;
+                fmt_length(f, precision)
+            }
+            DataType::Real => write!(f, "REAL"),
+            DataType::DoublePrecision => write!(f, "DOUBLE PRECISION"),
+            DataType::Boolean => write!(f, "BOOLEAN"),
+            DataType::Date => write!(f, "DATE"),
+            DataType::Time {
+                precision,
+                with_time_zone,
+            } => {
+                write!(f, "TIME")?;
+                fmt_length(f, precision)?;
+                fmt_time_zone(f, *with_time_zone)
+            }
+            DataType::Timestamp {
+                precision,
+                with_time_zone,
+            } => {
+                write!(f, "TIMESTAMP")?;
+                fmt_length(f, precision)?;
+                fmt_time_zone(f, *with_time_zone)
+            }
+            DataType::Interval(qualifier) => write!(f, "INTERVAL {}", qualifier),
+            DataType::Array(element, max_cardinality) => {
+                write!(f, "{} ARRAY", element)?;
+
+                if let Some(max_cardinality) = max_cardinality {
+                    write!(f, "[{}]", max_cardinality)?;
+                }
+
+                Ok(())
+            }
+            DataType::Multiset(element) => write!(f, "{} MULTISET", element),
+        }
+    }
+}
+
+impl<'q, DB> ToQuery<'q, DB> for DataType
+where
+    DB: Database,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "{}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DataType, DatetimeField, IntervalQualifier};
+
+    #[test]
+    fn test_data_types() {
+        assert_eq!(DataType::varchar(255).to_string(), "CHARACTER VARYING(255)");
+        assert_eq!(DataType::numeric(10, 2).to_string(), "NUMERIC(10, 2)");
+        assert_eq!(
+            DataType::timestamp_with_time_zone().to_string(),
+            "TIMESTAMP WITH TIME ZONE"
+        );
+        assert_eq!(
+            DataType::Interval(
+                IntervalQualifier::range(DatetimeField::Day, DatetimeField::Second).unwrap()
+            )
+            .to_string(),
+            "INTERVAL DAY TO SECOND"
+        );
+        assert_eq!(
+            DataType::Integer.array(Some(10)).to_string(),
+            "INTEGER ARRAY[10]"
+        );
+        assert_eq!(DataType::Decimal(Some((5, None))).to_string(), "DECIMAL(5)");
+    }
+
+    #[test]
+    fn test_interval_qualifier_range_order() {
+        assert_eq!(
+            IntervalQualifier::range(DatetimeField::Year, DatetimeField::Month)
+                .unwrap()
+                .to_string(),
+            "YEAR TO MONTH"
+        );
+        assert!(IntervalQualifier::range(DatetimeField::Second, DatetimeField::Day).is_err());
+        assert!(IntervalQualifier::range(DatetimeField::Day, DatetimeField::Day).is_err());
+        assert!(IntervalQualifier::range(DatetimeField::Month, DatetimeField::Day).is_err());
+    }
+}
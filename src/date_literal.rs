@@ -0,0 +1,71 @@
+use crate::{Database, ToQuery};
+use sql_builder_macros::DateLiteral;
+use std::fmt::Write;
+
+#[derive(Debug, Clone, PartialEq, Eq, DateLiteral)]
+/// DATE '<years value>-<months value>-<days value>'
+///
+/// See [self::date_lit]
+pub struct DateLiteral(pub(crate) String);
+
+impl ::std::fmt::Display for DateLiteral {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DATE '{}'", self.0.replace('\'', "''"))
+    }
+}
+
+impl<'q, DB> ToQuery<'q, DB> for DateLiteral
+where
+    DB: Database,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "{}", self)
+    }
+}
+
+impl From<&str> for DateLiteral {
+    fn from(value: &str) -> Self {
+        Self(value.to_owned())
+    }
+}
+
+impl From<String> for DateLiteral {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for DateLiteral {
+    fn from(value: chrono::NaiveDate) -> Self {
+        Self(value.format("%Y-%m-%d").to_string())
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Date> for DateLiteral {
+    fn from(value: time::Date) -> Self {
+        Self(format!(
+            "{:04}-{:02}-{:02}",
+            value.year(),
+            u8::from(value.month()),
+            value.day()
+        ))
+    }
+}
+
+#[inline]
+/// Creates a date literal.
+///
+/// # Example
+/// ```
+/// use sql_builder::date_lit;
+///
+/// assert_eq!(date_lit("2024-02-29").to_string(), "DATE '2024-02-29'");
+/// ```
+pub fn date_lit<V>(value: V) -> DateLiteral
+where
+    DateLiteral: From<V>,
+{
+    DateLiteral::from(value)
+}
@@ -0,0 +1,97 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::DatetimeValueExpression;
+use std::fmt::Write;
+
+#[derive(Clone, Copy)]
+enum DatetimeOpKind {
+    Add,
+    Sub,
+}
+
+impl AsRef<str> for DatetimeOpKind {
+    fn as_ref(&self) -> &str {
+        match self {
+            DatetimeOpKind::Add => "+",
+            DatetimeOpKind::Sub => "-",
+        }
+    }
+}
+
+impl std::fmt::Display for DatetimeOpKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Clone, Copy, DatetimeValueExpression)]
+/// <datetime value expression> { + | - } <interval term>
+///
+/// See [self::add_interval] and [self::sub_interval]
+pub struct DatetimeOperand<Lhs, Rhs>
+where
+    Lhs: G::DatetimeValueExpression,
+    Rhs: G::IntervalTerm,
+{
+    lhs: Lhs,
+    rhs: Rhs,
+    kind: DatetimeOpKind,
+}
+
+impl<Lhs, Rhs> std::fmt::Display for DatetimeOperand<Lhs, Rhs>
+where
+    Lhs: G::DatetimeValueExpression + std::fmt::Display,
+    Rhs: G::IntervalTerm + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.lhs, self.kind, self.rhs)
+    }
+}
+
+impl<'q, DB, Lhs, Rhs> ToQuery<'q, DB> for DatetimeOperand<Lhs, Rhs>
+where
+    DB: Database,
+    Lhs: G::DatetimeValueExpression + ToQuery<'q, DB>,
+    Rhs: G::IntervalTerm + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        self.lhs.write(ctx)?;
+        write!(ctx, " {} ", self.kind)?;
+        self.rhs.write(ctx)
+    }
+}
+
+#[inline]
+/// Shifts the datetime forward by the interval.
+///
+/// # Example
+/// ```
+/// use sql_builder::{add_interval, current_timestamp, lit};
+///
+/// let expr = add_interval(current_timestamp(), lit!(INTERVAL "7" DAY));
+/// assert_eq!(expr.to_string(), "CURRENT_TIMESTAMP + INTERVAL '7' DAY");
+/// ```
+pub fn add_interval<Lhs, Rhs>(lhs: Lhs, rhs: Rhs) -> DatetimeOperand<Lhs, Rhs>
+where
+    Lhs: G::DatetimeValueExpression,
+    Rhs: G::IntervalTerm,
+{
+    DatetimeOperand {
+        lhs,
+        rhs,
+        kind: DatetimeOpKind::Add,
+    }
+}
+
+#[inline]
+/// Shifts the datetime backward by the interval.
+pub fn sub_interval<Lhs, Rhs>(lhs: Lhs, rhs: Rhs) -> DatetimeOperand<Lhs, Rhs>
+where
+    Lhs: G::DatetimeValueExpression,
+    Rhs: G::IntervalTerm,
+{
+    DatetimeOperand {
+        lhs,
+        rhs,
+        kind: DatetimeOpKind::Sub,
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{Database, ToQuery};
+use sql_builder_macros::DatetimeValueFunction;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, DatetimeValueFunction)]
+/// <datetime value function>
+///
+/// See [self::current_date], [self::current_time], [self::localtime],
+/// [self::current_timestamp] and [self::localtimestamp]
+pub enum CurrentDatetime {
+    CurrentDate,
+    CurrentTime,
+    LocalTime,
+    CurrentTimestamp,
+    LocalTimestamp,
+}
+
+impl AsRef<str> for CurrentDatetime {
+    fn as_ref(&self) -> &str {
+        match self {
+            CurrentDatetime::CurrentDate => "CURRENT_DATE",
+            CurrentDatetime::CurrentTime => "CURRENT_TIME",
+            CurrentDatetime::LocalTime => "LOCALTIME",
+            CurrentDatetime::CurrentTimestamp => "CURRENT_TIMESTAMP",
+            CurrentDatetime::LocalTimestamp => "LOCALTIMESTAMP",
+        }
+    }
+}
+
+impl std::fmt::Display for CurrentDatetime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl<'q, DB> ToQuery<'q, DB> for CurrentDatetime
+where
+    DB: Database,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "{}", self)
+    }
+}
+
+#[inline]
+/// The current date.
+pub fn current_date() -> CurrentDatetime {
+    CurrentDatetime::CurrentDate
+}
+
+#[inline]
+/// The current time, with the time zone.
+pub fn current_time() -> CurrentDatetime {
+    CurrentDatetime::CurrentTime
+}
+
+#[inline]
+/// The current time, without the time zone.
+pub fn localtime() -> CurrentDatetime {
+    CurrentDatetime::LocalTime
+}
+
+#[inline]
+/// The current timestamp, with the time zone.
+pub fn current_timestamp() -> CurrentDatetime {
+    CurrentDatetime::CurrentTimestamp
+}
+
+#[inline]
+/// The current timestamp, without the time zone.
+pub fn localtimestamp() -> CurrentDatetime {
+    CurrentDatetime::LocalTimestamp
+}
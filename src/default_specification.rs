@@ -0,0 +1,47 @@
+use crate::{Database, ToQuery};
+use sql_builder_macros::DefaultSpecification;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, DefaultSpecification)]
+/// DEFAULT
+///
+/// See [self::default]
+pub struct DefaultSpecification;
+
+impl AsRef<str> for DefaultSpecification {
+    fn as_ref(&self) -> &str {
+        "DEFAULT"
+    }
+}
+
+impl std::fmt::Display for DefaultSpecification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl<'q, DB> ToQuery<'q, DB> for DefaultSpecification
+where
+    DB: Database,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "{}", self)
+    }
+}
+
+#[inline]
+/// The default value of the column, the value is typed by its context.
+///
+/// # Example
+/// ```
+/// use sql_builder::{columns, default, id, insert, lit, row_value, prelude::*};
+///
+/// let stmt = insert(id!(users))
+///     .columns(columns!(id!(id), id!(name)))
+///     .values(row_value!(default(), lit!("jdoe")));
+///
+/// assert_eq!(stmt.to_string(), "INSERT INTO users (id, name) VALUES (DEFAULT, 'jdoe')");
+/// ```
+pub fn default() -> DefaultSpecification {
+    DefaultSpecification
+}
@@ -0,0 +1,174 @@
+use crate::{blank::Blank, either::Either, ToQuery};
+use sql_builder_macros::DeleteStatement;
+use std::fmt::Write;
+
+use crate::grammar as G;
+use crate::helpers as H;
+use crate::Database;
+
+#[derive(Clone, Copy, DeleteStatement)]
+/// Represents a searched delete statement.
+///
+/// Without a where clause, all the rows of the target table are deleted.
+/// See [self::delete_from]
+pub struct Delete<With, Target, Where, Returning>
+where
+    With: G::WithClause,
+    Target: G::TableName,
+    Where: G::WhereClause,
+    Returning: G::ReturningClause,
+{
+    pub with_clause: With,
+    pub target: Target,
+    pub where_clause: Where,
+    pub returning: Returning,
+}
+
+impl<With, Target, Where, Returning> H::DeleteStatement for Delete<With, Target, Where, Returning>
+where
+    With: G::WithClause,
+    Target: G::TableName,
+    Where: G::WhereClause,
+    Returning: G::ReturningClause,
+{
+    type WithClause = With;
+    type Target = Target;
+    type WhereClause = Where;
+    type ReturningClause = Returning;
+
+    #[inline]
+    fn unwrap(self) -> Self {
+        self
+    }
+}
+
+impl<With, Target, Where, Returning> std::fmt::Display for Delete<With, Target, Where, Returning>
+where
+    With: G::WithClause + std::fmt::Display,
+    Target: G::TableName + std::fmt::Display,
+    Where: G::WhereClause + std::fmt::Display,
+    Returning: G::ReturningClause + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if With::IS_IMPL {
+            write!(f, "{} ", self.with_clause)?;
+        }
+
+        write!(f, "DELETE FROM {}", self.target)?;
+
+        if Where::IS_IMPL {
+            write!(f, " {}", self.where_clause)?;
+        }
+
+        if Returning::IS_IMPL {
+            write!(f, " {}", self.returning)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'q, DB, With, Target, Where, Returning> ToQuery<'q, DB>
+    for Delete<With, Target, Where, Returning>
+where
+    DB: Database,
+    With: G::WithClause + ToQuery<'q, DB>,
+    Target: G::TableName + ToQuery<'q, DB>,
+    Where: G::WhereClause + ToQuery<'q, DB>,
+    Returning: G::ReturningClause + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        if With::IS_IMPL {
+            self.with_clause.write(ctx)?;
+            write!(ctx, " ")?;
+        }
+
+        write!(ctx, "DELETE FROM ")?;
+        self.target.write(ctx)?;
+
+        if Where::IS_IMPL {
+            write!(ctx, " ")?;
+            self.where_clause.write(ctx)?;
+        }
+
+        if Returning::IS_IMPL {
+            write!(ctx, " ")?;
+            self.returning.write(ctx)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<Lhs, Rhs> H::DeleteStatement for Either<Lhs, Rhs>
+where
+    Lhs: G::DeleteStatement,
+    Rhs: G::DeleteStatement,
+{
+    type WithClause = Either<Lhs::WithClause, Rhs::WithClause>;
+    type Target = Either<Lhs::Target, Rhs::Target>;
+    type WhereClause = Either<Lhs::WhereClause, Rhs::WhereClause>;
+    type ReturningClause = Either<Lhs::ReturningClause, Rhs::ReturningClause>;
+
+    fn unwrap(
+        self,
+    ) -> Delete<Self::WithClause, Self::Target, Self::WhereClause, Self::ReturningClause> {
+        match self {
+            Either::Left(lhs) => {
+                let Delete {
+                    with_clause,
+                    target,
+                    where_clause,
+                    returning,
+                } = lhs.unwrap();
+
+                Delete {
+                    with_clause: Either::Left(with_clause),
+                    target: Either::Left(target),
+                    where_clause: Either::Left(where_clause),
+                    returning: Either::Left(returning),
+                }
+            }
+            Either::Right(rhs) => {
+                let Delete {
+                    with_clause,
+                    target,
+                    where_clause,
+                    returning,
+                } = rhs.unwrap();
+
+                Delete {
+                    with_clause: Either::Right(with_clause),
+                    target: Either::Right(target),
+                    where_clause: Either::Right(where_clause),
+                    returning: Either::Right(returning),
+                }
+            }
+        }
+    }
+}
+
+#[inline]
+/// Creates a delete statement.
+///
+/// # Example
+/// ```
+/// use sql_builder::{delete_from, id, bind, eq, prelude::*};
+///
+/// # let id = 1;
+/// let stmt = delete_from(id!(my_table)).r#where(eq(id!(id), bind!(id)));
+///
+/// let sql = stmt.to_string();
+/// assert_eq!(sql, "DELETE FROM my_table WHERE id = ?");
+/// ```
+pub fn delete_from<Target>(target: Target) -> Delete<Blank, Target, Blank, Blank>
+where
+    Target: G::TableName,
+{
+    Delete {
+        with_clause: Blank,
+        target,
+        where_clause: Blank,
+        returning: Blank,
+    }
+}
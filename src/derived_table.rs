@@ -0,0 +1,91 @@
+use crate::{blank::Blank, grammar as G, Database, ToQuery};
+use sql_builder_macros::DerivedTable;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, DerivedTable)]
+/// (<query expression>) AS <correlation name> [ (<derived column list>) ]
+///
+/// See [crate::helpers::QueryExpression::alias_table]
+pub struct DerivedTable<Query, Name, Columns>
+where
+    Query: G::QueryExpression,
+    Name: G::CorrelationName,
+    Columns: G::DerivedColumnList,
+{
+    query: Query,
+    name: Name,
+    columns: Columns,
+}
+
+impl<Query, Name> DerivedTable<Query, Name, Blank>
+where
+    Query: G::QueryExpression,
+    Name: G::CorrelationName,
+{
+    pub fn new(query: Query, name: Name) -> Self {
+        Self {
+            query,
+            name,
+            columns: Blank,
+        }
+    }
+}
+
+impl<Query, Name, Columns> DerivedTable<Query, Name, Columns>
+where
+    Query: G::QueryExpression,
+    Name: G::CorrelationName,
+    Columns: G::DerivedColumnList,
+{
+    /// Rename the columns of the derived table.
+    pub fn columns<NewColumns>(self, columns: NewColumns) -> DerivedTable<Query, Name, NewColumns>
+    where
+        NewColumns: G::DerivedColumnList,
+    {
+        DerivedTable {
+            query: self.query,
+            name: self.name,
+            columns,
+        }
+    }
+}
+
+impl<Query, Name, Columns> ::std::fmt::Display for DerivedTable<Query, Name, Columns>
+where
+    Query: G::QueryExpression + std::fmt::Display,
+    Name: G::CorrelationName + std::fmt::Display,
+    Columns: G::DerivedColumnList + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}) AS {}", self.query, self.name)?;
+
+        if Columns::IS_IMPL {
+            write!(f, " ({})", self.columns)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'q, DB, Query, Name, Columns> ToQuery<'q, DB> for DerivedTable<Query, Name, Columns>
+where
+    DB: Database,
+    Query: G::QueryExpression + ToQuery<'q, DB>,
+    Name: G::CorrelationName + ToQuery<'q, DB>,
+    Columns: G::DerivedColumnList + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "(")?;
+        self.query.write(ctx)?;
+        write!(ctx, ") AS ")?;
+        self.name.write(ctx)?;
+
+        if Columns::IS_IMPL {
+            write!(ctx, " (")?;
+            self.columns.write(ctx)?;
+            write!(ctx, ")")?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::DistinctPredicate;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, DistinctPredicate)]
+/// <row value predicand> IS [ NOT ] DISTINCT FROM <row value predicand>
+///
+/// See [self::is_distinct_from] and [self::is_not_distinct_from]
+pub struct IsDistinctFrom<Lhs, Rhs>
+where
+    Lhs: G::RowValuePredicand,
+    Rhs: G::RowValuePredicand,
+{
+    lhs: Lhs,
+    rhs: Rhs,
+    negated: bool,
+}
+
+impl<Lhs, Rhs> ::std::fmt::Display for IsDistinctFrom<Lhs, Rhs>
+where
+    Lhs: G::RowValuePredicand + std::fmt::Display,
+    Rhs: G::RowValuePredicand + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} IS ", self.lhs)?;
+
+        if self.negated {
+            write!(f, "NOT ")?;
+        }
+
+        write!(f, "DISTINCT FROM {}", self.rhs)
+    }
+}
+
+impl<'q, DB, Lhs, Rhs> ToQuery<'q, DB> for IsDistinctFrom<Lhs, Rhs>
+where
+    DB: Database,
+    Lhs: G::RowValuePredicand + ToQuery<'q, DB>,
+    Rhs: G::RowValuePredicand + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        self.lhs.write(ctx)?;
+        write!(ctx, " IS ")?;
+
+        if self.negated {
+            write!(ctx, "NOT ")?;
+        }
+
+        write!(ctx, "DISTINCT FROM ")?;
+        self.rhs.write(ctx)
+    }
+}
+
+#[inline]
+/// Checks if two values are different, null values being comparable
+///
+/// # SQL
+/// ```sql
+/// <lhs> IS DISTINCT FROM <rhs>
+/// ```
+pub fn is_distinct_from<Lhs, Rhs>(lhs: Lhs, rhs: Rhs) -> IsDistinctFrom<Lhs, Rhs>
+where
+    Lhs: G::RowValuePredicand,
+    Rhs: G::RowValuePredicand,
+{
+    IsDistinctFrom {
+        lhs,
+        rhs,
+        negated: false,
+    }
+}
+
+#[inline]
+/// Checks if two values are the same, null values being comparable
+///
+/// # SQL
+/// ```sql
+/// <lhs> IS NOT DISTINCT FROM <rhs>
+/// ```
+pub fn is_not_distinct_from<Lhs, Rhs>(lhs: Lhs, rhs: Rhs) -> IsDistinctFrom<Lhs, Rhs>
+where
+    Lhs: G::RowValuePredicand,
+    Rhs: G::RowValuePredicand,
+{
+    IsDistinctFrom {
+        lhs,
+        rhs,
+        negated: true,
+    }
+}
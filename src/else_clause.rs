@@ -0,0 +1,38 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::ElseClause;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, ElseClause)]
+/// ELSE <result>
+pub struct Else<Result>(Result)
+where
+    Result: G::ValueExpression;
+
+impl<Result> Else<Result>
+where
+    Result: G::ValueExpression,
+{
+    pub fn new(result: Result) -> Self {
+        Self(result)
+    }
+}
+
+impl<Result> ::std::fmt::Display for Else<Result>
+where
+    Result: G::ValueExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ELSE {}", self.0)
+    }
+}
+
+impl<'q, DB, Result> ToQuery<'q, DB> for Else<Result>
+where
+    DB: Database,
+    Result: G::ValueExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "ELSE ")?;
+        self.0.write(ctx)
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{Database, ToQuery};
+use sql_builder_macros::EmptyGroupingSet;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, EmptyGroupingSet)]
+/// The empty grouping set, ()
+pub struct EmptyGroupingSet;
+
+impl std::fmt::Display for EmptyGroupingSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "()")
+    }
+}
+
+impl<'q, DB> ToQuery<'q, DB> for EmptyGroupingSet
+where
+    DB: Database,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "()")
+    }
+}
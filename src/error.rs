@@ -1,12 +1,20 @@
 #[derive(Debug, Clone)]
 pub enum ErrorKind {
     InvalidIdentifier(String),
+    InvalidIntervalQualifier(String),
+    InvalidWindowFrame(String),
+    TooManyParameters(String),
 }
 
 impl std::fmt::Display for ErrorKind {
-    fn fmt(&self, _f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            ErrorKind::InvalidIdentifier(_) => todo!(),
+            ErrorKind::InvalidIdentifier(value) => write!(f, "invalid identifier: {}", value),
+            ErrorKind::InvalidIntervalQualifier(value) => {
+                write!(f, "invalid interval qualifier: {}", value)
+            }
+            ErrorKind::InvalidWindowFrame(value) => write!(f, "invalid window frame: {}", value),
+            ErrorKind::TooManyParameters(value) => write!(f, "too many parameters: {}", value),
         }
     }
 }
@@ -24,7 +32,7 @@ impl std::fmt::Display for Error {
 }
 
 impl std::error::Error for Error {
-    fn cause(&self) -> Option<&(dyn std::error::Error)> {
+    fn cause(&self) -> Option<&dyn std::error::Error> {
         self.source.as_ref().map(|u| u.as_ref())
     }
 }
@@ -36,4 +44,25 @@ impl Error {
             source: None,
         }
     }
+
+    pub fn invalid_interval_qualifier(value: String) -> Self {
+        Self {
+            kind: ErrorKind::InvalidIntervalQualifier(value),
+            source: None,
+        }
+    }
+
+    pub fn invalid_window_frame(value: String) -> Self {
+        Self {
+            kind: ErrorKind::InvalidWindowFrame(value),
+            source: None,
+        }
+    }
+
+    pub fn too_many_parameters(value: String) -> Self {
+        Self {
+            kind: ErrorKind::TooManyParameters(value),
+            source: None,
+        }
+    }
 }
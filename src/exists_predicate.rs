@@ -0,0 +1,45 @@
+use crate::{grammar as G, subquery::Subquery, Database, ToQuery};
+use sql_builder_macros::ExistsPredicate;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, ExistsPredicate)]
+/// EXISTS <table subquery>
+///
+/// See [self::exists]
+pub struct Exists<Query>(Query)
+where
+    Query: G::TableSubquery;
+
+impl<Query> ::std::fmt::Display for Exists<Query>
+where
+    Query: G::TableSubquery + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EXISTS {}", self.0)
+    }
+}
+
+impl<'q, DB, Query> ToQuery<'q, DB> for Exists<Query>
+where
+    DB: Database,
+    Query: G::TableSubquery + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "EXISTS ")?;
+        self.0.write(ctx)
+    }
+}
+
+#[inline]
+/// Checks if the query returns at least one row
+///
+/// # SQL
+/// ```sql
+/// EXISTS (<query>)
+/// ```
+pub fn exists<Query>(query: Query) -> Exists<Subquery<Query>>
+where
+    Query: G::QueryExpression,
+{
+    Exists(Subquery::new(query))
+}
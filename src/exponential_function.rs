@@ -0,0 +1,44 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::ExponentialFunction;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, ExponentialFunction)]
+/// EXP(<numeric value expression>)
+///
+/// See [self::exp]
+pub struct Exp<Value>
+where
+    Value: G::NumericValueExpression,
+{
+    value: Value,
+}
+
+impl<Value> ::std::fmt::Display for Exp<Value>
+where
+    Value: G::NumericValueExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EXP({})", self.value)
+    }
+}
+
+impl<'q, DB, Value> ToQuery<'q, DB> for Exp<Value>
+where
+    DB: Database,
+    Value: G::NumericValueExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "EXP(")?;
+        self.value.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Returns e raised to the power of a number.
+pub fn exp<Value>(value: Value) -> Exp<Value>
+where
+    Value: G::NumericValueExpression,
+{
+    Exp { value }
+}
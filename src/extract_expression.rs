@@ -0,0 +1,53 @@
+use crate::{data_type::DatetimeField, grammar as G, Database, ToQuery};
+use sql_builder_macros::ExtractExpression;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, ExtractExpression)]
+/// EXTRACT(<extract field> FROM <extract source>)
+///
+/// See [self::extract]
+pub struct Extract<Source>
+where
+    Source: G::ExtractSource,
+{
+    field: DatetimeField,
+    source: Source,
+}
+
+impl<Source> ::std::fmt::Display for Extract<Source>
+where
+    Source: G::ExtractSource + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EXTRACT({} FROM {})", self.field, self.source)
+    }
+}
+
+impl<'q, DB, Source> ToQuery<'q, DB> for Extract<Source>
+where
+    DB: Database,
+    Source: G::ExtractSource + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "EXTRACT({} FROM ", self.field)?;
+        self.source.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Extracts a field of a datetime or an interval.
+///
+/// # Example
+/// ```
+/// use sql_builder::{data_type::DatetimeField, extract, id};
+///
+/// let expr = extract(DatetimeField::Year, id!(created_at));
+/// assert_eq!(expr.to_string(), "EXTRACT(YEAR FROM created_at)");
+/// ```
+pub fn extract<Source>(field: DatetimeField, source: Source) -> Extract<Source>
+where
+    Source: G::ExtractSource,
+{
+    Extract { field, source }
+}
@@ -0,0 +1,51 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::Factor;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, Factor)]
+/// <minus sign> <numeric primary>
+///
+/// See [self::neg]
+pub struct Negative<Primary>
+where
+    Primary: G::NumericPrimary,
+{
+    primary: Primary,
+}
+
+impl<Primary> ::std::fmt::Display for Negative<Primary>
+where
+    Primary: G::NumericPrimary + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "-{}", self.primary)
+    }
+}
+
+impl<'q, DB, Primary> ToQuery<'q, DB> for Negative<Primary>
+where
+    DB: Database,
+    Primary: G::NumericPrimary + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "-")?;
+        self.primary.write(ctx)
+    }
+}
+
+#[inline]
+/// Negates a number.
+///
+/// # Example
+/// ```
+/// use sql_builder::{abs, id, mult, neg, prelude::*};
+///
+/// let expr = mult(neg(id!(price)), abs(id!(quantity)));
+/// assert_eq!(expr.to_string(), "-price * ABS(quantity)");
+/// ```
+pub fn neg<Primary>(primary: Primary) -> Negative<Primary>
+where
+    Primary: G::NumericPrimary,
+{
+    Negative { primary }
+}
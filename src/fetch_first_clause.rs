@@ -0,0 +1,228 @@
+use crate::{
+    blank::Blank, either::Either, grammar as G, helpers as H, result_offset_clause::Offset,
+    Database, ToQuery,
+};
+use sql_builder_macros::FetchFirstClause;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, FetchFirstClause)]
+/// FETCH FIRST <fetch first row count> ROWS { ONLY | WITH TIES }
+pub struct FetchFirst<Count>
+where
+    Count: G::FetchFirstRowCount,
+{
+    count: Count,
+    with_ties: bool,
+}
+
+impl<Count> FetchFirst<Count>
+where
+    Count: G::FetchFirstRowCount,
+{
+    pub fn new(count: Count) -> Self {
+        Self {
+            count,
+            with_ties: false,
+        }
+    }
+
+    /// Also fetch the rows that are peers of the last fetched row.
+    pub fn with_ties(self) -> Self {
+        Self {
+            count: self.count,
+            with_ties: true,
+        }
+    }
+}
+
+impl<Count> ::std::fmt::Display for FetchFirst<Count>
+where
+    Count: G::FetchFirstRowCount + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FETCH FIRST {} ROWS ", self.count)?;
+
+        if self.with_ties {
+            write!(f, "WITH TIES")
+        } else {
+            write!(f, "ONLY")
+        }
+    }
+}
+
+impl<'q, DB, Count> ToQuery<'q, DB> for FetchFirst<Count>
+where
+    DB: Database,
+    Count: G::FetchFirstRowCount + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "FETCH FIRST ")?;
+        self.count.write(ctx)?;
+
+        if self.with_ties {
+            write!(ctx, " ROWS WITH TIES")
+        } else {
+            write!(ctx, " ROWS ONLY")
+        }
+    }
+}
+
+#[derive(Clone, Copy, FetchFirstClause)]
+/// LIMIT <fetch first row count> [ OFFSET <offset row count> ]
+///
+/// Non-standard row limitation, for the databases which do not support
+/// the FETCH FIRST clause.
+pub struct Limit<Count, OffsetCount>
+where
+    Count: G::FetchFirstRowCount,
+    OffsetCount: G::OffsetRowCount,
+{
+    count: Count,
+    offset: OffsetCount,
+}
+
+impl<Count, OffsetCount> Limit<Count, OffsetCount>
+where
+    Count: G::FetchFirstRowCount,
+    OffsetCount: G::OffsetRowCount,
+{
+    pub fn new(count: Count, offset: OffsetCount) -> Self {
+        Self { count, offset }
+    }
+}
+
+impl<Count, OffsetCount> ::std::fmt::Display for Limit<Count, OffsetCount>
+where
+    Count: G::FetchFirstRowCount + std::fmt::Display,
+    OffsetCount: G::OffsetRowCount + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LIMIT {}", self.count)?;
+
+        if OffsetCount::IS_IMPL {
+            write!(f, " OFFSET {}", self.offset)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'q, DB, Count, OffsetCount> ToQuery<'q, DB> for Limit<Count, OffsetCount>
+where
+    DB: Database,
+    Count: G::FetchFirstRowCount + ToQuery<'q, DB>,
+    OffsetCount: G::OffsetRowCount + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "LIMIT ")?;
+        self.count.write(ctx)?;
+
+        if OffsetCount::IS_IMPL {
+            write!(ctx, " OFFSET ")?;
+            self.offset.write(ctx)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl H::FetchFirstClause for Blank {
+    type ResultOffsetClause<OffsetCount: G::OffsetRowCount> = Offset<OffsetCount>;
+    type WithOffset<OffsetCount: G::OffsetRowCount> = Self;
+
+    fn with_offset<OffsetCount>(self, count: OffsetCount) -> (Offset<OffsetCount>, Self)
+    where
+        OffsetCount: G::OffsetRowCount,
+    {
+        (Offset::new(count), self)
+    }
+}
+
+impl<Count> H::FetchFirstClause for FetchFirst<Count>
+where
+    Count: G::FetchFirstRowCount,
+{
+    type ResultOffsetClause<OffsetCount: G::OffsetRowCount> = Offset<OffsetCount>;
+    type WithOffset<OffsetCount: G::OffsetRowCount> = Self;
+
+    fn with_offset<OffsetCount>(self, count: OffsetCount) -> (Offset<OffsetCount>, Self)
+    where
+        OffsetCount: G::OffsetRowCount,
+    {
+        (Offset::new(count), self)
+    }
+}
+
+impl<Count, CurrentOffsetCount> H::FetchFirstClause for Limit<Count, CurrentOffsetCount>
+where
+    Count: G::FetchFirstRowCount,
+    CurrentOffsetCount: G::OffsetRowCount,
+{
+    type ResultOffsetClause<OffsetCount: G::OffsetRowCount> = Blank;
+    type WithOffset<OffsetCount: G::OffsetRowCount> = Limit<Count, OffsetCount>;
+
+    fn with_offset<OffsetCount>(self, count: OffsetCount) -> (Blank, Limit<Count, OffsetCount>)
+    where
+        OffsetCount: G::OffsetRowCount,
+    {
+        (Blank, Limit::new(self.count, count))
+    }
+}
+
+impl<Lhs, Rhs> H::FetchFirstClause for Either<Lhs, Rhs>
+where
+    Lhs: G::FetchFirstClause,
+    Rhs: G::FetchFirstClause,
+{
+    type ResultOffsetClause<OffsetCount: G::OffsetRowCount> =
+        Either<Lhs::ResultOffsetClause<OffsetCount>, Rhs::ResultOffsetClause<OffsetCount>>;
+    type WithOffset<OffsetCount: G::OffsetRowCount> =
+        Either<Lhs::WithOffset<OffsetCount>, Rhs::WithOffset<OffsetCount>>;
+
+    fn with_offset<OffsetCount>(
+        self,
+        count: OffsetCount,
+    ) -> (
+        Self::ResultOffsetClause<OffsetCount>,
+        Self::WithOffset<OffsetCount>,
+    )
+    where
+        OffsetCount: G::OffsetRowCount,
+    {
+        match self {
+            Either::Left(lhs) => {
+                let (offset, fetch) = lhs.with_offset(count);
+                (Either::Left(offset), Either::Left(fetch))
+            }
+            Either::Right(rhs) => {
+                let (offset, fetch) = rhs.with_offset(count);
+                (Either::Right(offset), Either::Right(fetch))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FetchFirst, Limit};
+    use crate::{blank::Blank, unsigned_numeric_lit};
+
+    #[test]
+    fn test_fetch_first() {
+        let clause = FetchFirst::new(unsigned_numeric_lit(10u64));
+        assert_eq!(clause.to_string(), "FETCH FIRST 10 ROWS ONLY");
+        assert_eq!(
+            clause.with_ties().to_string(),
+            "FETCH FIRST 10 ROWS WITH TIES"
+        );
+    }
+
+    #[test]
+    fn test_limit() {
+        let clause = Limit::new(unsigned_numeric_lit(10u64), Blank);
+        assert_eq!(clause.to_string(), "LIMIT 10");
+
+        let clause = Limit::new(unsigned_numeric_lit(10u64), unsigned_numeric_lit(20u64));
+        assert_eq!(clause.to_string(), "LIMIT 10 OFFSET 20");
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::FilterClause;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, FilterClause)]
+/// FILTER (WHERE <search condition>)
+pub struct Filter<Cond>(Cond)
+where
+    Cond: G::SearchCondition;
+
+impl<Cond> Filter<Cond>
+where
+    Cond: G::SearchCondition,
+{
+    pub fn new(condition: Cond) -> Self {
+        Self(condition)
+    }
+}
+
+impl<Cond> ::std::fmt::Display for Filter<Cond>
+where
+    Cond: G::SearchCondition + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FILTER (WHERE {})", self.0)
+    }
+}
+
+impl<'q, DB, Cond> ToQuery<'q, DB> for Filter<Cond>
+where
+    DB: Database,
+    Cond: G::SearchCondition + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "FILTER (WHERE ")?;
+        self.0.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
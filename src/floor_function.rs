@@ -0,0 +1,44 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::FloorFunction;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, FloorFunction)]
+/// FLOOR(<numeric value expression>)
+///
+/// See [self::floor]
+pub struct Floor<Value>
+where
+    Value: G::NumericValueExpression,
+{
+    value: Value,
+}
+
+impl<Value> ::std::fmt::Display for Floor<Value>
+where
+    Value: G::NumericValueExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FLOOR({})", self.value)
+    }
+}
+
+impl<'q, DB, Value> ToQuery<'q, DB> for Floor<Value>
+where
+    DB: Database,
+    Value: G::NumericValueExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "FLOOR(")?;
+        self.value.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Returns the largest integer less than or equal to a number.
+pub fn floor<Value>(value: Value) -> Floor<Value>
+where
+    Value: G::NumericValueExpression,
+{
+    Floor { value }
+}
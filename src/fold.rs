@@ -0,0 +1,82 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::Fold;
+use std::fmt::Write;
+
+#[derive(Clone, Copy)]
+/// UPPER | LOWER
+pub enum FoldKind {
+    Upper,
+    Lower,
+}
+
+impl AsRef<str> for FoldKind {
+    fn as_ref(&self) -> &str {
+        match self {
+            FoldKind::Upper => "UPPER",
+            FoldKind::Lower => "LOWER",
+        }
+    }
+}
+
+impl std::fmt::Display for FoldKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Clone, Copy, Fold)]
+/// { UPPER | LOWER } (<character value expression>)
+///
+/// See [self::upper] and [self::lower]
+pub struct Folded<Value>
+where
+    Value: G::CharacterValueExpression,
+{
+    kind: FoldKind,
+    value: Value,
+}
+
+impl<Value> ::std::fmt::Display for Folded<Value>
+where
+    Value: G::CharacterValueExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.kind, self.value)
+    }
+}
+
+impl<'q, DB, Value> ToQuery<'q, DB> for Folded<Value>
+where
+    DB: Database,
+    Value: G::CharacterValueExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "{}(", self.kind)?;
+        self.value.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Converts the characters to upper case.
+pub fn upper<Value>(value: Value) -> Folded<Value>
+where
+    Value: G::CharacterValueExpression,
+{
+    Folded {
+        kind: FoldKind::Upper,
+        value,
+    }
+}
+
+#[inline]
+/// Converts the characters to lower case.
+pub fn lower<Value>(value: Value) -> Folded<Value>
+where
+    Value: G::CharacterValueExpression,
+{
+    Folded {
+        kind: FoldKind::Lower,
+        value,
+    }
+}
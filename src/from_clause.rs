@@ -41,16 +41,23 @@ where
     type WhereClause = Blank;
     type GroupByClause = Blank;
     type HavingClause = Blank;
+    type WindowClause = Blank;
 
     fn unwrap(
         self,
-    ) -> TableExpr<Self::FromClause, Self::WhereClause, Self::GroupByClause, Self::HavingClause>
-    {
+    ) -> TableExpr<
+        Self::FromClause,
+        Self::WhereClause,
+        Self::GroupByClause,
+        Self::HavingClause,
+        Self::WindowClause,
+    > {
         TableExpr {
             from_clause: self,
             where_clause: Blank,
             group_by: Blank,
             having: Blank,
+            window: Blank,
         }
     }
 }
@@ -1,8 +1,12 @@
-use crate::{grammar as G, Database, ToQuery};
+use crate::{
+    contextually_typed_row_value_expression_list::{BoundRow, BoundRows},
+    grammar as G, Database, ToQuery,
+};
 use sql_builder_macros::FromConstructor;
 use std::fmt::Write;
 
 #[derive(Clone, Copy, FromConstructor)]
+/// [ (<insert column list>) ] [ <override clause> ] VALUES <contextually typed row value expression list>
 pub struct FromConstructor<Columns, Override, Value>
 where
     Columns: G::InsertColumnList,
@@ -29,6 +33,23 @@ where
     }
 }
 
+impl<Columns, Override, Row> FromConstructor<Columns, Override, BoundRows<Row>>
+where
+    Columns: G::InsertColumnList + Clone,
+    Override: G::OverrideClause + Clone,
+    Row: BoundRow,
+{
+    /// Split the rows in constructors, each binding at most `max_parameters` values.
+    pub fn batches(self, max_parameters: usize) -> Result<Vec<Self>, crate::Error> {
+        Ok(self
+            .value
+            .batches(max_parameters)?
+            .into_iter()
+            .map(|value| Self::new(self.columns.clone(), self.override_clause.clone(), value))
+            .collect())
+    }
+}
+
 impl<Columns, Override, Value> ::std::fmt::Display for FromConstructor<Columns, Override, Value>
 where
     Columns: G::InsertColumnList + std::fmt::Display,
@@ -41,10 +62,10 @@ where
         }
 
         if Override::IS_IMPL {
-            write!(f, "{}", self.override_clause)?;
+            write!(f, "{} ", self.override_clause)?;
         }
 
-        write!(f, "{}", self.value)
+        write!(f, "VALUES {}", self.value)
     }
 }
 
@@ -67,6 +88,7 @@ where
             write!(ctx, " ")?;
         }
 
+        write!(ctx, "VALUES ")?;
         self.value.write(ctx)
     }
 }
@@ -0,0 +1,30 @@
+use crate::{Database, ToQuery};
+use sql_builder_macros::FromDefault;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, FromDefault)]
+/// DEFAULT VALUES
+///
+/// See [crate::insert::InsertFragment::default_values]
+pub struct FromDefault;
+
+impl AsRef<str> for FromDefault {
+    fn as_ref(&self) -> &str {
+        "DEFAULT VALUES"
+    }
+}
+
+impl std::fmt::Display for FromDefault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl<'q, DB> ToQuery<'q, DB> for FromDefault
+where
+    DB: Database,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "{}", self)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::FromSubQuery;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, FromSubQuery)]
+/// [ (<insert column list>) ] [ <override clause> ] <query expression>
+pub struct FromSubQuery<Columns, Override, Query>
+where
+    Columns: G::InsertColumnList,
+    Override: G::OverrideClause,
+    Query: G::QueryExpression,
+{
+    columns: Columns,
+    override_clause: Override,
+    query: Query,
+}
+
+impl<Columns, Override, Query> FromSubQuery<Columns, Override, Query>
+where
+    Columns: G::InsertColumnList,
+    Override: G::OverrideClause,
+    Query: G::QueryExpression,
+{
+    pub fn new(columns: Columns, override_clause: Override, query: Query) -> Self {
+        Self {
+            columns,
+            override_clause,
+            query,
+        }
+    }
+}
+
+impl<Columns, Override, Query> ::std::fmt::Display for FromSubQuery<Columns, Override, Query>
+where
+    Columns: G::InsertColumnList + std::fmt::Display,
+    Override: G::OverrideClause + std::fmt::Display,
+    Query: G::QueryExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if Columns::IS_IMPL {
+            write!(f, "({}) ", self.columns)?;
+        }
+
+        if Override::IS_IMPL {
+            write!(f, "{} ", self.override_clause)?;
+        }
+
+        write!(f, "{}", self.query)
+    }
+}
+
+impl<'q, DB, Columns, Override, Query> ToQuery<'q, DB> for FromSubQuery<Columns, Override, Query>
+where
+    DB: Database,
+    Columns: G::InsertColumnList + ToQuery<'q, DB>,
+    Override: G::OverrideClause + ToQuery<'q, DB>,
+    Query: G::QueryExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> ::std::fmt::Result {
+        if Columns::IS_IMPL {
+            write!(ctx, "(")?;
+            self.columns.write(ctx)?;
+            write!(ctx, ") ")?;
+        }
+
+        if Override::IS_IMPL {
+            self.override_clause.write(ctx)?;
+            write!(ctx, " ")?;
+        }
+
+        self.query.write(ctx)
+    }
+}
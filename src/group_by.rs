@@ -1,22 +1,99 @@
-use crate::{Database, ToQuery};
+use crate::{grammar as G, select::SetQuantifier, Database, ToQuery};
 use sql_builder_macros::GroupByClause;
 use std::fmt::Write;
 
 #[derive(Clone, Copy, GroupByClause)]
-pub struct GroupBy();
+/// GROUP BY [ <set quantifier> ] <grouping element list>
+pub struct GroupBy<Elements>
+where
+    Elements: G::GroupingElementList,
+{
+    quantifier: Option<SetQuantifier>,
+    elements: Elements,
+}
+
+impl<Elements> GroupBy<Elements>
+where
+    Elements: G::GroupingElementList,
+{
+    pub fn new(elements: Elements) -> Self {
+        Self {
+            quantifier: None,
+            elements,
+        }
+    }
 
-impl ::std::fmt::Display for GroupBy {
-    fn fmt(&self, _f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Ok(())
+    /// Removed duplicated grouping sets
+    pub fn distinct(self) -> Self {
+        Self {
+            quantifier: Some(SetQuantifier::Distinct),
+            elements: self.elements,
+        }
+    }
+
+    /// All grouping sets are kept
+    pub fn all(self) -> Self {
+        Self {
+            quantifier: Some(SetQuantifier::All),
+            elements: self.elements,
+        }
     }
 }
 
-impl<'q, DB> ToQuery<'q, DB> for GroupBy
+impl<Elements> ::std::fmt::Display for GroupBy<Elements>
+where
+    Elements: G::GroupingElementList + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GROUP BY ")?;
+
+        if let Some(q) = &self.quantifier {
+            write!(f, "{} ", q)?;
+        }
+
+        write!(f, "{}", self.elements)
+    }
+}
+
+impl<'q, DB, Elements> ToQuery<'q, DB> for GroupBy<Elements>
 where
     DB: Database,
+    Elements: G::GroupingElementList + ToQuery<'q, DB>,
 {
     fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
         write!(ctx, "GROUP BY ")?;
-        todo!("implement ToQuery for GroupBy")
+
+        if let Some(q) = &self.quantifier {
+            write!(ctx, "{} ", q)?;
+        }
+
+        self.elements.write(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GroupBy;
+    use crate::{cube, id, prelude::*, rollup, EmptyGroupingSet};
+
+    #[test]
+    fn test_group_by_columns() {
+        let clause = GroupBy::new(id("a").add_grouping_element(id("b")));
+        assert_eq!(clause.to_string(), "GROUP BY a, b");
+    }
+
+    #[test]
+    fn test_group_by_rollup_and_cube() {
+        let clause = GroupBy::new(
+            rollup(id("a").add_ordinary_grouping_set(id("b")))
+                .add_grouping_element(cube(id("c")))
+                .add_grouping_element(EmptyGroupingSet),
+        )
+        .distinct();
+
+        assert_eq!(
+            clause.to_string(),
+            "GROUP BY DISTINCT ROLLUP(a, b), CUBE(c), ()"
+        );
     }
 }
@@ -0,0 +1,47 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::GroupingColumnReferenceList;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, GroupingColumnReferenceList)]
+/// A linked-list of grouping column references.
+pub struct GroupingColumnReferenceLink<Head, Tail>
+where
+    Head: G::GroupingColumnReferenceList,
+    Tail: G::GroupingColumnReference,
+{
+    head: Head,
+    tail: Tail,
+}
+
+impl<Head, Tail> GroupingColumnReferenceLink<Head, Tail>
+where
+    Head: G::GroupingColumnReferenceList,
+    Tail: G::GroupingColumnReference,
+{
+    pub fn new(head: Head, tail: Tail) -> Self {
+        Self { head, tail }
+    }
+}
+
+impl<Head, Tail> ::std::fmt::Display for GroupingColumnReferenceLink<Head, Tail>
+where
+    Head: G::GroupingColumnReferenceList + std::fmt::Display,
+    Tail: G::GroupingColumnReference + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}", self.head, self.tail)
+    }
+}
+
+impl<'q, DB, Head, Tail> ToQuery<'q, DB> for GroupingColumnReferenceLink<Head, Tail>
+where
+    DB: Database,
+    Head: G::GroupingColumnReferenceList + ToQuery<'q, DB>,
+    Tail: G::GroupingColumnReference + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> ::std::fmt::Result {
+        self.head.write(ctx)?;
+        write!(ctx, ", ")?;
+        self.tail.write(ctx)
+    }
+}
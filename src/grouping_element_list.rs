@@ -0,0 +1,47 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::GroupingElementList;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, GroupingElementList)]
+/// A linked-list of grouping elements.
+pub struct GroupingElementLink<Head, Tail>
+where
+    Head: G::GroupingElementList,
+    Tail: G::GroupingElement,
+{
+    head: Head,
+    tail: Tail,
+}
+
+impl<Head, Tail> GroupingElementLink<Head, Tail>
+where
+    Head: G::GroupingElementList,
+    Tail: G::GroupingElement,
+{
+    pub fn new(head: Head, tail: Tail) -> Self {
+        Self { head, tail }
+    }
+}
+
+impl<Head, Tail> ::std::fmt::Display for GroupingElementLink<Head, Tail>
+where
+    Head: G::GroupingElementList + std::fmt::Display,
+    Tail: G::GroupingElement + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}", self.head, self.tail)
+    }
+}
+
+impl<'q, DB, Head, Tail> ToQuery<'q, DB> for GroupingElementLink<Head, Tail>
+where
+    DB: Database,
+    Head: G::GroupingElementList + ToQuery<'q, DB>,
+    Tail: G::GroupingElement + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> ::std::fmt::Result {
+        self.head.write(ctx)?;
+        write!(ctx, ", ")?;
+        self.tail.write(ctx)
+    }
+}
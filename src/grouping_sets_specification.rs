@@ -0,0 +1,39 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::GroupingSetsSpecification;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, GroupingSetsSpecification)]
+/// GROUPING SETS <left paren> <grouping set list> <right paren>
+pub struct GroupingSets<List>(List)
+where
+    List: G::GroupingElementList;
+
+impl<List> ::std::fmt::Display for GroupingSets<List>
+where
+    List: G::GroupingElementList + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GROUPING SETS({})", self.0)
+    }
+}
+
+impl<'q, DB, List> ToQuery<'q, DB> for GroupingSets<List>
+where
+    DB: Database,
+    List: G::GroupingElementList + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "GROUPING SETS(")?;
+        self.0.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Groups the rows on each of the grouping sets.
+pub fn grouping_sets<List>(list: List) -> GroupingSets<List>
+where
+    List: G::GroupingElementList,
+{
+    GroupingSets(list)
+}
@@ -1,21 +1,39 @@
-use sql_builder_macros::HavingClause;
-
+use crate::grammar as G;
 use crate::{Database, ToQuery};
+use sql_builder_macros::HavingClause;
+use std::fmt::Write;
 
 #[derive(Clone, Copy, HavingClause)]
-pub struct Having;
+/// HAVING <search_condition>
+pub struct Having<SearchCond: G::SearchCondition> {
+    search_cond: SearchCond,
+}
 
-impl ::std::fmt::Display for Having {
-    fn fmt(&self, _f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Ok(())
+impl<SearchCond> Having<SearchCond>
+where
+    SearchCond: G::SearchCondition,
+{
+    pub fn new(search_cond: SearchCond) -> Self {
+        Self { search_cond }
+    }
+}
+
+impl<SearchCond> ::std::fmt::Display for Having<SearchCond>
+where
+    SearchCond: G::SearchCondition + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HAVING {}", self.search_cond)
     }
 }
 
-impl<'q, DB> ToQuery<'q, DB> for Having
+impl<'q, DB, SearchCond> ToQuery<'q, DB> for Having<SearchCond>
 where
     DB: Database,
+    SearchCond: G::SearchCondition + ToQuery<'q, DB>,
 {
-    fn write(&'q self, _ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
-        todo!("implement ToQuery for Having")
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "HAVING ")?;
+        self.search_cond.write(ctx)
     }
 }
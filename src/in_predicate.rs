@@ -0,0 +1,252 @@
+use crate::{grammar as G, in_predicate_value::InValues, subquery::Subquery, Database, ToQuery};
+use sql_builder_macros::InPredicate;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, InPredicate)]
+/// <row value predicand> [ NOT ] IN <in predicate value>
+pub struct In<Lhs, Value>
+where
+    Lhs: G::RowValuePredicand,
+    Value: G::InPredicateValue,
+{
+    lhs: Lhs,
+    value: Value,
+    negated: bool,
+}
+
+impl<Lhs, Value> ::std::fmt::Display for In<Lhs, Value>
+where
+    Lhs: G::RowValuePredicand + std::fmt::Display,
+    Value: G::InPredicateValue + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ", self.lhs)?;
+
+        if self.negated {
+            write!(f, "NOT ")?;
+        }
+
+        write!(f, "IN {}", self.value)
+    }
+}
+
+impl<'q, DB, Lhs, Value> ToQuery<'q, DB> for In<Lhs, Value>
+where
+    DB: Database,
+    Lhs: G::RowValuePredicand + ToQuery<'q, DB>,
+    Value: G::InPredicateValue + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        self.lhs.write(ctx)?;
+        write!(ctx, " ")?;
+
+        if self.negated {
+            write!(ctx, "NOT ")?;
+        }
+
+        write!(ctx, "IN ")?;
+        self.value.write(ctx)
+    }
+}
+
+#[derive(Clone, InPredicate)]
+/// <row value predicand> [ NOT ] IN (?, ...)
+///
+/// Each value is bound to its own dynamic parameter.
+///
+/// An empty list renders a predicate that is always false (or always true if
+/// negated), as most databases reject an empty IN value list.
+pub struct InBound<Lhs, T>
+where
+    Lhs: G::RowValuePredicand,
+{
+    lhs: Lhs,
+    values: Vec<T>,
+    negated: bool,
+}
+
+impl<Lhs, T> InBound<Lhs, T>
+where
+    Lhs: G::RowValuePredicand,
+{
+    fn empty_fallback(&self) -> &'static str {
+        if self.negated {
+            "1 = 1"
+        } else {
+            "1 = 0"
+        }
+    }
+}
+
+impl<Lhs, T> ::std::fmt::Display for InBound<Lhs, T>
+where
+    Lhs: G::RowValuePredicand + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.values.is_empty() {
+            return write!(f, "{}", self.empty_fallback());
+        }
+
+        write!(f, "{} ", self.lhs)?;
+
+        if self.negated {
+            write!(f, "NOT ")?;
+        }
+
+        write!(f, "IN (")?;
+
+        for i in 0..self.values.len() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "?")?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+impl<'q, DB, Lhs, T> ToQuery<'q, DB> for InBound<Lhs, T>
+where
+    DB: Database,
+    Lhs: G::RowValuePredicand + ToQuery<'q, DB>,
+    T: ::sqlx::Encode<'q, DB> + ::sqlx::Type<DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        if self.values.is_empty() {
+            return write!(ctx, "{}", self.empty_fallback());
+        }
+
+        self.lhs.write(ctx)?;
+        write!(ctx, " ")?;
+
+        if self.negated {
+            write!(ctx, "NOT ")?;
+        }
+
+        write!(ctx, "IN (")?;
+
+        for (i, value) in self.values.iter().enumerate() {
+            if i > 0 {
+                write!(ctx, ", ")?;
+            }
+
+            ctx.write_argument(value)?;
+        }
+
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Checks if the value is in the list
+///
+/// # SQL
+/// ```sql
+/// <lhs> IN (<values>)
+/// ```
+pub fn in_list<Lhs, Values>(lhs: Lhs, values: Values) -> In<Lhs, InValues<Values>>
+where
+    Lhs: G::RowValuePredicand,
+    Values: G::InValueList,
+{
+    In {
+        lhs,
+        value: InValues::new(values),
+        negated: false,
+    }
+}
+
+#[inline]
+/// Checks if the value is not in the list
+///
+/// # SQL
+/// ```sql
+/// <lhs> NOT IN (<values>)
+/// ```
+pub fn not_in<Lhs, Values>(lhs: Lhs, values: Values) -> In<Lhs, InValues<Values>>
+where
+    Lhs: G::RowValuePredicand,
+    Values: G::InValueList,
+{
+    In {
+        lhs,
+        value: InValues::new(values),
+        negated: true,
+    }
+}
+
+#[inline]
+/// Checks if the value is returned by the query
+///
+/// # SQL
+/// ```sql
+/// <lhs> IN (<query>)
+/// ```
+pub fn in_subquery<Lhs, Query>(lhs: Lhs, query: Query) -> In<Lhs, Subquery<Query>>
+where
+    Lhs: G::RowValuePredicand,
+    Query: G::QueryExpression,
+{
+    In {
+        lhs,
+        value: Subquery::new(query),
+        negated: false,
+    }
+}
+
+#[inline]
+/// Checks if the value is not returned by the query
+///
+/// # SQL
+/// ```sql
+/// <lhs> NOT IN (<query>)
+/// ```
+pub fn not_in_subquery<Lhs, Query>(lhs: Lhs, query: Query) -> In<Lhs, Subquery<Query>>
+where
+    Lhs: G::RowValuePredicand,
+    Query: G::QueryExpression,
+{
+    In {
+        lhs,
+        value: Subquery::new(query),
+        negated: true,
+    }
+}
+
+#[inline]
+/// Checks if the value is in the list of bound values, known at runtime
+///
+/// # SQL
+/// ```sql
+/// <lhs> IN (?, ?, ...)
+/// ```
+pub fn in_bound<Lhs, T>(lhs: Lhs, values: impl IntoIterator<Item = T>) -> InBound<Lhs, T>
+where
+    Lhs: G::RowValuePredicand,
+{
+    InBound {
+        lhs,
+        values: values.into_iter().collect(),
+        negated: false,
+    }
+}
+
+#[inline]
+/// Checks if the value is not in the list of bound values, known at runtime
+///
+/// # SQL
+/// ```sql
+/// <lhs> NOT IN (?, ?, ...)
+/// ```
+pub fn not_in_bound<Lhs, T>(lhs: Lhs, values: impl IntoIterator<Item = T>) -> InBound<Lhs, T>
+where
+    Lhs: G::RowValuePredicand,
+{
+    InBound {
+        lhs,
+        values: values.into_iter().collect(),
+        negated: true,
+    }
+}
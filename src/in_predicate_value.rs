@@ -0,0 +1,39 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::InPredicateValue;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, InPredicateValue)]
+/// (<in value list>)
+pub struct InValues<List>(List)
+where
+    List: G::InValueList;
+
+impl<List> InValues<List>
+where
+    List: G::InValueList,
+{
+    pub fn new(list: List) -> Self {
+        Self(list)
+    }
+}
+
+impl<List> std::fmt::Display for InValues<List>
+where
+    List: G::InValueList + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({})", self.0)
+    }
+}
+
+impl<'q, DB, List> ToQuery<'q, DB> for InValues<List>
+where
+    DB: Database,
+    List: G::InValueList + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "(")?;
+        self.0.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
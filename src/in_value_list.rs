@@ -0,0 +1,46 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::InValueList;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, InValueList)]
+pub struct InValueLink<Head, Tail>
+where
+    Head: G::InValueList,
+    Tail: G::RowValuePredicand,
+{
+    head: Head,
+    tail: Tail,
+}
+
+impl<Head, Tail> InValueLink<Head, Tail>
+where
+    Head: G::InValueList,
+    Tail: G::RowValuePredicand,
+{
+    pub fn new(head: Head, tail: Tail) -> Self {
+        Self { head, tail }
+    }
+}
+
+impl<Head, Tail> std::fmt::Display for InValueLink<Head, Tail>
+where
+    Head: G::InValueList + std::fmt::Display,
+    Tail: G::RowValuePredicand + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}", self.head, self.tail)
+    }
+}
+
+impl<'q, DB, Head, Tail> ToQuery<'q, DB> for InValueLink<Head, Tail>
+where
+    DB: Database,
+    Head: G::InValueList + ToQuery<'q, DB>,
+    Tail: G::RowValuePredicand + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> ::std::fmt::Result {
+        self.head.write(ctx)?;
+        write!(ctx, ", ")?;
+        self.tail.write(ctx)
+    }
+}
@@ -1,8 +1,11 @@
 use crate::{
     blank::Blank,
+    contextually_typed_row_value_expression_list::{BoundRow, BoundRows},
     either::Either,
     from_constructor::FromConstructor,
-    grammar::{self, InsertColumnsAndSources, InsertionTarget, OverrideClause},
+    from_default::FromDefault,
+    from_subquery::FromSubQuery,
+    grammar::{InsertColumnsAndSources, InsertionTarget, OverrideClause},
     ToQuery,
 };
 use sql_builder_macros::Insert;
@@ -13,66 +16,87 @@ use crate::helpers as H;
 use crate::Database;
 
 #[derive(Clone, Copy, Insert)]
-pub struct Insert<Target, Values>
+/// Represents an insert statement.
+/// See [self::insert]
+pub struct Insert<With, Target, Values, Returning>
 where
+    With: G::WithClause,
     Target: G::InsertionTarget,
     Values: G::InsertColumnsAndSources,
+    Returning: G::ReturningClause,
 {
-    target: Target,
-    values: Values,
+    pub with_clause: With,
+    pub target: Target,
+    pub values: Values,
+    pub returning: Returning,
 }
 
-impl<Target, Values> H::Insert for Insert<Target, Values>
+impl<With, Target, Values, Returning> H::Insert for Insert<With, Target, Values, Returning>
 where
-    Target: InsertionTarget,
-    Values: InsertColumnsAndSources,
+    With: G::WithClause,
+    Target: G::InsertionTarget,
+    Values: G::InsertColumnsAndSources,
+    Returning: G::ReturningClause,
 {
+    type WithClause = With;
     type Target = Target;
     type ColumnsAndSources = Values;
+    type ReturningClause = Returning;
 
-    fn transform_target<NewTarget: InsertionTarget>(
-        self,
-        transform: impl FnOnce(Self::Target) -> NewTarget,
-    ) -> impl grammar::Insert {
-        Insert {
-            target: transform(self.target),
-            values: self.values,
-        }
-    }
-
-    /// Transform the columns and sources
-    fn transform_columns_and_sources<NewColumnsAndSources: InsertColumnsAndSources>(
-        self,
-        transform: impl FnOnce(Self::ColumnsAndSources) -> NewColumnsAndSources,
-    ) -> impl grammar::Insert {
-        Insert {
-            target: self.target,
-            values: transform(self.values),
-        }
+    #[inline]
+    fn unwrap(self) -> Self {
+        self
     }
 }
 
-impl<Target, Values> std::fmt::Display for Insert<Target, Values>
+impl<With, Target, Values, Returning> std::fmt::Display for Insert<With, Target, Values, Returning>
 where
+    With: G::WithClause + std::fmt::Display,
     Target: InsertionTarget + std::fmt::Display,
     Values: InsertColumnsAndSources + std::fmt::Display,
+    Returning: G::ReturningClause + std::fmt::Display,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "INSERT INTO {} {}", self.target, self.values)
+        if With::IS_IMPL {
+            write!(f, "{} ", self.with_clause)?;
+        }
+
+        write!(f, "INSERT INTO {} {}", self.target, self.values)?;
+
+        if Returning::IS_IMPL {
+            write!(f, " {}", self.returning)?;
+        }
+
+        Ok(())
     }
 }
 
-impl<'q, DB, Target, Values> ToQuery<'q, DB> for Insert<Target, Values>
+impl<'q, DB, With, Target, Values, Returning> ToQuery<'q, DB>
+    for Insert<With, Target, Values, Returning>
 where
     DB: Database,
+    With: G::WithClause + ToQuery<'q, DB>,
     Target: InsertionTarget + ToQuery<'q, DB>,
     Values: InsertColumnsAndSources + ToQuery<'q, DB>,
+    Returning: G::ReturningClause + ToQuery<'q, DB>,
 {
     fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        if With::IS_IMPL {
+            self.with_clause.write(ctx)?;
+            write!(ctx, " ")?;
+        }
+
         write!(ctx, "INSERT INTO ")?;
         self.target.write(ctx)?;
         write!(ctx, " ")?;
-        self.values.write(ctx)
+        self.values.write(ctx)?;
+
+        if Returning::IS_IMPL {
+            write!(ctx, " ")?;
+            self.returning.write(ctx)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -81,29 +105,124 @@ where
     Lhs: G::Insert,
     Rhs: G::Insert,
 {
+    type WithClause = Either<Lhs::WithClause, Rhs::WithClause>;
     type Target = Either<Lhs::Target, Rhs::Target>;
     type ColumnsAndSources = Either<Lhs::ColumnsAndSources, Rhs::ColumnsAndSources>;
+    type ReturningClause = Either<Lhs::ReturningClause, Rhs::ReturningClause>;
 
-    fn transform_target<NewTarget: grammar::InsertionTarget>(
+    fn unwrap(
         self,
-        transform: impl FnOnce(Self::Target) -> NewTarget,
-    ) -> impl grammar::Insert {
-        self.apply_with_args(
-            transform,
-            |lhs, transform| lhs.transform_target(|a| transform(Either::Left(a))),
-            |rhs, transform| rhs.transform_target(|a| transform(Either::Right(a))),
-        )
+    ) -> Insert<Self::WithClause, Self::Target, Self::ColumnsAndSources, Self::ReturningClause>
+    {
+        match self {
+            Either::Left(lhs) => {
+                let Insert {
+                    with_clause,
+                    target,
+                    values,
+                    returning,
+                } = lhs.unwrap();
+
+                Insert {
+                    with_clause: Either::Left(with_clause),
+                    target: Either::Left(target),
+                    values: Either::Left(values),
+                    returning: Either::Left(returning),
+                }
+            }
+            Either::Right(rhs) => {
+                let Insert {
+                    with_clause,
+                    target,
+                    values,
+                    returning,
+                } = rhs.unwrap();
+
+                Insert {
+                    with_clause: Either::Right(with_clause),
+                    target: Either::Right(target),
+                    values: Either::Right(values),
+                    returning: Either::Right(returning),
+                }
+            }
+        }
     }
+}
 
-    fn transform_columns_and_sources<NewColumnsAndSources: grammar::InsertColumnsAndSources>(
-        self,
-        transform: impl FnOnce(Self::ColumnsAndSources) -> NewColumnsAndSources,
-    ) -> impl grammar::Insert {
-        self.apply_with_args(
-            transform,
-            |lhs, transform| lhs.transform_columns_and_sources(|a| transform(Either::Left(a))),
-            |rhs, transform| rhs.transform_columns_and_sources(|a| transform(Either::Right(a))),
-        )
+/// An insertion of rows known at runtime.
+///
+/// See [InsertFromConstructorFragment::values_from_iter]
+pub type InsertFromIter<Target, Columns, Override, Row> =
+    Insert<Blank, Target, FromConstructor<Columns, Override, BoundRows<Row>>, Blank>;
+
+impl<Target, Columns, Override, Row> InsertFromIter<Target, Columns, Override, Row>
+where
+    Target: G::InsertionTarget + Clone,
+    Columns: G::InsertColumnList + Clone,
+    Override: G::OverrideClause + Clone,
+    Row: BoundRow,
+{
+    /// Split the insertion in several statements, each binding at most `max_parameters` values.
+    ///
+    /// Fails if a single row binds more values than the limit. See
+    /// [Insert::batches_for] to use the limit of the target database.
+    ///
+    /// Only the rows are counted against the limit, so the with and returning
+    /// clauses are added to each statement afterwards.
+    ///
+    /// # Example
+    /// ```
+    /// use sql_builder::{columns, id, insert, prelude::*};
+    ///
+    /// let rows = (0..5).map(|i| (i, i * 2));
+    ///
+    /// let stmts = insert(id!(points))
+    ///     .columns(columns!(id!(x), id!(y)))
+    ///     .values_from_iter(rows)
+    ///     .unwrap()
+    ///     .batches(4)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(stmts.len(), 3);
+    /// assert_eq!(stmts[0].to_string(), "INSERT INTO points (x, y) VALUES (?, ?), (?, ?)");
+    /// assert_eq!(stmts[2].to_string(), "INSERT INTO points (x, y) VALUES (?, ?)");
+    /// ```
+    pub fn batches(self, max_parameters: usize) -> Result<Vec<Self>, crate::Error> {
+        Ok(self
+            .values
+            .batches(max_parameters)?
+            .into_iter()
+            .map(|values| Insert {
+                with_clause: Blank,
+                target: self.target.clone(),
+                values,
+                returning: Blank,
+            })
+            .collect())
+    }
+
+    /// Split the insertion in several statements, each binding at most the
+    /// number of parameters the database accepts, see [crate::MaxParameters].
+    ///
+    /// # Example
+    /// ```
+    /// use sql_builder::{columns, id, insert, prelude::*};
+    /// use sqlx::Any;
+    ///
+    /// let stmts = insert(id!(points))
+    ///     .columns(columns!(id!(x), id!(y)))
+    ///     .values_from_iter((0..20000).map(|i| (i, i * 2)))
+    ///     .unwrap()
+    ///     .batches_for::<Any>()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(stmts.len(), 2);
+    /// ```
+    pub fn batches_for<DB>(self) -> Result<Vec<Self>, crate::Error>
+    where
+        DB: crate::MaxParameters,
+    {
+        self.batches(DB::MAX_PARAMETERS)
     }
 }
 
@@ -132,6 +251,36 @@ where
             columns,
         }
     }
+
+    /// Insert the rows returned by the query, in the columns of the table.
+    ///
+    /// This creates a valid insert command.
+    pub fn select<Query>(
+        self,
+        query: Query,
+    ) -> Insert<Blank, Target, FromSubQuery<Blank, Blank, Query>, Blank>
+    where
+        Query: G::QueryExpression,
+    {
+        Insert {
+            with_clause: Blank,
+            target: self.target,
+            values: FromSubQuery::new(Blank, Blank, query),
+            returning: Blank,
+        }
+    }
+
+    /// Insert a single row, made of the default values of the columns.
+    ///
+    /// This creates a valid insert command.
+    pub fn default_values(self) -> Insert<Blank, Target, FromDefault, Blank> {
+        Insert {
+            with_clause: Blank,
+            target: self.target,
+            values: FromDefault,
+            returning: Blank,
+        }
+    }
 }
 
 pub struct InsertFromConstructorFragment<Target, Override, Columns>
@@ -168,13 +317,75 @@ where
     pub fn values<Value>(
         self,
         values: Value,
-    ) -> Insert<Target, FromConstructor<Columns, Override, Value>>
+    ) -> Insert<Blank, Target, FromConstructor<Columns, Override, Value>, Blank>
     where
         Value: G::ContextuallyTypedTableValueConstructor,
     {
         Insert {
+            with_clause: Blank,
             target: self.target,
             values: FromConstructor::new(self.columns, self.override_clause, values),
+            returning: Blank,
+        }
+    }
+
+    /// Set the rows to be inserted in the table, the rows are only known at runtime.
+    ///
+    /// Each value of the rows is bound to its own dynamic parameter. The
+    /// statement can be split with [Insert::batches] to not exceed the number
+    /// of parameters the database accepts in a single statement.
+    ///
+    /// Returns None if the iterator yields no row, as there would be nothing to insert.
+    ///
+    /// # Example
+    /// ```
+    /// use sql_builder::{columns, id, insert, prelude::*};
+    ///
+    /// let users = vec![("jdoe", 32), ("asmith", 27)];
+    ///
+    /// let stmt = insert(id!(users))
+    ///     .columns(columns!(id!(name), id!(age)))
+    ///     .values_from_iter(users)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(stmt.to_string(), "INSERT INTO users (name, age) VALUES (?, ?), (?, ?)");
+    /// ```
+    pub fn values_from_iter<Row>(
+        self,
+        rows: impl IntoIterator<Item = Row>,
+    ) -> Option<InsertFromIter<Target, Columns, Override, Row>>
+    where
+        Row: BoundRow,
+    {
+        BoundRows::new(rows).map(|rows| self.values(rows))
+    }
+
+    /// Insert the rows returned by the query
+    ///
+    /// This creates a valid insert command.
+    ///
+    /// # Example
+    /// ```
+    /// use sql_builder::{columns, id, insert, select, select_columns, prelude::*};
+    ///
+    /// let stmt = insert(id!(archive))
+    ///     .columns(columns!(id!(id), id!(name)))
+    ///     .select(select(select_columns!(id!(id), id!(name))).from(id!(users)));
+    ///
+    /// assert_eq!(stmt.to_string(), "INSERT INTO archive (id, name) SELECT id, name FROM users");
+    /// ```
+    pub fn select<Query>(
+        self,
+        query: Query,
+    ) -> Insert<Blank, Target, FromSubQuery<Columns, Override, Query>, Blank>
+    where
+        Query: G::QueryExpression,
+    {
+        Insert {
+            with_clause: Blank,
+            target: self.target,
+            values: FromSubQuery::new(self.columns, self.override_clause, query),
+            returning: Blank,
         }
     }
 }
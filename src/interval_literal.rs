@@ -0,0 +1,110 @@
+use crate::{data_type::IntervalQualifier, Database, ToQuery};
+use sql_builder_macros::IntervalLiteral;
+use std::fmt::Write;
+
+#[derive(Debug, Clone, PartialEq, Eq, IntervalLiteral)]
+/// INTERVAL '<interval string>' <interval qualifier>
+///
+/// See [self::interval_lit]
+pub struct IntervalLiteral {
+    value: String,
+    qualifier: IntervalQualifier,
+}
+
+impl IntervalLiteral {
+    /// A day-time interval, DAY TO SECOND.
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn day_to_second(negative: bool, seconds: u64, nanos: u32) -> Self {
+        let mut value = format!(
+            "{}{} {:02}:{:02}:{:02}",
+            if negative { "-" } else { "" },
+            seconds / 86_400,
+            seconds % 86_400 / 3_600,
+            seconds % 3_600 / 60,
+            seconds % 60
+        );
+
+        if nanos != 0 {
+            let nanos = format!("{:09}", nanos);
+            value.push('.');
+            value.push_str(nanos.trim_end_matches('0'));
+        }
+
+        Self {
+            value,
+            qualifier: IntervalQualifier {
+                start: crate::data_type::DatetimeField::Day,
+                end: Some(crate::data_type::DatetimeField::Second),
+            },
+        }
+    }
+}
+
+impl ::std::fmt::Display for IntervalLiteral {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "INTERVAL '{}' {}",
+            self.value.replace('\'', "''"),
+            self.qualifier
+        )
+    }
+}
+
+impl<'q, DB> ToQuery<'q, DB> for IntervalLiteral
+where
+    DB: Database,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "{}", self)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::TimeDelta> for IntervalLiteral {
+    fn from(value: chrono::TimeDelta) -> Self {
+        let abs = value.abs();
+        Self::day_to_second(
+            value < chrono::TimeDelta::zero(),
+            abs.num_seconds().unsigned_abs(),
+            abs.subsec_nanos().unsigned_abs(),
+        )
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Duration> for IntervalLiteral {
+    fn from(value: time::Duration) -> Self {
+        Self::day_to_second(
+            value.is_negative(),
+            value.whole_seconds().unsigned_abs(),
+            value.subsec_nanoseconds().unsigned_abs(),
+        )
+    }
+}
+
+#[inline]
+/// Creates an interval literal.
+///
+/// Durations of the `chrono` and `time` crates can be converted with
+/// [IntervalLiteral::from], as a DAY TO SECOND interval.
+///
+/// # Example
+/// ```
+/// use sql_builder::{data_type::{DatetimeField, IntervalQualifier}, interval_lit};
+///
+/// let qualifier = IntervalQualifier::range(DatetimeField::Day, DatetimeField::Second).unwrap();
+/// assert_eq!(
+///     interval_lit("1 12:00:00", qualifier).to_string(),
+///     "INTERVAL '1 12:00:00' DAY TO SECOND"
+/// );
+/// ```
+pub fn interval_lit<V>(value: V, qualifier: IntervalQualifier) -> IntervalLiteral
+where
+    V: Into<String>,
+{
+    IntervalLiteral {
+        value: value.into(),
+        qualifier,
+    }
+}
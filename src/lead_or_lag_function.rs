@@ -0,0 +1,144 @@
+use crate::{blank::Blank, grammar as G, Database, ToQuery};
+use sql_builder_macros::WindowFunctionType;
+use std::fmt::Write;
+
+#[derive(Clone, Copy)]
+/// <lead or lag>
+pub enum LeadOrLagKind {
+    Lead,
+    Lag,
+}
+
+impl AsRef<str> for LeadOrLagKind {
+    fn as_ref(&self) -> &str {
+        match self {
+            LeadOrLagKind::Lead => "LEAD",
+            LeadOrLagKind::Lag => "LAG",
+        }
+    }
+}
+
+impl std::fmt::Display for LeadOrLagKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Clone, Copy, WindowFunctionType)]
+/// <lead or lag> (<lead or lag extent> [, <offset> [, <default expression> ] ])
+///
+/// See [self::lead] and [self::lag]
+pub struct LeadOrLag<Value, Default>
+where
+    Value: G::ValueExpression,
+    Default: G::LeadOrLagDefault,
+{
+    kind: LeadOrLagKind,
+    value: Value,
+    offset: Option<u64>,
+    default: Default,
+}
+
+impl<Value, Default> LeadOrLag<Value, Default>
+where
+    Value: G::ValueExpression,
+    Default: G::LeadOrLagDefault,
+{
+    /// Set the number of rows between the current row and the accessed one.
+    pub fn offset(self, offset: u64) -> Self {
+        Self {
+            offset: Some(offset),
+            ..self
+        }
+    }
+
+    /// Set the value returned when the accessed row is out of the partition.
+    pub fn default_value<NewDefault>(self, default: NewDefault) -> LeadOrLag<Value, NewDefault>
+    where
+        NewDefault: G::LeadOrLagDefault,
+    {
+        LeadOrLag {
+            kind: self.kind,
+            value: self.value,
+            offset: self.offset,
+            default,
+        }
+    }
+}
+
+impl<Value, Default> ::std::fmt::Display for LeadOrLag<Value, Default>
+where
+    Value: G::ValueExpression + std::fmt::Display,
+    Default: G::LeadOrLagDefault + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({}", self.kind, self.value)?;
+
+        if Default::IS_IMPL {
+            write!(f, ", {}, {}", self.offset.unwrap_or(1), self.default)?;
+        } else if let Some(offset) = self.offset {
+            write!(f, ", {}", offset)?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+impl<'q, DB, Value, Default> ToQuery<'q, DB> for LeadOrLag<Value, Default>
+where
+    DB: Database,
+    Value: G::ValueExpression + ToQuery<'q, DB>,
+    Default: G::LeadOrLagDefault + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "{}(", self.kind)?;
+        self.value.write(ctx)?;
+
+        if Default::IS_IMPL {
+            write!(ctx, ", {}, ", self.offset.unwrap_or(1))?;
+            self.default.write(ctx)?;
+        } else if let Some(offset) = self.offset {
+            write!(ctx, ", {}", offset)?;
+        }
+
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Accesses the value of a following row of the window partition.
+///
+/// # Example
+/// ```
+/// use sql_builder::{id, lead, lit, window, prelude::*};
+///
+/// let expr = lead(id!(price)).offset(2).default_value(lit!(0)).over(window().order_by(id!(day)));
+/// assert_eq!(expr.to_string(), "LEAD(price, 2, 0) OVER (ORDER BY day)");
+/// ```
+pub fn lead<Value>(value: Value) -> LeadOrLag<Value, Blank>
+where
+    Value: G::ValueExpression,
+{
+    LeadOrLag {
+        kind: LeadOrLagKind::Lead,
+        value,
+        offset: None,
+        default: Blank,
+    }
+}
+
+#[inline]
+/// Accesses the value of a preceding row of the window partition.
+///
+/// See [self::lead]
+pub fn lag<Value>(value: Value) -> LeadOrLag<Value, Blank>
+where
+    Value: G::ValueExpression,
+{
+    LeadOrLag {
+        kind: LeadOrLagKind::Lag,
+        value,
+        offset: None,
+        default: Blank,
+    }
+}
@@ -9,7 +9,7 @@
 //! (direct SELECT, INSERT, UPDATE and DELETE commands).
 //!
 //! # How to build a SELECT query
-//! ```ignore
+//! ```
 //! use sql_builder::{select, id, prelude::*};
 //!
 //! let selected_columns = id!(col1)
@@ -19,11 +19,48 @@
 //!
 //! let stmt = select(selected_columns).from(table);
 //!
-//! let sql = sel.to_raw_query().unwrap();
+//! let sql = stmt.to_string();
 //! assert_eq!(sql, "SELECT col1, col2 AS aliased_column, col3 FROM my_table");
 //! ```
 //!
 //! # How to build an INSERT query
+//! ```
+//! use sql_builder::{insert, columns, id, lit, bind, row_value, prelude::*};
+//!
+//! # let name = "jdoe";
+//! let stmt = insert(id!(my_table))
+//!     .columns(columns!(id!(col1), id!(col2)))
+//!     .values(row_value!(lit!(10), bind!(name)));
+//!
+//! let sql = stmt.to_string();
+//! assert_eq!(sql, "INSERT INTO my_table (col1, col2) VALUES (10, ?)");
+//! ```
+//!
+//! # How to build an UPDATE query
+//! ```
+//! use sql_builder::{update, id, lit, bind, eq, prelude::*};
+//!
+//! # let value = 20;
+//! # let id = 1;
+//! let stmt = update(id!(my_table))
+//!     .set(id!(col1), lit!(10))
+//!     .set(id!(col2), bind!(value))
+//!     .r#where(eq(id!(id), bind!(id)));
+//!
+//! let sql = stmt.to_string();
+//! assert_eq!(sql, "UPDATE my_table SET col1 = 10, col2 = ? WHERE id = ?");
+//! ```
+//!
+//! # How to build a DELETE query
+//! ```
+//! use sql_builder::{delete_from, id, bind, eq, prelude::*};
+//!
+//! # let id = 1;
+//! let stmt = delete_from(id!(my_table)).r#where(eq(id!(id), bind!(id)));
+//!
+//! let sql = stmt.to_string();
+//! assert_eq!(sql, "DELETE FROM my_table WHERE id = ?");
+//! ```
 //!
 pub mod error;
 
@@ -38,42 +75,134 @@ pub mod identifier;
 pub mod numeric_value_expression;
 pub mod term;
 pub mod where_clause;
+pub mod window_clause;
+pub mod window_definition;
+pub mod window_definition_list;
+pub mod window_frame_clause;
+pub mod window_function;
+pub mod window_partition_clause;
+pub mod window_partition_column_reference_list;
+pub mod window_specification;
+pub mod with_clause;
+pub mod with_list;
+pub mod with_list_element;
 
 pub mod derived_column;
+pub mod derived_table;
+pub mod distinct_predicate;
+pub mod else_clause;
+pub mod exists_predicate;
+pub mod exponential_function;
+pub mod extract_expression;
+pub mod factor;
 pub mod from_clause;
 
+pub mod absolute_value_expression;
+pub mod aggregate_function;
 pub mod asterisk;
+pub mod between_predicate;
 pub mod blank;
 pub mod boolean_factor;
 pub mod boolean_primary;
 pub mod boolean_term;
 pub mod boolean_test;
+pub mod case_abbreviation;
+pub mod cast_specification;
+pub mod ceiling_function;
+pub mod char_length_expression;
+pub mod character_overlay_function;
 pub mod character_string_literal;
+pub mod character_substring_function;
+pub mod collate_clause;
 pub mod column_name_list;
 pub mod comparison_predicate;
+pub mod concatenation;
 pub mod contextually_typed_row_value_constructor;
 pub mod contextually_typed_row_value_constructor_element_list;
 pub mod contextually_typed_row_value_expression_list;
+pub mod corresponding_spec;
 pub mod cross_join;
+pub mod cube_list;
+pub mod data_type;
+pub mod date_literal;
+pub mod datetime_value_expression;
+pub mod datetime_value_function;
+pub mod default_specification;
+pub mod delete;
 pub mod either;
+pub mod empty_grouping_set;
+pub mod fetch_first_clause;
+pub mod filter_clause;
+pub mod floor_function;
+pub mod fold;
 pub mod from_constructor;
+pub mod from_default;
+pub mod from_subquery;
+pub mod grouping_column_reference_list;
+pub mod grouping_element_list;
+pub mod grouping_sets_specification;
 pub mod having_clause;
 pub mod identifier_chain;
+pub mod in_predicate;
+pub mod in_predicate_value;
+pub mod in_value_list;
 pub mod insert;
+pub mod interval_literal;
 pub mod join_condition;
 pub mod join_type;
+pub mod lead_or_lag_function;
+pub mod like_predicate;
+pub mod modulus_expression;
 pub mod named_columns_join;
 pub mod natural_join;
+pub mod natural_logarithm;
+pub mod non_join_query_expression;
+pub mod non_join_query_primary;
+pub mod non_join_query_term;
+pub mod null_ordering;
+pub mod null_predicate;
+pub mod octet_length_expression;
+pub mod order_by;
+pub mod ordering_specification;
+pub mod ordinary_grouping_set;
+pub mod ordinary_grouping_set_list;
+pub mod power_function;
 pub mod qualified_join;
+pub mod quantified_comparison_predicate;
+pub mod query_expression;
+pub mod rank_function;
+pub mod result_offset_clause;
+pub mod returning_clause;
+pub mod rollup_list;
 pub mod routine_invocation;
 pub mod schema_name;
 pub mod search_condition;
+pub mod searched_case;
+pub mod searched_when_clause;
+pub mod searched_when_clause_list;
+pub mod set_clause;
+pub mod set_clause_list;
 pub mod signed_numeric_literal;
+pub mod similar_predicate;
+pub mod simple_case;
+pub mod simple_when_clause;
+pub mod simple_when_clause_list;
+pub mod sort_specification;
+pub mod sort_specification_list;
 pub mod sql_argument_list;
+pub mod square_root;
+pub mod string_position_expression;
+pub mod subquery;
+pub mod time_literal;
+pub mod timestamp_literal;
+pub mod trim_function;
 pub mod truth_value;
 pub mod union_join;
+pub mod unique_predicate;
 pub mod unqualified_schema_name;
 pub mod unsigned_numeric_literal;
+pub mod update;
+pub mod width_bucket_function;
 
 use sqlx::Arguments as _;
 pub use sqlx::Database;
@@ -139,23 +268,110 @@ where
     fn write(&'q self, ctx: &mut ToQueryContext<'q, DB>) -> std::fmt::Result;
 }
 
+/// The maximum number of dynamic parameters the database accepts in a single statement.
+///
+/// See [crate::insert::Insert::batches_for]
+pub trait MaxParameters: Database {
+    const MAX_PARAMETERS: usize;
+}
+
+impl MaxParameters for sqlx::Any {
+    /// The lowest limit among the databases [sqlx::Any] can connect to.
+    const MAX_PARAMETERS: usize = 32766;
+}
+
+#[cfg(feature = "postgres")]
+impl MaxParameters for sqlx::Postgres {
+    const MAX_PARAMETERS: usize = 65535;
+}
+
+#[cfg(feature = "mysql")]
+impl MaxParameters for sqlx::MySql {
+    const MAX_PARAMETERS: usize = 65535;
+}
+
+#[cfg(feature = "sqlite")]
+impl MaxParameters for sqlx::Sqlite {
+    const MAX_PARAMETERS: usize = 32766;
+}
+
+pub use absolute_value_expression::abs;
+pub use aggregate_function::{avg, count, every, max, min, sum};
+pub use between_predicate::{between, not_between};
 pub use bind::bind;
 pub use boolean_factor::not;
 pub use boolean_term::and;
 pub use boolean_test::{is_not_truth_value, is_truth_value};
+pub use case_abbreviation::{coalesce, nullif};
+pub use cast_specification::cast;
+pub use ceiling_function::ceiling;
+pub use char_length_expression::char_length;
+pub use character_overlay_function::overlay;
 pub use character_string_literal::char_str_lit;
+pub use character_substring_function::substring;
 pub use comparison_predicate::{eq, gt, gte, lt, lte, neq};
+pub use concatenation::concat;
+pub use cube_list::cube;
+pub use data_type::DataType;
+pub use date_literal::date_lit;
+pub use datetime_value_expression::{add_interval, sub_interval};
+pub use datetime_value_function::{
+    current_date, current_time, current_timestamp, localtime, localtimestamp,
+};
+pub use default_specification::default;
+pub use delete::delete_from;
+pub use distinct_predicate::{is_distinct_from, is_not_distinct_from};
+pub use empty_grouping_set::EmptyGroupingSet;
 pub use error::Error;
+pub use exists_predicate::exists;
+pub use exponential_function::exp;
+pub use extract_expression::extract;
+pub use factor::neg;
+pub use floor_function::floor;
+pub use fold::{lower, upper};
+pub use grouping_sets_specification::grouping_sets;
 pub use identifier::id;
+pub use in_predicate::{in_bound, in_list, in_subquery, not_in, not_in_bound, not_in_subquery};
 pub use insert::insert;
+pub use interval_literal::interval_lit;
+pub use lead_or_lag_function::{lag, lead};
+pub use like_predicate::{like, not_like};
+pub use modulus_expression::r#mod;
+pub use natural_logarithm::ln;
+pub use null_predicate::{is_not_null, is_null};
 pub use numeric_value_expression::{add, sub};
+pub use octet_length_expression::octet_length;
+pub use ordinary_grouping_set::grouping_columns;
+pub use power_function::power;
+pub use quantified_comparison_predicate::{
+    eq_all, eq_any, gt_all, gt_any, gte_all, gte_any, lt_all, lt_any, lte_all, lte_any, neq_all,
+    neq_any, quantified,
+};
+pub use rank_function::{cume_dist, dense_rank, percent_rank, rank, row_number};
+pub use rollup_list::rollup;
 pub use search_condition::or;
+pub use searched_case::case;
 pub use select::select;
 pub use signed_numeric_literal::signed_numeric_lit;
-pub use sql_builder_macros::{bind, columns, id, invoke, lit, row_value, select_columns};
+pub use similar_predicate::{not_similar_to, similar_to};
+pub use simple_case::simple_case;
+pub use sql_builder_macros::{
+    bind, columns, id, in_values, invoke, lit, row_value, select_columns,
+};
+pub use square_root::sqrt;
+pub use string_position_expression::position;
 pub use term::{div, mult};
+pub use time_literal::time_lit;
+pub use timestamp_literal::timestamp_lit;
+pub use trim_function::trim;
 pub use truth_value::{False, True, Unknown};
+pub use unique_predicate::unique;
 pub use unsigned_numeric_literal::unsigned_numeric_lit;
+pub use update::update;
+pub use width_bucket_function::width_bucket;
+pub use window_definition::define_window;
+pub use window_specification::window;
+pub use with_clause::{with, with_recursive};
 
 sql_builder_macros::check_symbol_loops!();
 
@@ -192,15 +408,56 @@ pub mod prelude {
 
 pub mod helpers {
     use crate::{
-        boolean_primary::NestedSearchCondition, boolean_term::And,
-        column_name_list::ColumnNameLink, contextually_typed_row_value_constructor::RowValue,
+        blank::Blank,
+        boolean_primary::NestedSearchCondition,
+        boolean_term::And,
+        collate_clause::Collate,
+        column_name_list::ColumnNameLink,
+        contextually_typed_row_value_constructor::RowValue,
         contextually_typed_row_value_constructor_element_list::RowElementLink,
         contextually_typed_row_value_expression_list::ContextuallyTypedRowExpressionLink,
-        cross_join::CrossJoin, derived_column::AliasedColumn, grammar as G,
-        identifier_chain::IdentifierLink, join_type::Inner, qualified_join::QualifiedJoinFragment,
-        search_condition::Or, select::Select, select_sublist::SelectLink,
-        sql_argument_list::SQLArgumentLink, table_expression::TableExpr,
-        table_reference_list::TableReferenceLink, where_clause::Where,
+        cross_join::CrossJoin,
+        delete::Delete,
+        derived_column::AliasedColumn,
+        derived_table::DerivedTable,
+        distinct_predicate::IsDistinctFrom,
+        fetch_first_clause::{FetchFirst, Limit},
+        grammar as G,
+        group_by::GroupBy,
+        grouping_column_reference_list::GroupingColumnReferenceLink,
+        grouping_element_list::GroupingElementLink,
+        having_clause::Having,
+        identifier_chain::IdentifierLink,
+        in_value_list::InValueLink,
+        join_type::Inner,
+        non_join_query_expression::{SetOperation, SetOperator},
+        non_join_query_term::Intersect,
+        null_ordering::{NullsFirst, NullsLast},
+        null_predicate::IsNull,
+        order_by::OrderBy,
+        ordering_specification::{Asc, Desc},
+        ordinary_grouping_set_list::OrdinaryGroupingSetLink,
+        qualified_join::QualifiedJoinFragment,
+        query_expression::QueryExpr,
+        returning_clause::Returning,
+        search_condition::Or,
+        select::Select,
+        select_sublist::SelectLink,
+        set_clause::SetClause,
+        set_clause_list::SetClauseLink,
+        sort_specification::SortSpec,
+        sort_specification_list::SortSpecificationLink,
+        sql_argument_list::SQLArgumentLink,
+        subquery::Subquery,
+        table_expression::TableExpr,
+        table_reference_list::TableReferenceLink,
+        update::Update,
+        where_clause::Where,
+        window_clause::WindowClause,
+        window_definition_list::WindowDefinitionLink,
+        window_function::Over,
+        window_partition_column_reference_list::WindowPartitionColumnLink,
+        with_list::WithListLink,
     };
 
     pub type QuerySpecificationWithTransformedWhere<Qs, SearchCond> = Select<
@@ -210,9 +467,195 @@ pub mod helpers {
             Where<SearchCond>,
             <<Qs as QuerySpecification>::TableExpression as TableExpression>::GroupByClause,
             <<Qs as QuerySpecification>::TableExpression as TableExpression>::HavingClause,
+            <<Qs as QuerySpecification>::TableExpression as TableExpression>::WindowClause,
+        >,
+    >;
+
+    pub type UpdateStatementWithAddedSetClause<Upd, Column, Value> = Update<
+        <Upd as UpdateStatement>::WithClause,
+        <Upd as UpdateStatement>::Target,
+        SetClauseLink<<Upd as UpdateStatement>::SetClauseList, SetClause<Column, Value>>,
+        <Upd as UpdateStatement>::WhereClause,
+        <Upd as UpdateStatement>::ReturningClause,
+    >;
+
+    pub type UpdateStatementUnwrapped<Upd> = Update<
+        <Upd as UpdateStatement>::WithClause,
+        <Upd as UpdateStatement>::Target,
+        <Upd as UpdateStatement>::SetClauseList,
+        <Upd as UpdateStatement>::WhereClause,
+        <Upd as UpdateStatement>::ReturningClause,
+    >;
+
+    pub type UpdateStatementWithTransformedWith<Upd, With> = Update<
+        With,
+        <Upd as UpdateStatement>::Target,
+        <Upd as UpdateStatement>::SetClauseList,
+        <Upd as UpdateStatement>::WhereClause,
+        <Upd as UpdateStatement>::ReturningClause,
+    >;
+
+    pub type UpdateStatementWithTransformedWhere<Upd, Where> = Update<
+        <Upd as UpdateStatement>::WithClause,
+        <Upd as UpdateStatement>::Target,
+        <Upd as UpdateStatement>::SetClauseList,
+        Where,
+        <Upd as UpdateStatement>::ReturningClause,
+    >;
+
+    pub type UpdateStatementWithTransformedReturning<Upd, Returning> = Update<
+        <Upd as UpdateStatement>::WithClause,
+        <Upd as UpdateStatement>::Target,
+        <Upd as UpdateStatement>::SetClauseList,
+        <Upd as UpdateStatement>::WhereClause,
+        Returning,
+    >;
+
+    pub type QuerySpecificationWithTransformedGroupBy<Qs, Elements> = Select<
+        <Qs as QuerySpecification>::SelectList,
+        TableExpr<
+            <<Qs as QuerySpecification>::TableExpression as TableExpression>::FromClause,
+            <<Qs as QuerySpecification>::TableExpression as TableExpression>::WhereClause,
+            GroupBy<Elements>,
+            <<Qs as QuerySpecification>::TableExpression as TableExpression>::HavingClause,
+            <<Qs as QuerySpecification>::TableExpression as TableExpression>::WindowClause,
+        >,
+    >;
+
+    pub type QuerySpecificationWithTransformedHaving<Qs, SearchCond> = Select<
+        <Qs as QuerySpecification>::SelectList,
+        TableExpr<
+            <<Qs as QuerySpecification>::TableExpression as TableExpression>::FromClause,
+            <<Qs as QuerySpecification>::TableExpression as TableExpression>::WhereClause,
+            <<Qs as QuerySpecification>::TableExpression as TableExpression>::GroupByClause,
+            Having<SearchCond>,
+            <<Qs as QuerySpecification>::TableExpression as TableExpression>::WindowClause,
+        >,
+    >;
+
+    pub type QuerySpecificationWithTransformedWindow<Qs, Definitions> = Select<
+        <Qs as QuerySpecification>::SelectList,
+        TableExpr<
+            <<Qs as QuerySpecification>::TableExpression as TableExpression>::FromClause,
+            <<Qs as QuerySpecification>::TableExpression as TableExpression>::WhereClause,
+            <<Qs as QuerySpecification>::TableExpression as TableExpression>::GroupByClause,
+            <<Qs as QuerySpecification>::TableExpression as TableExpression>::HavingClause,
+            WindowClause<Definitions>,
         >,
     >;
 
+    pub type TableExpressionUnwrapped<Te> = TableExpr<
+        <Te as TableExpression>::FromClause,
+        <Te as TableExpression>::WhereClause,
+        <Te as TableExpression>::GroupByClause,
+        <Te as TableExpression>::HavingClause,
+        <Te as TableExpression>::WindowClause,
+    >;
+
+    pub type TableExpressionWithTransformedFrom<Te, From> = TableExpr<
+        From,
+        <Te as TableExpression>::WhereClause,
+        <Te as TableExpression>::GroupByClause,
+        <Te as TableExpression>::HavingClause,
+        <Te as TableExpression>::WindowClause,
+    >;
+
+    pub type TableExpressionWithTransformedWhere<Te, Where> = TableExpr<
+        <Te as TableExpression>::FromClause,
+        Where,
+        <Te as TableExpression>::GroupByClause,
+        <Te as TableExpression>::HavingClause,
+        <Te as TableExpression>::WindowClause,
+    >;
+
+    pub type TableExpressionWithTransformedGroupBy<Te, GroupBy> = TableExpr<
+        <Te as TableExpression>::FromClause,
+        <Te as TableExpression>::WhereClause,
+        GroupBy,
+        <Te as TableExpression>::HavingClause,
+        <Te as TableExpression>::WindowClause,
+    >;
+
+    pub type TableExpressionWithTransformedHaving<Te, Having> = TableExpr<
+        <Te as TableExpression>::FromClause,
+        <Te as TableExpression>::WhereClause,
+        <Te as TableExpression>::GroupByClause,
+        Having,
+        <Te as TableExpression>::WindowClause,
+    >;
+
+    pub type TableExpressionWithTransformedWindow<Te, Window> = TableExpr<
+        <Te as TableExpression>::FromClause,
+        <Te as TableExpression>::WhereClause,
+        <Te as TableExpression>::GroupByClause,
+        <Te as TableExpression>::HavingClause,
+        Window,
+    >;
+
+    pub type QueryExpressionUnwrapped<Qe> = QueryExpr<
+        <Qe as QueryExpression>::WithClause,
+        <Qe as QueryExpression>::Body,
+        <Qe as QueryExpression>::OrderByClause,
+        <Qe as QueryExpression>::ResultOffsetClause,
+        <Qe as QueryExpression>::FetchFirstClause,
+    >;
+
+    pub type QueryExpressionWithTransformedWith<Qe, With> = QueryExpr<
+        With,
+        <Qe as QueryExpression>::Body,
+        <Qe as QueryExpression>::OrderByClause,
+        <Qe as QueryExpression>::ResultOffsetClause,
+        <Qe as QueryExpression>::FetchFirstClause,
+    >;
+
+    pub type QueryExpressionWithTransformedOrderBy<Qe, OrderBy> = QueryExpr<
+        <Qe as QueryExpression>::WithClause,
+        <Qe as QueryExpression>::Body,
+        OrderBy,
+        <Qe as QueryExpression>::ResultOffsetClause,
+        <Qe as QueryExpression>::FetchFirstClause,
+    >;
+
+    pub type QueryExpressionWithTransformedOffset<Qe, Offset> = QueryExpr<
+        <Qe as QueryExpression>::WithClause,
+        <Qe as QueryExpression>::Body,
+        <Qe as QueryExpression>::OrderByClause,
+        Offset,
+        <Qe as QueryExpression>::FetchFirstClause,
+    >;
+
+    pub type QueryExpressionWithTransformedFetch<Qe, Fetch> = QueryExpr<
+        <Qe as QueryExpression>::WithClause,
+        <Qe as QueryExpression>::Body,
+        <Qe as QueryExpression>::OrderByClause,
+        <Qe as QueryExpression>::ResultOffsetClause,
+        Fetch,
+    >;
+
+    pub type QueryExpressionWithOffset<Qe, Count> = QueryExpr<
+        <Qe as QueryExpression>::WithClause,
+        <Qe as QueryExpression>::Body,
+        <Qe as QueryExpression>::OrderByClause,
+        <<Qe as QueryExpression>::FetchFirstClause as FetchFirstClause>::ResultOffsetClause<Count>,
+        <<Qe as QueryExpression>::FetchFirstClause as FetchFirstClause>::WithOffset<Count>,
+    >;
+
+    pub type QueryExpressionWithLimit<Qe, Count> = QueryExpr<
+        <Qe as QueryExpression>::WithClause,
+        <Qe as QueryExpression>::Body,
+        <Qe as QueryExpression>::OrderByClause,
+        Blank,
+        <<Qe as QueryExpression>::ResultOffsetClause as ResultOffsetClause>::WithLimit<Count>,
+    >;
+
+    pub type QueryExpressionWithLimitOffset<Qe, Count, OffsetCount> = QueryExpr<
+        <Qe as QueryExpression>::WithClause,
+        <Qe as QueryExpression>::Body,
+        <Qe as QueryExpression>::OrderByClause,
+        Blank,
+        Limit<Count, OffsetCount>,
+    >;
+
     pub trait QuerySpecification: Sized {
         type SelectList: G::SelectList;
         type TableExpression: G::TableExpression;
@@ -260,6 +703,42 @@ pub mod helpers {
             self.transform_table_expression(|expr| expr.r#where(cond))
         }
 
+        /// Group the rows.
+        fn group_by<Elements>(
+            self,
+            elements: Elements,
+        ) -> QuerySpecificationWithTransformedGroupBy<Self, Elements>
+        where
+            Self: G::QuerySpecification,
+            Elements: G::GroupingElementList,
+        {
+            self.transform_table_expression(|expr| expr.group_by(elements))
+        }
+
+        /// Set the condition to filter the groups.
+        fn having<SearchCond>(
+            self,
+            cond: SearchCond,
+        ) -> QuerySpecificationWithTransformedHaving<Self, SearchCond>
+        where
+            Self: G::QuerySpecification,
+            SearchCond: G::SearchCondition,
+        {
+            self.transform_table_expression(|expr| expr.having(cond))
+        }
+
+        /// Define the named windows of the query.
+        fn window<Definitions>(
+            self,
+            definitions: Definitions,
+        ) -> QuerySpecificationWithTransformedWindow<Self, Definitions>
+        where
+            Self: G::QuerySpecification,
+            Definitions: G::WindowDefinitionList,
+        {
+            self.transform_table_expression(|expr| expr.window(definitions))
+        }
+
         /// Transform the table expression
         fn transform_table_expression<NewTableExpr>(
             self,
@@ -282,6 +761,359 @@ pub mod helpers {
         }
     }
 
+    pub trait QueryExpression: Sized {
+        type WithClause: G::WithClause;
+        type Body: G::QueryExpressionBody;
+        type OrderByClause: G::OrderByClause;
+        type ResultOffsetClause: G::ResultOffsetClause;
+        type FetchFirstClause: G::FetchFirstClause;
+        type IntoQueryExpressionBody: G::QueryExpressionBody;
+        type IntoQueryTerm: G::QueryTerm;
+        type IntoQueryPrimary: G::QueryPrimary;
+
+        /// Unwrap the query expression
+        fn unwrap(self) -> QueryExpressionUnwrapped<Self>;
+
+        /// Turn the query into the left operand of an UNION or an EXCEPT,
+        /// parenthesized if required.
+        fn into_query_expression_body(self) -> Self::IntoQueryExpressionBody;
+
+        /// Turn the query into the right operand of an UNION or an EXCEPT,
+        /// or the left operand of an INTERSECT, parenthesized if required.
+        fn into_query_term(self) -> Self::IntoQueryTerm;
+
+        /// Turn the query into the right operand of an INTERSECT,
+        /// parenthesized if required.
+        fn into_query_primary(self) -> Self::IntoQueryPrimary;
+
+        /// Wrap the query in a subquery, to be used as a value or as a predicate operand.
+        fn subquery(self) -> Subquery<Self>
+        where
+            Self: G::QueryExpression,
+        {
+            Subquery::new(self)
+        }
+
+        /// Use the query as a derived table in a FROM clause.
+        fn alias_table<Name>(self, name: Name) -> DerivedTable<Self, Name, Blank>
+        where
+            Self: G::QueryExpression,
+            Name: G::CorrelationName,
+        {
+            DerivedTable::new(self, name)
+        }
+
+        /// Combine the rows of both queries, without duplicates.
+        fn union<Rhs>(
+            self,
+            rhs: Rhs,
+        ) -> SetOperation<Self::IntoQueryExpressionBody, Rhs::IntoQueryTerm, Blank>
+        where
+            Rhs: G::QueryExpression,
+        {
+            SetOperation::new(
+                SetOperator::Union,
+                self.into_query_expression_body(),
+                rhs.into_query_term(),
+            )
+        }
+
+        /// Combine the rows of both queries, keeping the duplicates.
+        fn union_all<Rhs>(
+            self,
+            rhs: Rhs,
+        ) -> SetOperation<Self::IntoQueryExpressionBody, Rhs::IntoQueryTerm, Blank>
+        where
+            Rhs: G::QueryExpression,
+        {
+            self.union(rhs).all()
+        }
+
+        /// Keep the rows which are not returned by the other query, without duplicates.
+        fn except<Rhs>(
+            self,
+            rhs: Rhs,
+        ) -> SetOperation<Self::IntoQueryExpressionBody, Rhs::IntoQueryTerm, Blank>
+        where
+            Rhs: G::QueryExpression,
+        {
+            SetOperation::new(
+                SetOperator::Except,
+                self.into_query_expression_body(),
+                rhs.into_query_term(),
+            )
+        }
+
+        /// Keep the rows which are not returned by the other query, keeping the duplicates.
+        fn except_all<Rhs>(
+            self,
+            rhs: Rhs,
+        ) -> SetOperation<Self::IntoQueryExpressionBody, Rhs::IntoQueryTerm, Blank>
+        where
+            Rhs: G::QueryExpression,
+        {
+            self.except(rhs).all()
+        }
+
+        /// Keep the rows which are returned by both queries, without duplicates.
+        fn intersect<Rhs>(
+            self,
+            rhs: Rhs,
+        ) -> Intersect<Self::IntoQueryTerm, Rhs::IntoQueryPrimary, Blank>
+        where
+            Rhs: G::QueryExpression,
+        {
+            Intersect::new(self.into_query_term(), rhs.into_query_primary())
+        }
+
+        /// Keep the rows which are returned by both queries, keeping the duplicates.
+        fn intersect_all<Rhs>(
+            self,
+            rhs: Rhs,
+        ) -> Intersect<Self::IntoQueryTerm, Rhs::IntoQueryPrimary, Blank>
+        where
+            Rhs: G::QueryExpression,
+        {
+            self.intersect(rhs).all()
+        }
+
+        /// Transform the with clause
+        fn transform_with<NewWithClause: G::WithClause>(
+            self,
+            transform: impl FnOnce(Self::WithClause) -> NewWithClause,
+        ) -> QueryExpressionWithTransformedWith<Self, NewWithClause> {
+            let QueryExpr {
+                with_clause,
+                body,
+                order_by,
+                offset,
+                fetch,
+            } = self.unwrap();
+
+            QueryExpr {
+                with_clause: transform(with_clause),
+                body,
+                order_by,
+                offset,
+                fetch,
+            }
+        }
+
+        /// Transform the order by clause
+        fn transform_order_by<NewOrderByClause: G::OrderByClause>(
+            self,
+            transform: impl FnOnce(Self::OrderByClause) -> NewOrderByClause,
+        ) -> QueryExpressionWithTransformedOrderBy<Self, NewOrderByClause> {
+            let QueryExpr {
+                with_clause,
+                body,
+                order_by,
+                offset,
+                fetch,
+            } = self.unwrap();
+
+            QueryExpr {
+                with_clause,
+                body,
+                order_by: transform(order_by),
+                offset,
+                fetch,
+            }
+        }
+
+        /// Sort the rows, replacing the current ORDER BY clause.
+        fn order_by<Specs>(
+            self,
+            specs: Specs,
+        ) -> QueryExpressionWithTransformedOrderBy<Self, OrderBy<Specs>>
+        where
+            Specs: G::SortSpecificationList,
+        {
+            self.transform_order_by(|_| OrderBy::new(specs))
+        }
+
+        /// Transform the result offset clause
+        fn transform_offset<NewResultOffsetClause: G::ResultOffsetClause>(
+            self,
+            transform: impl FnOnce(Self::ResultOffsetClause) -> NewResultOffsetClause,
+        ) -> QueryExpressionWithTransformedOffset<Self, NewResultOffsetClause> {
+            let QueryExpr {
+                with_clause,
+                body,
+                order_by,
+                offset,
+                fetch,
+            } = self.unwrap();
+
+            QueryExpr {
+                with_clause,
+                body,
+                order_by,
+                offset: transform(offset),
+                fetch,
+            }
+        }
+
+        /// Skip the first rows.
+        ///
+        /// If the rows are limited with the non-standard LIMIT clause, the offset
+        /// is moved into it (LIMIT m OFFSET n).
+        fn offset<Count>(self, count: Count) -> QueryExpressionWithOffset<Self, Count>
+        where
+            Count: G::OffsetRowCount,
+        {
+            let QueryExpr {
+                with_clause,
+                body,
+                order_by,
+                offset: _,
+                fetch,
+            } = self.unwrap();
+
+            let (offset, fetch) = fetch.with_offset(count);
+
+            QueryExpr {
+                with_clause,
+                body,
+                order_by,
+                offset,
+                fetch,
+            }
+        }
+
+        /// Transform the fetch first clause
+        fn transform_fetch<NewFetchFirstClause: G::FetchFirstClause>(
+            self,
+            transform: impl FnOnce(Self::FetchFirstClause) -> NewFetchFirstClause,
+        ) -> QueryExpressionWithTransformedFetch<Self, NewFetchFirstClause> {
+            let QueryExpr {
+                with_clause,
+                body,
+                order_by,
+                offset,
+                fetch,
+            } = self.unwrap();
+
+            QueryExpr {
+                with_clause,
+                body,
+                order_by,
+                offset,
+                fetch: transform(fetch),
+            }
+        }
+
+        /// Fetch at most `count` rows.
+        fn fetch_first<Count>(
+            self,
+            count: Count,
+        ) -> QueryExpressionWithTransformedFetch<Self, FetchFirst<Count>>
+        where
+            Count: G::FetchFirstRowCount,
+        {
+            self.transform_fetch(|_| FetchFirst::new(count))
+        }
+
+        /// Fetch at most `count` rows, and the rows which are peers of the last one.
+        fn fetch_first_with_ties<Count>(
+            self,
+            count: Count,
+        ) -> QueryExpressionWithTransformedFetch<Self, FetchFirst<Count>>
+        where
+            Count: G::FetchFirstRowCount,
+        {
+            self.transform_fetch(|_| FetchFirst::new(count).with_ties())
+        }
+
+        /// Fetch at most `count` rows with the non-standard LIMIT clause.
+        ///
+        /// The current OFFSET clause is moved into it (LIMIT m OFFSET n), and
+        /// the current FETCH FIRST clause is replaced.
+        fn limit<Count>(self, count: Count) -> QueryExpressionWithLimit<Self, Count>
+        where
+            Count: G::FetchFirstRowCount,
+        {
+            let QueryExpr {
+                with_clause,
+                body,
+                order_by,
+                offset,
+                fetch: _,
+            } = self.unwrap();
+
+            QueryExpr {
+                with_clause,
+                body,
+                order_by,
+                offset: Blank,
+                fetch: offset.with_limit(count),
+            }
+        }
+
+        /// Skip the first `offset` rows and fetch at most `count` rows, with the
+        /// non-standard LIMIT m OFFSET n clause.
+        ///
+        /// Replaces the current OFFSET and FETCH FIRST clauses.
+        fn limit_offset<Count, OffsetCount>(
+            self,
+            count: Count,
+            offset: OffsetCount,
+        ) -> QueryExpressionWithLimitOffset<Self, Count, OffsetCount>
+        where
+            Count: G::FetchFirstRowCount,
+            OffsetCount: G::OffsetRowCount,
+        {
+            self.transform_offset(|_| Blank)
+                .transform_fetch(|_| Limit::new(count, offset))
+        }
+    }
+
+    pub trait FetchFirstClause: Sized {
+        type ResultOffsetClause<Count: G::OffsetRowCount>: G::ResultOffsetClause;
+        type WithOffset<Count: G::OffsetRowCount>: G::FetchFirstClause;
+
+        /// Skip the first rows, returns the result offset clause and the
+        /// fetch first clause of the query expression.
+        fn with_offset<Count>(
+            self,
+            count: Count,
+        ) -> (Self::ResultOffsetClause<Count>, Self::WithOffset<Count>)
+        where
+            Count: G::OffsetRowCount;
+    }
+
+    pub trait ResultOffsetClause: Sized {
+        type WithLimit<Count: G::FetchFirstRowCount>: G::FetchFirstClause;
+
+        /// Fetch at most `count` rows with the non-standard LIMIT clause,
+        /// returns the fetch first clause holding the offset.
+        fn with_limit<Count>(self, count: Count) -> Self::WithLimit<Count>
+        where
+            Count: G::FetchFirstRowCount;
+    }
+
+    pub trait WithList: Sized {
+        /// Add a new common table expression in the list.
+        fn add_with_element<Element>(self, element: Element) -> WithListLink<Self, Element>
+        where
+            Self: G::WithList,
+            Element: G::WithListElement,
+        {
+            WithListLink::new(self, element)
+        }
+    }
+
+    pub trait SortSpecificationList: Sized {
+        /// Add a new sort specification in the list.
+        fn add_sort_specification<Spec>(self, spec: Spec) -> SortSpecificationLink<Self, Spec>
+        where
+            Self: G::SortSpecificationList,
+            Spec: G::SortSpecification,
+        {
+            SortSpecificationLink::new(self, spec)
+        }
+    }
+
     pub trait FromClause {
         /// Add a table reference to the current from clause.
         fn add_table_reference(self, table_refs: impl G::TableReference) -> impl G::FromClause;
@@ -292,22 +1124,21 @@ pub mod helpers {
         type WhereClause: G::WhereClause;
         type GroupByClause: G::GroupByClause;
         type HavingClause: G::HavingClause;
+        type WindowClause: G::WindowClause;
 
-        fn unwrap(
-            self,
-        ) -> TableExpr<Self::FromClause, Self::WhereClause, Self::GroupByClause, Self::HavingClause>;
+        fn unwrap(self) -> TableExpressionUnwrapped<Self>;
 
         /// Transform the from clause
         fn transform_from<NewFromClause: G::FromClause>(
             self,
             transform: impl FnOnce(Self::FromClause) -> NewFromClause,
-        ) -> TableExpr<NewFromClause, Self::WhereClause, Self::GroupByClause, Self::HavingClause>
-        {
+        ) -> TableExpressionWithTransformedFrom<Self, NewFromClause> {
             let TableExpr {
                 from_clause,
                 where_clause,
                 group_by,
                 having,
+                window,
             } = self.unwrap();
 
             TableExpr {
@@ -315,6 +1146,7 @@ pub mod helpers {
                 where_clause,
                 group_by,
                 having,
+                window,
             }
         }
 
@@ -322,13 +1154,13 @@ pub mod helpers {
         fn transform_where<NewWhereClause: G::WhereClause>(
             self,
             transform: impl FnOnce(Self::WhereClause) -> NewWhereClause,
-        ) -> TableExpr<Self::FromClause, NewWhereClause, Self::GroupByClause, Self::HavingClause>
-        {
+        ) -> TableExpressionWithTransformedWhere<Self, NewWhereClause> {
             let TableExpr {
                 from_clause,
                 where_clause,
                 group_by,
                 having,
+                window,
             } = self.unwrap();
 
             TableExpr {
@@ -336,23 +1168,157 @@ pub mod helpers {
                 where_clause: transform(where_clause),
                 group_by,
                 having,
+                window,
             }
         }
 
-        fn r#where<Cond>(
+        fn r#where<Cond>(self, cond: Cond) -> TableExpressionWithTransformedWhere<Self, Where<Cond>>
+        where
+            Self: G::TableExpression,
+            Cond: G::SearchCondition,
+        {
+            self.transform_where(|_| Where::new(cond))
+        }
+
+        /// Transform the group by clause
+        fn transform_group_by<NewGroupByClause: G::GroupByClause>(
+            self,
+            transform: impl FnOnce(Self::GroupByClause) -> NewGroupByClause,
+        ) -> TableExpressionWithTransformedGroupBy<Self, NewGroupByClause> {
+            let TableExpr {
+                from_clause,
+                where_clause,
+                group_by,
+                having,
+                window,
+            } = self.unwrap();
+
+            TableExpr {
+                from_clause,
+                where_clause,
+                group_by: transform(group_by),
+                having,
+                window,
+            }
+        }
+
+        /// Replace the current GROUP BY clause with another one.
+        fn group_by<Elements>(
+            self,
+            elements: Elements,
+        ) -> TableExpressionWithTransformedGroupBy<Self, GroupBy<Elements>>
+        where
+            Self: G::TableExpression,
+            Elements: G::GroupingElementList,
+        {
+            self.transform_group_by(|_| GroupBy::new(elements))
+        }
+
+        /// Transform the having clause
+        fn transform_having<NewHavingClause: G::HavingClause>(
+            self,
+            transform: impl FnOnce(Self::HavingClause) -> NewHavingClause,
+        ) -> TableExpressionWithTransformedHaving<Self, NewHavingClause> {
+            let TableExpr {
+                from_clause,
+                where_clause,
+                group_by,
+                having,
+                window,
+            } = self.unwrap();
+
+            TableExpr {
+                from_clause,
+                where_clause,
+                group_by,
+                having: transform(having),
+                window,
+            }
+        }
+
+        /// Replace the current HAVING clause with another one.
+        fn having<Cond>(
             self,
             cond: Cond,
-        ) -> TableExpr<
-            <Self as TableExpression>::FromClause,
-            Where<Cond>,
-            <Self as TableExpression>::GroupByClause,
-            <Self as TableExpression>::HavingClause,
-        >
+        ) -> TableExpressionWithTransformedHaving<Self, Having<Cond>>
         where
             Self: G::TableExpression,
             Cond: G::SearchCondition,
         {
-            self.transform_where(|_| Where::new(cond))
+            self.transform_having(|_| Having::new(cond))
+        }
+
+        /// Transform the window clause
+        fn transform_window<NewWindowClause: G::WindowClause>(
+            self,
+            transform: impl FnOnce(Self::WindowClause) -> NewWindowClause,
+        ) -> TableExpressionWithTransformedWindow<Self, NewWindowClause> {
+            let TableExpr {
+                from_clause,
+                where_clause,
+                group_by,
+                having,
+                window,
+            } = self.unwrap();
+
+            TableExpr {
+                from_clause,
+                where_clause,
+                group_by,
+                having,
+                window: transform(window),
+            }
+        }
+
+        /// Replace the current WINDOW clause with another one.
+        fn window<Definitions>(
+            self,
+            definitions: Definitions,
+        ) -> TableExpressionWithTransformedWindow<Self, WindowClause<Definitions>>
+        where
+            Self: G::TableExpression,
+            Definitions: G::WindowDefinitionList,
+        {
+            self.transform_window(|_| WindowClause::new(definitions))
+        }
+    }
+
+    pub trait GroupingElementList: Sized {
+        /// Add a new grouping element in the list.
+        fn add_grouping_element<Element>(
+            self,
+            element: Element,
+        ) -> GroupingElementLink<Self, Element>
+        where
+            Self: G::GroupingElementList,
+            Element: G::GroupingElement,
+        {
+            GroupingElementLink::new(self, element)
+        }
+    }
+
+    pub trait OrdinaryGroupingSetList: Sized {
+        /// Add a new ordinary grouping set in the list.
+        fn add_ordinary_grouping_set<Set>(self, set: Set) -> OrdinaryGroupingSetLink<Self, Set>
+        where
+            Self: G::OrdinaryGroupingSetList,
+            Set: G::OrdinaryGroupingSet,
+        {
+            OrdinaryGroupingSetLink::new(self, set)
+        }
+    }
+
+    pub trait GroupingColumnReferenceList: Sized {
+        /// Add a new grouping column in the list.
+        fn add_grouping_column<Column>(
+            self,
+            column: Column,
+        ) -> GroupingColumnReferenceLink<Self, Column>
+        where
+            Self: G::GroupingColumnReferenceList,
+            Column: G::GroupingColumnReference,
+        {
+            GroupingColumnReferenceLink::new(self, column)
         }
     }
 
@@ -375,21 +1341,397 @@ pub mod helpers {
         {
             AliasedColumn::new(self, alias)
         }
+
+        /// Sort by the value in ascending order.
+        fn asc(self) -> SortSpec<Self, Blank, Asc, Blank>
+        where
+            Self: G::ValueExpression,
+        {
+            SortSpec::new(self).asc()
+        }
+
+        /// Sort by the value in descending order.
+        fn desc(self) -> SortSpec<Self, Blank, Desc, Blank>
+        where
+            Self: G::ValueExpression,
+        {
+            SortSpec::new(self).desc()
+        }
+
+        /// Sort by the value, the null values first.
+        fn nulls_first(self) -> SortSpec<Self, Blank, Blank, NullsFirst>
+        where
+            Self: G::ValueExpression,
+        {
+            SortSpec::new(self).nulls_first()
+        }
+
+        /// Sort by the value, the null values last.
+        fn nulls_last(self) -> SortSpec<Self, Blank, Blank, NullsLast>
+        where
+            Self: G::ValueExpression,
+        {
+            SortSpec::new(self).nulls_last()
+        }
+
+        /// Sort by the value, compared with the collation.
+        fn collate<Name>(self, name: Name) -> SortSpec<Self, Collate<Name>, Blank, Blank>
+        where
+            Self: G::ValueExpression,
+            Name: G::CollationName,
+        {
+            SortSpec::new(self).collate(name)
+        }
     }
 
-    pub trait Insert {
+    pub trait Insert: Sized {
+        type WithClause: G::WithClause;
         type Target: G::InsertionTarget;
         type ColumnsAndSources: G::InsertColumnsAndSources;
+        type ReturningClause: G::ReturningClause;
+
+        /// Unwrap the insert statement
+        fn unwrap(
+            self,
+        ) -> crate::insert::Insert<
+            Self::WithClause,
+            Self::Target,
+            Self::ColumnsAndSources,
+            Self::ReturningClause,
+        >;
+
+        /// Transform the with clause
+        fn transform_with<NewWithClause: G::WithClause>(
+            self,
+            transform: impl FnOnce(Self::WithClause) -> NewWithClause,
+        ) -> crate::insert::Insert<
+            NewWithClause,
+            Self::Target,
+            Self::ColumnsAndSources,
+            Self::ReturningClause,
+        > {
+            let crate::insert::Insert {
+                with_clause,
+                target,
+                values,
+                returning,
+            } = self.unwrap();
+
+            crate::insert::Insert {
+                with_clause: transform(with_clause),
+                target,
+                values,
+                returning,
+            }
+        }
 
+        /// Transform the insertion target
         fn transform_target<NewTarget: G::InsertionTarget>(
             self,
             transform: impl FnOnce(Self::Target) -> NewTarget,
-        ) -> impl G::Insert;
+        ) -> crate::insert::Insert<
+            Self::WithClause,
+            NewTarget,
+            Self::ColumnsAndSources,
+            Self::ReturningClause,
+        > {
+            let crate::insert::Insert {
+                with_clause,
+                target,
+                values,
+                returning,
+            } = self.unwrap();
+
+            crate::insert::Insert {
+                with_clause,
+                target: transform(target),
+                values,
+                returning,
+            }
+        }
 
+        /// Transform the columns and sources
         fn transform_columns_and_sources<NewColumnsAndSources: G::InsertColumnsAndSources>(
             self,
             transform: impl FnOnce(Self::ColumnsAndSources) -> NewColumnsAndSources,
-        ) -> impl G::Insert;
+        ) -> crate::insert::Insert<
+            Self::WithClause,
+            Self::Target,
+            NewColumnsAndSources,
+            Self::ReturningClause,
+        > {
+            let crate::insert::Insert {
+                with_clause,
+                target,
+                values,
+                returning,
+            } = self.unwrap();
+
+            crate::insert::Insert {
+                with_clause,
+                target,
+                values: transform(values),
+                returning,
+            }
+        }
+
+        /// Transform the returning clause
+        fn transform_returning<NewReturningClause: G::ReturningClause>(
+            self,
+            transform: impl FnOnce(Self::ReturningClause) -> NewReturningClause,
+        ) -> crate::insert::Insert<
+            Self::WithClause,
+            Self::Target,
+            Self::ColumnsAndSources,
+            NewReturningClause,
+        > {
+            let crate::insert::Insert {
+                with_clause,
+                target,
+                values,
+                returning,
+            } = self.unwrap();
+
+            crate::insert::Insert {
+                with_clause,
+                target,
+                values,
+                returning: transform(returning),
+            }
+        }
+
+        /// Return the inserted rows, evaluated against the select list.
+        fn returning<List>(
+            self,
+            list: List,
+        ) -> crate::insert::Insert<
+            Self::WithClause,
+            Self::Target,
+            Self::ColumnsAndSources,
+            Returning<List>,
+        >
+        where
+            List: G::SelectList,
+        {
+            self.transform_returning(|_| Returning::new(list))
+        }
+    }
+
+    pub trait UpdateStatement: Sized {
+        type WithClause: G::WithClause;
+        type Target: G::TableName;
+        type SetClauseList: G::SetClauseList;
+        type WhereClause: G::WhereClause;
+        type ReturningClause: G::ReturningClause;
+
+        /// Unwrap the update statement
+        fn unwrap(self) -> UpdateStatementUnwrapped<Self>;
+
+        /// Add a column to update.
+        fn set<Column, Value>(
+            self,
+            column: Column,
+            value: Value,
+        ) -> UpdateStatementWithAddedSetClause<Self, Column, Value>
+        where
+            Column: G::ColumnName,
+            Value: G::UpdateSource,
+        {
+            let Update {
+                with_clause,
+                target,
+                set_clause_list,
+                where_clause,
+                returning,
+            } = self.unwrap();
+
+            Update {
+                with_clause,
+                target,
+                set_clause_list: SetClauseLink::new(set_clause_list, SetClause::new(column, value)),
+                where_clause,
+                returning,
+            }
+        }
+
+        /// Transform the with clause
+        fn transform_with<NewWithClause: G::WithClause>(
+            self,
+            transform: impl FnOnce(Self::WithClause) -> NewWithClause,
+        ) -> UpdateStatementWithTransformedWith<Self, NewWithClause> {
+            let Update {
+                with_clause,
+                target,
+                set_clause_list,
+                where_clause,
+                returning,
+            } = self.unwrap();
+
+            Update {
+                with_clause: transform(with_clause),
+                target,
+                set_clause_list,
+                where_clause,
+                returning,
+            }
+        }
+
+        /// Transform the where clause
+        fn transform_where<NewWhereClause: G::WhereClause>(
+            self,
+            transform: impl FnOnce(Self::WhereClause) -> NewWhereClause,
+        ) -> UpdateStatementWithTransformedWhere<Self, NewWhereClause> {
+            let Update {
+                with_clause,
+                target,
+                set_clause_list,
+                where_clause,
+                returning,
+            } = self.unwrap();
+
+            Update {
+                with_clause,
+                target,
+                set_clause_list,
+                where_clause: transform(where_clause),
+                returning,
+            }
+        }
+
+        /// Transform the returning clause
+        fn transform_returning<NewReturningClause: G::ReturningClause>(
+            self,
+            transform: impl FnOnce(Self::ReturningClause) -> NewReturningClause,
+        ) -> UpdateStatementWithTransformedReturning<Self, NewReturningClause> {
+            let Update {
+                with_clause,
+                target,
+                set_clause_list,
+                where_clause,
+                returning,
+            } = self.unwrap();
+
+            Update {
+                with_clause,
+                target,
+                set_clause_list,
+                where_clause,
+                returning: transform(returning),
+            }
+        }
+
+        /// Set the condition to filter the updated rows.
+        fn r#where<Cond>(self, cond: Cond) -> UpdateStatementWithTransformedWhere<Self, Where<Cond>>
+        where
+            Cond: G::SearchCondition,
+        {
+            self.transform_where(|_| Where::new(cond))
+        }
+
+        /// Return the updated rows, evaluated against the select list.
+        fn returning<List>(
+            self,
+            list: List,
+        ) -> UpdateStatementWithTransformedReturning<Self, Returning<List>>
+        where
+            List: G::SelectList,
+        {
+            self.transform_returning(|_| Returning::new(list))
+        }
+    }
+
+    pub trait DeleteStatement: Sized {
+        type WithClause: G::WithClause;
+        type Target: G::TableName;
+        type WhereClause: G::WhereClause;
+        type ReturningClause: G::ReturningClause;
+
+        /// Unwrap the delete statement
+        fn unwrap(
+            self,
+        ) -> Delete<Self::WithClause, Self::Target, Self::WhereClause, Self::ReturningClause>;
+
+        /// Transform the with clause
+        fn transform_with<NewWithClause: G::WithClause>(
+            self,
+            transform: impl FnOnce(Self::WithClause) -> NewWithClause,
+        ) -> Delete<NewWithClause, Self::Target, Self::WhereClause, Self::ReturningClause> {
+            let Delete {
+                with_clause,
+                target,
+                where_clause,
+                returning,
+            } = self.unwrap();
+
+            Delete {
+                with_clause: transform(with_clause),
+                target,
+                where_clause,
+                returning,
+            }
+        }
+
+        /// Transform the where clause
+        fn transform_where<NewWhereClause: G::WhereClause>(
+            self,
+            transform: impl FnOnce(Self::WhereClause) -> NewWhereClause,
+        ) -> Delete<Self::WithClause, Self::Target, NewWhereClause, Self::ReturningClause> {
+            let Delete {
+                with_clause,
+                target,
+                where_clause,
+                returning,
+            } = self.unwrap();
+
+            Delete {
+                with_clause,
+                target,
+                where_clause: transform(where_clause),
+                returning,
+            }
+        }
+
+        /// Transform the returning clause
+        fn transform_returning<NewReturningClause: G::ReturningClause>(
+            self,
+            transform: impl FnOnce(Self::ReturningClause) -> NewReturningClause,
+        ) -> Delete<Self::WithClause, Self::Target, Self::WhereClause, NewReturningClause> {
+            let Delete {
+                with_clause,
+                target,
+                where_clause,
+                returning,
+            } = self.unwrap();
+
+            Delete {
+                with_clause,
+                target,
+                where_clause,
+                returning: transform(returning),
+            }
+        }
+
+        /// Set the condition to filter the deleted rows.
+        fn r#where<Cond>(
+            self,
+            cond: Cond,
+        ) -> Delete<Self::WithClause, Self::Target, Where<Cond>, Self::ReturningClause>
+        where
+            Cond: G::SearchCondition,
+        {
+            self.transform_where(|_| Where::new(cond))
+        }
+
+        /// Return the deleted rows, evaluated against the select list.
+        fn returning<List>(
+            self,
+            list: List,
+        ) -> Delete<Self::WithClause, Self::Target, Self::WhereClause, Returning<List>>
+        where
+            List: G::SelectList,
+        {
+            self.transform_returning(|_| Returning::new(list))
+        }
     }
 
     pub trait TableReferenceList {
@@ -433,6 +1775,56 @@ pub mod helpers {
         }
     }
 
+    pub trait RowValuePredicand {
+        /// Checks if the value is null.
+        #[allow(clippy::wrong_self_convention)]
+        fn is_null(self) -> IsNull<Self>
+        where
+            Self: G::RowValuePredicand,
+        {
+            crate::is_null(self)
+        }
+
+        /// Checks if the value is not null.
+        #[allow(clippy::wrong_self_convention)]
+        fn is_not_null(self) -> IsNull<Self>
+        where
+            Self: G::RowValuePredicand,
+        {
+            crate::is_not_null(self)
+        }
+
+        /// Checks if the values are different, null values being comparable.
+        #[allow(clippy::wrong_self_convention)]
+        fn is_distinct_from<Rhs>(self, rhs: Rhs) -> IsDistinctFrom<Self, Rhs>
+        where
+            Self: G::RowValuePredicand,
+            Rhs: G::RowValuePredicand,
+        {
+            crate::is_distinct_from(self, rhs)
+        }
+
+        /// Checks if the values are the same, null values being comparable.
+        #[allow(clippy::wrong_self_convention)]
+        fn is_not_distinct_from<Rhs>(self, rhs: Rhs) -> IsDistinctFrom<Self, Rhs>
+        where
+            Self: G::RowValuePredicand,
+            Rhs: G::RowValuePredicand,
+        {
+            crate::is_not_distinct_from(self, rhs)
+        }
+    }
+
+    pub trait InValueList {
+        fn add_in_value<Value>(self, value: Value) -> InValueLink<Self, Value>
+        where
+            Self: G::InValueList,
+            Value: G::RowValuePredicand,
+        {
+            InValueLink::new(self, value)
+        }
+    }
+
     pub trait IdentifierChain {
         fn add_identifier<Id>(self, id: Id) -> IdentifierLink<Self, Id>
         where
@@ -507,6 +1899,45 @@ pub mod helpers {
         }
     }
 
+    pub trait WindowFunctionType: Sized {
+        /// Compute the function over a window.
+        fn over<Window>(self, window: Window) -> Over<Self, Window>
+        where
+            Self: G::WindowFunctionType,
+            Window: G::WindowNameOrSpecification,
+        {
+            Over::new(self, window)
+        }
+    }
+
+    pub trait WindowPartitionColumnReferenceList: Sized {
+        /// Add a new partition column in the list.
+        fn add_partition_column<Column>(
+            self,
+            column: Column,
+        ) -> WindowPartitionColumnLink<Self, Column>
+        where
+            Self: G::WindowPartitionColumnReferenceList,
+            Column: G::ColumnReference,
+        {
+            WindowPartitionColumnLink::new(self, column)
+        }
+    }
+
+    pub trait WindowDefinitionList: Sized {
+        /// Add a new window definition in the list.
+        fn add_window_definition<Definition>(
+            self,
+            definition: Definition,
+        ) -> WindowDefinitionLink<Self, Definition>
+        where
+            Self: G::WindowDefinitionList,
+            Definition: G::WindowDefinition,
+        {
+            WindowDefinitionLink::new(self, definition)
+        }
+    }
+
     pub trait SQLArgumentList: Sized {
         fn add_sql_argument<Arg>(self, arg: Arg) -> SQLArgumentLink<Self, Arg>
         where
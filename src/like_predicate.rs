@@ -0,0 +1,129 @@
+use crate::{blank::Blank, grammar as G, Database, ToQuery};
+use sql_builder_macros::CharacterLikePredicate;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, CharacterLikePredicate)]
+/// <row value predicand> [ NOT ] LIKE <character pattern> [ ESCAPE <escape character> ]
+///
+/// See [self::like] and [self::not_like]
+pub struct Like<Value, Pattern, Escape>
+where
+    Value: G::RowValuePredicand,
+    Pattern: G::CharacterValueExpression,
+    Escape: G::EscapeCharacter,
+{
+    value: Value,
+    pattern: Pattern,
+    escape: Escape,
+    negated: bool,
+}
+
+impl<Value, Pattern, Escape> Like<Value, Pattern, Escape>
+where
+    Value: G::RowValuePredicand,
+    Pattern: G::CharacterValueExpression,
+    Escape: G::EscapeCharacter,
+{
+    /// Set the character used to escape the wildcards of the pattern.
+    pub fn escape<NewEscape>(self, escape: NewEscape) -> Like<Value, Pattern, NewEscape>
+    where
+        NewEscape: G::EscapeCharacter,
+    {
+        Like {
+            value: self.value,
+            pattern: self.pattern,
+            escape,
+            negated: self.negated,
+        }
+    }
+}
+
+impl<Value, Pattern, Escape> ::std::fmt::Display for Like<Value, Pattern, Escape>
+where
+    Value: G::RowValuePredicand + std::fmt::Display,
+    Pattern: G::CharacterValueExpression + std::fmt::Display,
+    Escape: G::EscapeCharacter + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ", self.value)?;
+
+        if self.negated {
+            write!(f, "NOT ")?;
+        }
+
+        write!(f, "LIKE {}", self.pattern)?;
+
+        if Escape::IS_IMPL {
+            write!(f, " ESCAPE {}", self.escape)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'q, DB, Value, Pattern, Escape> ToQuery<'q, DB> for Like<Value, Pattern, Escape>
+where
+    DB: Database,
+    Value: G::RowValuePredicand + ToQuery<'q, DB>,
+    Pattern: G::CharacterValueExpression + ToQuery<'q, DB>,
+    Escape: G::EscapeCharacter + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        self.value.write(ctx)?;
+        write!(ctx, " ")?;
+
+        if self.negated {
+            write!(ctx, "NOT ")?;
+        }
+
+        write!(ctx, "LIKE ")?;
+        self.pattern.write(ctx)?;
+
+        if Escape::IS_IMPL {
+            write!(ctx, " ESCAPE ")?;
+            self.escape.write(ctx)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[inline]
+/// Checks if the value matches the pattern
+///
+/// # SQL
+/// ```sql
+/// <value> LIKE <pattern>
+/// ```
+pub fn like<Value, Pattern>(value: Value, pattern: Pattern) -> Like<Value, Pattern, Blank>
+where
+    Value: G::RowValuePredicand,
+    Pattern: G::CharacterValueExpression,
+{
+    Like {
+        value,
+        pattern,
+        escape: Blank,
+        negated: false,
+    }
+}
+
+#[inline]
+/// Checks if the value does not match the pattern
+///
+/// # SQL
+/// ```sql
+/// <value> NOT LIKE <pattern>
+/// ```
+pub fn not_like<Value, Pattern>(value: Value, pattern: Pattern) -> Like<Value, Pattern, Blank>
+where
+    Value: G::RowValuePredicand,
+    Pattern: G::CharacterValueExpression,
+{
+    Like {
+        value,
+        pattern,
+        escape: Blank,
+        negated: true,
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::ModulusExpression;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, ModulusExpression)]
+/// MOD(<numeric value expression dividend>, <numeric value expression divisor>)
+///
+/// See [self::r#mod]
+pub struct Mod<Dividend, Divisor>
+where
+    Dividend: G::NumericValueExpression,
+    Divisor: G::NumericValueExpression,
+{
+    dividend: Dividend,
+    divisor: Divisor,
+}
+
+impl<Dividend, Divisor> ::std::fmt::Display for Mod<Dividend, Divisor>
+where
+    Dividend: G::NumericValueExpression + std::fmt::Display,
+    Divisor: G::NumericValueExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MOD({}, {})", self.dividend, self.divisor)
+    }
+}
+
+impl<'q, DB, Dividend, Divisor> ToQuery<'q, DB> for Mod<Dividend, Divisor>
+where
+    DB: Database,
+    Dividend: G::NumericValueExpression + ToQuery<'q, DB>,
+    Divisor: G::NumericValueExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "MOD(")?;
+        self.dividend.write(ctx)?;
+        write!(ctx, ", ")?;
+        self.divisor.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Returns the remainder of the division of the dividend by the divisor.
+pub fn r#mod<Dividend, Divisor>(dividend: Dividend, divisor: Divisor) -> Mod<Dividend, Divisor>
+where
+    Dividend: G::NumericValueExpression,
+    Divisor: G::NumericValueExpression,
+{
+    Mod { dividend, divisor }
+}
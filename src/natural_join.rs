@@ -1,3 +1,4 @@
+use crate::{blank::Blank, helpers as H, query_expression::QueryExpr};
 use crate::{grammar as G, ToQuery};
 use sql_builder_macros::NaturalJoin;
 use sqlx::Database;
@@ -52,3 +53,45 @@ where
         self.table_dest.write(ctx)
     }
 }
+
+impl<Src, Kind, Dest> H::QueryExpression for NaturalJoin<Src, Kind, Dest>
+where
+    Src: G::TableReference,
+    Kind: G::JoinType,
+    Dest: G::TablePrimary,
+{
+    type WithClause = Blank;
+    type Body = Self;
+    type OrderByClause = Blank;
+    type ResultOffsetClause = Blank;
+    type FetchFirstClause = Blank;
+    type IntoQueryExpressionBody = Self;
+    type IntoQueryTerm = Self;
+    type IntoQueryPrimary = Self;
+
+    #[inline]
+    fn unwrap(self) -> QueryExpr<Blank, Self, Blank, Blank, Blank> {
+        QueryExpr {
+            with_clause: Blank,
+            body: self,
+            order_by: Blank,
+            offset: Blank,
+            fetch: Blank,
+        }
+    }
+
+    #[inline]
+    fn into_query_expression_body(self) -> Self {
+        self
+    }
+
+    #[inline]
+    fn into_query_term(self) -> Self {
+        self
+    }
+
+    #[inline]
+    fn into_query_primary(self) -> Self {
+        self
+    }
+}
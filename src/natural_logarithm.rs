@@ -0,0 +1,44 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::NaturalLogarithm;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, NaturalLogarithm)]
+/// LN(<numeric value expression>)
+///
+/// See [self::ln]
+pub struct Ln<Value>
+where
+    Value: G::NumericValueExpression,
+{
+    value: Value,
+}
+
+impl<Value> ::std::fmt::Display for Ln<Value>
+where
+    Value: G::NumericValueExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LN({})", self.value)
+    }
+}
+
+impl<'q, DB, Value> ToQuery<'q, DB> for Ln<Value>
+where
+    DB: Database,
+    Value: G::NumericValueExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "LN(")?;
+        self.value.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Returns the natural logarithm of a number.
+pub fn ln<Value>(value: Value) -> Ln<Value>
+where
+    Value: G::NumericValueExpression,
+{
+    Ln { value }
+}
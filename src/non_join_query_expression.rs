@@ -0,0 +1,207 @@
+use crate::{
+    blank::Blank, corresponding_spec::Corresponding, non_join_query_primary::NestedQueryExpression,
+    query_expression::QueryExpr, select::SetQuantifier, ToQuery,
+};
+use sql_builder_macros::NonJoinQueryExpression;
+use std::fmt::Write;
+
+use crate::grammar as G;
+use crate::helpers as H;
+use crate::Database;
+
+#[derive(Clone, Copy)]
+/// The set operators with the lowest precedence, either UNION or EXCEPT.
+/// See [crate::helpers::QueryExpression::union] or [crate::helpers::QueryExpression::except]
+pub enum SetOperator {
+    Union,
+    Except,
+}
+
+impl AsRef<str> for SetOperator {
+    fn as_ref(&self) -> &str {
+        match self {
+            SetOperator::Union => "UNION",
+            SetOperator::Except => "EXCEPT",
+        }
+    }
+}
+
+impl std::fmt::Display for SetOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Clone, Copy, NonJoinQueryExpression)]
+/// <query expression body> { UNION | EXCEPT } [ ALL | DISTINCT ] [ <corresponding spec> ] <query term>
+pub struct SetOperation<Lhs, Rhs, Spec>
+where
+    Lhs: G::QueryExpressionBody,
+    Rhs: G::QueryTerm,
+    Spec: G::CorrespondingSpec,
+{
+    operator: SetOperator,
+    quantifier: Option<SetQuantifier>,
+    lhs: Lhs,
+    rhs: Rhs,
+    spec: Spec,
+}
+
+impl<Lhs, Rhs> SetOperation<Lhs, Rhs, Blank>
+where
+    Lhs: G::QueryExpressionBody,
+    Rhs: G::QueryTerm,
+{
+    pub fn new(operator: SetOperator, lhs: Lhs, rhs: Rhs) -> Self {
+        Self {
+            operator,
+            quantifier: None,
+            lhs,
+            rhs,
+            spec: Blank,
+        }
+    }
+}
+
+impl<Lhs, Rhs, Spec> SetOperation<Lhs, Rhs, Spec>
+where
+    Lhs: G::QueryExpressionBody,
+    Rhs: G::QueryTerm,
+    Spec: G::CorrespondingSpec,
+{
+    /// Removed duplicated rows
+    pub fn distinct(self) -> Self {
+        Self {
+            quantifier: Some(SetQuantifier::Distinct),
+            ..self
+        }
+    }
+
+    /// All rows are kept
+    pub fn all(self) -> Self {
+        Self {
+            quantifier: Some(SetQuantifier::All),
+            ..self
+        }
+    }
+
+    /// Only the columns with the same name in both queries are combined.
+    pub fn corresponding(self) -> SetOperation<Lhs, Rhs, Corresponding<Blank>> {
+        self.transform_corresponding_spec(|_| Corresponding::new(Blank))
+    }
+
+    /// Only the listed columns of both queries are combined.
+    pub fn corresponding_by<Columns>(
+        self,
+        columns: Columns,
+    ) -> SetOperation<Lhs, Rhs, Corresponding<Columns>>
+    where
+        Columns: G::CorrespondingColumnList,
+    {
+        self.transform_corresponding_spec(|_| Corresponding::new(columns))
+    }
+
+    /// Transform the corresponding spec
+    pub fn transform_corresponding_spec<NewSpec>(
+        self,
+        transform: impl FnOnce(Spec) -> NewSpec,
+    ) -> SetOperation<Lhs, Rhs, NewSpec>
+    where
+        NewSpec: G::CorrespondingSpec,
+    {
+        SetOperation {
+            operator: self.operator,
+            quantifier: self.quantifier,
+            lhs: self.lhs,
+            rhs: self.rhs,
+            spec: transform(self.spec),
+        }
+    }
+}
+
+impl<Lhs, Rhs, Spec> H::QueryExpression for SetOperation<Lhs, Rhs, Spec>
+where
+    Lhs: G::QueryExpressionBody,
+    Rhs: G::QueryTerm,
+    Spec: G::CorrespondingSpec,
+{
+    type WithClause = Blank;
+    type Body = Self;
+    type OrderByClause = Blank;
+    type ResultOffsetClause = Blank;
+    type FetchFirstClause = Blank;
+    type IntoQueryExpressionBody = Self;
+    type IntoQueryTerm = NestedQueryExpression<Self>;
+    type IntoQueryPrimary = NestedQueryExpression<Self>;
+
+    #[inline]
+    fn unwrap(self) -> QueryExpr<Blank, Self, Blank, Blank, Blank> {
+        QueryExpr {
+            with_clause: Blank,
+            body: self,
+            order_by: Blank,
+            offset: Blank,
+            fetch: Blank,
+        }
+    }
+
+    #[inline]
+    fn into_query_expression_body(self) -> Self {
+        self
+    }
+
+    #[inline]
+    fn into_query_term(self) -> NestedQueryExpression<Self> {
+        NestedQueryExpression::new(self)
+    }
+
+    #[inline]
+    fn into_query_primary(self) -> NestedQueryExpression<Self> {
+        NestedQueryExpression::new(self)
+    }
+}
+
+impl<Lhs, Rhs, Spec> std::fmt::Display for SetOperation<Lhs, Rhs, Spec>
+where
+    Lhs: G::QueryExpressionBody + std::fmt::Display,
+    Rhs: G::QueryTerm + std::fmt::Display,
+    Spec: G::CorrespondingSpec + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} ", self.lhs, self.operator)?;
+
+        if let Some(q) = &self.quantifier {
+            write!(f, "{} ", q)?;
+        }
+
+        if Spec::IS_IMPL {
+            write!(f, "{} ", self.spec)?;
+        }
+
+        write!(f, "{}", self.rhs)
+    }
+}
+
+impl<'q, DB, Lhs, Rhs, Spec> ToQuery<'q, DB> for SetOperation<Lhs, Rhs, Spec>
+where
+    DB: Database,
+    Lhs: G::QueryExpressionBody + ToQuery<'q, DB>,
+    Rhs: G::QueryTerm + ToQuery<'q, DB>,
+    Spec: G::CorrespondingSpec + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        self.lhs.write(ctx)?;
+        write!(ctx, " {} ", self.operator)?;
+
+        if let Some(q) = &self.quantifier {
+            write!(ctx, "{} ", q)?;
+        }
+
+        if Spec::IS_IMPL {
+            self.spec.write(ctx)?;
+            write!(ctx, " ")?;
+        }
+
+        self.rhs.write(ctx)
+    }
+}
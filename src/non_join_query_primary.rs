@@ -0,0 +1,83 @@
+use crate::{blank::Blank, query_expression::QueryExpr, ToQuery};
+use sql_builder_macros::NonJoinQueryPrimary;
+use std::fmt::Write;
+
+use crate::grammar as G;
+use crate::helpers as H;
+use crate::Database;
+
+#[derive(Clone, Copy, NonJoinQueryPrimary)]
+/// (<query expression>)
+pub struct NestedQueryExpression<Query>(Query)
+where
+    Query: G::QueryExpression;
+
+impl<Query> NestedQueryExpression<Query>
+where
+    Query: G::QueryExpression,
+{
+    pub fn new(query: Query) -> Self {
+        Self(query)
+    }
+}
+
+impl<Query> H::QueryExpression for NestedQueryExpression<Query>
+where
+    Query: G::QueryExpression,
+{
+    type WithClause = Blank;
+    type Body = Self;
+    type OrderByClause = Blank;
+    type ResultOffsetClause = Blank;
+    type FetchFirstClause = Blank;
+    type IntoQueryExpressionBody = Self;
+    type IntoQueryTerm = Self;
+    type IntoQueryPrimary = Self;
+
+    #[inline]
+    fn unwrap(self) -> QueryExpr<Blank, Self, Blank, Blank, Blank> {
+        QueryExpr {
+            with_clause: Blank,
+            body: self,
+            order_by: Blank,
+            offset: Blank,
+            fetch: Blank,
+        }
+    }
+
+    #[inline]
+    fn into_query_expression_body(self) -> Self {
+        self
+    }
+
+    #[inline]
+    fn into_query_term(self) -> Self {
+        self
+    }
+
+    #[inline]
+    fn into_query_primary(self) -> Self {
+        self
+    }
+}
+
+impl<Query> std::fmt::Display for NestedQueryExpression<Query>
+where
+    Query: G::QueryExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({})", self.0)
+    }
+}
+
+impl<'q, DB, Query> ToQuery<'q, DB> for NestedQueryExpression<Query>
+where
+    DB: Database,
+    Query: G::QueryExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "(")?;
+        self.0.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{
+    blank::Blank, corresponding_spec::Corresponding, non_join_query_primary::NestedQueryExpression,
+    query_expression::QueryExpr, select::SetQuantifier, ToQuery,
+};
+use sql_builder_macros::NonJoinQueryTerm;
+use std::fmt::Write;
+
+use crate::grammar as G;
+use crate::helpers as H;
+use crate::Database;
+
+#[derive(Clone, Copy, NonJoinQueryTerm)]
+/// <query term> INTERSECT [ ALL | DISTINCT ] [ <corresponding spec> ] <query primary>
+///
+/// See [crate::helpers::QueryExpression::intersect]
+pub struct Intersect<Lhs, Rhs, Spec>
+where
+    Lhs: G::QueryTerm,
+    Rhs: G::QueryPrimary,
+    Spec: G::CorrespondingSpec,
+{
+    quantifier: Option<SetQuantifier>,
+    lhs: Lhs,
+    rhs: Rhs,
+    spec: Spec,
+}
+
+impl<Lhs, Rhs> Intersect<Lhs, Rhs, Blank>
+where
+    Lhs: G::QueryTerm,
+    Rhs: G::QueryPrimary,
+{
+    pub fn new(lhs: Lhs, rhs: Rhs) -> Self {
+        Self {
+            quantifier: None,
+            lhs,
+            rhs,
+            spec: Blank,
+        }
+    }
+}
+
+impl<Lhs, Rhs, Spec> Intersect<Lhs, Rhs, Spec>
+where
+    Lhs: G::QueryTerm,
+    Rhs: G::QueryPrimary,
+    Spec: G::CorrespondingSpec,
+{
+    /// Removed duplicated rows
+    pub fn distinct(self) -> Self {
+        Self {
+            quantifier: Some(SetQuantifier::Distinct),
+            ..self
+        }
+    }
+
+    /// All rows are kept
+    pub fn all(self) -> Self {
+        Self {
+            quantifier: Some(SetQuantifier::All),
+            ..self
+        }
+    }
+
+    /// Only the columns with the same name in both queries are intersected.
+    pub fn corresponding(self) -> Intersect<Lhs, Rhs, Corresponding<Blank>> {
+        self.transform_corresponding_spec(|_| Corresponding::new(Blank))
+    }
+
+    /// Only the listed columns of both queries are intersected.
+    pub fn corresponding_by<Columns>(
+        self,
+        columns: Columns,
+    ) -> Intersect<Lhs, Rhs, Corresponding<Columns>>
+    where
+        Columns: G::CorrespondingColumnList,
+    {
+        self.transform_corresponding_spec(|_| Corresponding::new(columns))
+    }
+
+    /// Transform the corresponding spec
+    pub fn transform_corresponding_spec<NewSpec>(
+        self,
+        transform: impl FnOnce(Spec) -> NewSpec,
+    ) -> Intersect<Lhs, Rhs, NewSpec>
+    where
+        NewSpec: G::CorrespondingSpec,
+    {
+        Intersect {
+            quantifier: self.quantifier,
+            lhs: self.lhs,
+            rhs: self.rhs,
+            spec: transform(self.spec),
+        }
+    }
+}
+
+impl<Lhs, Rhs, Spec> H::QueryExpression for Intersect<Lhs, Rhs, Spec>
+where
+    Lhs: G::QueryTerm,
+    Rhs: G::QueryPrimary,
+    Spec: G::CorrespondingSpec,
+{
+    type WithClause = Blank;
+    type Body = Self;
+    type OrderByClause = Blank;
+    type ResultOffsetClause = Blank;
+    type FetchFirstClause = Blank;
+    type IntoQueryExpressionBody = Self;
+    type IntoQueryTerm = Self;
+    type IntoQueryPrimary = NestedQueryExpression<Self>;
+
+    #[inline]
+    fn unwrap(self) -> QueryExpr<Blank, Self, Blank, Blank, Blank> {
+        QueryExpr {
+            with_clause: Blank,
+            body: self,
+            order_by: Blank,
+            offset: Blank,
+            fetch: Blank,
+        }
+    }
+
+    #[inline]
+    fn into_query_expression_body(self) -> Self {
+        self
+    }
+
+    #[inline]
+    fn into_query_term(self) -> Self {
+        self
+    }
+
+    #[inline]
+    fn into_query_primary(self) -> NestedQueryExpression<Self> {
+        NestedQueryExpression::new(self)
+    }
+}
+
+impl<Lhs, Rhs, Spec> std::fmt::Display for Intersect<Lhs, Rhs, Spec>
+where
+    Lhs: G::QueryTerm + std::fmt::Display,
+    Rhs: G::QueryPrimary + std::fmt::Display,
+    Spec: G::CorrespondingSpec + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} INTERSECT ", self.lhs)?;
+
+        if let Some(q) = &self.quantifier {
+            write!(f, "{} ", q)?;
+        }
+
+        if Spec::IS_IMPL {
+            write!(f, "{} ", self.spec)?;
+        }
+
+        write!(f, "{}", self.rhs)
+    }
+}
+
+impl<'q, DB, Lhs, Rhs, Spec> ToQuery<'q, DB> for Intersect<Lhs, Rhs, Spec>
+where
+    DB: Database,
+    Lhs: G::QueryTerm + ToQuery<'q, DB>,
+    Rhs: G::QueryPrimary + ToQuery<'q, DB>,
+    Spec: G::CorrespondingSpec + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        self.lhs.write(ctx)?;
+        write!(ctx, " INTERSECT ")?;
+
+        if let Some(q) = &self.quantifier {
+            write!(ctx, "{} ", q)?;
+        }
+
+        if Spec::IS_IMPL {
+            self.spec.write(ctx)?;
+            write!(ctx, " ")?;
+        }
+
+        self.rhs.write(ctx)
+    }
+}
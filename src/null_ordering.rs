@@ -0,0 +1,51 @@
+use crate::{Database, ToQuery};
+use sql_builder_macros::NullOrdering;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, NullOrdering)]
+pub struct NullsFirst;
+
+impl AsRef<str> for NullsFirst {
+    fn as_ref(&self) -> &str {
+        "NULLS FIRST"
+    }
+}
+
+impl std::fmt::Display for NullsFirst {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl<'q, DB> ToQuery<'q, DB> for NullsFirst
+where
+    DB: Database,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "{}", self.as_ref())
+    }
+}
+
+#[derive(Clone, Copy, NullOrdering)]
+pub struct NullsLast;
+
+impl AsRef<str> for NullsLast {
+    fn as_ref(&self) -> &str {
+        "NULLS LAST"
+    }
+}
+
+impl std::fmt::Display for NullsLast {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl<'q, DB> ToQuery<'q, DB> for NullsLast
+where
+    DB: Database,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "{}", self.as_ref())
+    }
+}
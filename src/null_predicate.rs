@@ -0,0 +1,81 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::NullPredicate;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, NullPredicate)]
+/// <row value predicand> IS [ NOT ] NULL
+///
+/// See [self::is_null] and [self::is_not_null]
+pub struct IsNull<Value>
+where
+    Value: G::RowValuePredicand,
+{
+    value: Value,
+    negated: bool,
+}
+
+impl<Value> ::std::fmt::Display for IsNull<Value>
+where
+    Value: G::RowValuePredicand + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} IS ", self.value)?;
+
+        if self.negated {
+            write!(f, "NOT ")?;
+        }
+
+        write!(f, "NULL")
+    }
+}
+
+impl<'q, DB, Value> ToQuery<'q, DB> for IsNull<Value>
+where
+    DB: Database,
+    Value: G::RowValuePredicand + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        self.value.write(ctx)?;
+        write!(ctx, " IS ")?;
+
+        if self.negated {
+            write!(ctx, "NOT ")?;
+        }
+
+        write!(ctx, "NULL")
+    }
+}
+
+#[inline]
+/// Checks if the value is null
+///
+/// # SQL
+/// ```sql
+/// <value> IS NULL
+/// ```
+pub fn is_null<Value>(value: Value) -> IsNull<Value>
+where
+    Value: G::RowValuePredicand,
+{
+    IsNull {
+        value,
+        negated: false,
+    }
+}
+
+#[inline]
+/// Checks if the value is not null
+///
+/// # SQL
+/// ```sql
+/// <value> IS NOT NULL
+/// ```
+pub fn is_not_null<Value>(value: Value) -> IsNull<Value>
+where
+    Value: G::RowValuePredicand,
+{
+    IsNull {
+        value,
+        negated: true,
+    }
+}
@@ -1,8 +1,5 @@
 use crate::grammar as G;
-use crate::{
-    grammar::{NumericValueExpression, Term},
-    Database, ToQuery,
-};
+use crate::{Database, ToQuery};
 use sql_builder_macros::NumericValueExpression;
 use std::fmt::Write;
 
@@ -27,7 +24,7 @@ impl std::fmt::Display for ArithmOpKind {
     }
 }
 
-#[derive(NumericValueExpression)]
+#[derive(Clone, Copy, NumericValueExpression)]
 pub struct ArithmOperand<Lhs, Rhs>
 where
     Lhs: G::NumericValueExpression,
@@ -62,10 +59,11 @@ where
 }
 
 #[inline]
-pub fn add<Lhs, Rhs>(
-    lhs: impl NumericValueExpression,
-    rhs: impl Term,
-) -> impl NumericValueExpression {
+pub fn add<Lhs, Rhs>(lhs: Lhs, rhs: Rhs) -> ArithmOperand<Lhs, Rhs>
+where
+    Lhs: G::NumericValueExpression,
+    Rhs: G::Term,
+{
     ArithmOperand {
         lhs,
         rhs,
@@ -74,10 +72,11 @@ pub fn add<Lhs, Rhs>(
 }
 
 #[inline]
-pub fn sub<Lhs, Rhs>(
-    lhs: impl NumericValueExpression,
-    rhs: impl Term,
-) -> impl NumericValueExpression {
+pub fn sub<Lhs, Rhs>(lhs: Lhs, rhs: Rhs) -> ArithmOperand<Lhs, Rhs>
+where
+    Lhs: G::NumericValueExpression,
+    Rhs: G::Term,
+{
     ArithmOperand {
         lhs,
         rhs,
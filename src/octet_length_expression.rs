@@ -0,0 +1,44 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::OctetLengthExpression;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, OctetLengthExpression)]
+/// OCTET_LENGTH(<string value expression>)
+///
+/// See [self::octet_length]
+pub struct OctetLength<Value>
+where
+    Value: G::StringValueExpression,
+{
+    value: Value,
+}
+
+impl<Value> ::std::fmt::Display for OctetLength<Value>
+where
+    Value: G::StringValueExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "OCTET_LENGTH({})", self.value)
+    }
+}
+
+impl<'q, DB, Value> ToQuery<'q, DB> for OctetLength<Value>
+where
+    DB: Database,
+    Value: G::StringValueExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "OCTET_LENGTH(")?;
+        self.value.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Returns the number of bytes of the string.
+pub fn octet_length<Value>(value: Value) -> OctetLength<Value>
+where
+    Value: G::StringValueExpression,
+{
+    OctetLength { value }
+}
@@ -0,0 +1,63 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::OrderByClause;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, OrderByClause)]
+/// ORDER BY <sort specification list>
+pub struct OrderBy<Specs>
+where
+    Specs: G::SortSpecificationList,
+{
+    specs: Specs,
+}
+
+impl<Specs> OrderBy<Specs>
+where
+    Specs: G::SortSpecificationList,
+{
+    pub fn new(specs: Specs) -> Self {
+        Self { specs }
+    }
+}
+
+impl<Specs> ::std::fmt::Display for OrderBy<Specs>
+where
+    Specs: G::SortSpecificationList + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ORDER BY {}", self.specs)
+    }
+}
+
+impl<'q, DB, Specs> ToQuery<'q, DB> for OrderBy<Specs>
+where
+    DB: Database,
+    Specs: G::SortSpecificationList + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "ORDER BY ")?;
+        self.specs.write(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OrderBy;
+    use crate::{id, prelude::*};
+
+    #[test]
+    fn test_order_by() {
+        let clause = OrderBy::new(
+            id("a")
+                .desc()
+                .nulls_last()
+                .add_sort_specification(id("b").collate(id("fr_FR")))
+                .add_sort_specification(id("c")),
+        );
+
+        assert_eq!(
+            clause.to_string(),
+            "ORDER BY a DESC NULLS LAST, b COLLATE fr_FR, c"
+        );
+    }
+}
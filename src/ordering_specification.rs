@@ -0,0 +1,51 @@
+use crate::{Database, ToQuery};
+use sql_builder_macros::OrderingSpecification;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, OrderingSpecification)]
+pub struct Asc;
+
+impl AsRef<str> for Asc {
+    fn as_ref(&self) -> &str {
+        "ASC"
+    }
+}
+
+impl std::fmt::Display for Asc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl<'q, DB> ToQuery<'q, DB> for Asc
+where
+    DB: Database,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "{}", self.as_ref())
+    }
+}
+
+#[derive(Clone, Copy, OrderingSpecification)]
+pub struct Desc;
+
+impl AsRef<str> for Desc {
+    fn as_ref(&self) -> &str {
+        "DESC"
+    }
+}
+
+impl std::fmt::Display for Desc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl<'q, DB> ToQuery<'q, DB> for Desc
+where
+    DB: Database,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "{}", self.as_ref())
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::OrdinaryGroupingSet;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, OrdinaryGroupingSet)]
+/// <left paren> <grouping column reference list> <right paren>
+pub struct GroupingColumns<List>(List)
+where
+    List: G::GroupingColumnReferenceList;
+
+impl<List> ::std::fmt::Display for GroupingColumns<List>
+where
+    List: G::GroupingColumnReferenceList + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({})", self.0)
+    }
+}
+
+impl<'q, DB, List> ToQuery<'q, DB> for GroupingColumns<List>
+where
+    DB: Database,
+    List: G::GroupingColumnReferenceList + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "(")?;
+        self.0.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Groups the rows on a composite set of columns.
+pub fn grouping_columns<List>(list: List) -> GroupingColumns<List>
+where
+    List: G::GroupingColumnReferenceList,
+{
+    GroupingColumns(list)
+}
@@ -0,0 +1,47 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::OrdinaryGroupingSetList;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, OrdinaryGroupingSetList)]
+/// A linked-list of ordinary grouping sets.
+pub struct OrdinaryGroupingSetLink<Head, Tail>
+where
+    Head: G::OrdinaryGroupingSetList,
+    Tail: G::OrdinaryGroupingSet,
+{
+    head: Head,
+    tail: Tail,
+}
+
+impl<Head, Tail> OrdinaryGroupingSetLink<Head, Tail>
+where
+    Head: G::OrdinaryGroupingSetList,
+    Tail: G::OrdinaryGroupingSet,
+{
+    pub fn new(head: Head, tail: Tail) -> Self {
+        Self { head, tail }
+    }
+}
+
+impl<Head, Tail> ::std::fmt::Display for OrdinaryGroupingSetLink<Head, Tail>
+where
+    Head: G::OrdinaryGroupingSetList + std::fmt::Display,
+    Tail: G::OrdinaryGroupingSet + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}", self.head, self.tail)
+    }
+}
+
+impl<'q, DB, Head, Tail> ToQuery<'q, DB> for OrdinaryGroupingSetLink<Head, Tail>
+where
+    DB: Database,
+    Head: G::OrdinaryGroupingSetList + ToQuery<'q, DB>,
+    Tail: G::OrdinaryGroupingSet + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> ::std::fmt::Result {
+        self.head.write(ctx)?;
+        write!(ctx, ", ")?;
+        self.tail.write(ctx)
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::PowerFunction;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, PowerFunction)]
+/// POWER(<numeric value expression base>, <numeric value expression exponent>)
+///
+/// See [self::power]
+pub struct Power<Base, Exponent>
+where
+    Base: G::NumericValueExpression,
+    Exponent: G::NumericValueExpression,
+{
+    base: Base,
+    exponent: Exponent,
+}
+
+impl<Base, Exponent> ::std::fmt::Display for Power<Base, Exponent>
+where
+    Base: G::NumericValueExpression + std::fmt::Display,
+    Exponent: G::NumericValueExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "POWER({}, {})", self.base, self.exponent)
+    }
+}
+
+impl<'q, DB, Base, Exponent> ToQuery<'q, DB> for Power<Base, Exponent>
+where
+    DB: Database,
+    Base: G::NumericValueExpression + ToQuery<'q, DB>,
+    Exponent: G::NumericValueExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "POWER(")?;
+        self.base.write(ctx)?;
+        write!(ctx, ", ")?;
+        self.exponent.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Raises the base to the power of the exponent.
+pub fn power<Base, Exponent>(base: Base, exponent: Exponent) -> Power<Base, Exponent>
+where
+    Base: G::NumericValueExpression,
+    Exponent: G::NumericValueExpression,
+{
+    Power { base, exponent }
+}
@@ -1,3 +1,4 @@
+use crate::{blank::Blank, helpers as H, query_expression::QueryExpr};
 use crate::{
     grammar::{self as G},
     join_condition::JoinCondition,
@@ -135,3 +136,46 @@ where
         )
     }
 }
+
+impl<Src, Kind, Dest, Spec> H::QueryExpression for QualifiedJoin<Src, Kind, Dest, Spec>
+where
+    Src: G::TableReference,
+    Dest: G::TablePrimary,
+    Kind: G::JoinType,
+    Spec: G::JoinSpecification,
+{
+    type WithClause = Blank;
+    type Body = Self;
+    type OrderByClause = Blank;
+    type ResultOffsetClause = Blank;
+    type FetchFirstClause = Blank;
+    type IntoQueryExpressionBody = Self;
+    type IntoQueryTerm = Self;
+    type IntoQueryPrimary = Self;
+
+    #[inline]
+    fn unwrap(self) -> QueryExpr<Blank, Self, Blank, Blank, Blank> {
+        QueryExpr {
+            with_clause: Blank,
+            body: self,
+            order_by: Blank,
+            offset: Blank,
+            fetch: Blank,
+        }
+    }
+
+    #[inline]
+    fn into_query_expression_body(self) -> Self {
+        self
+    }
+
+    #[inline]
+    fn into_query_term(self) -> Self {
+        self
+    }
+
+    #[inline]
+    fn into_query_primary(self) -> Self {
+        self
+    }
+}
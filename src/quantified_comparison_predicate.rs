@@ -0,0 +1,301 @@
+use crate::{
+    comparison_predicate::ComparisonKind, grammar as G, subquery::Subquery, Database, ToQuery,
+};
+use sql_builder_macros::QuantifiedComparisonPredicate;
+use std::fmt::Write;
+
+#[derive(Clone, Copy)]
+/// The quantifier of a quantified comparison.
+pub enum Quantifier {
+    /// The comparison must hold for every row of the subquery.
+    All,
+    /// The comparison must hold for at least one row of the subquery.
+    Some,
+    /// Same as [Quantifier::Some].
+    Any,
+}
+
+impl AsRef<str> for Quantifier {
+    fn as_ref(&self) -> &str {
+        match self {
+            Quantifier::All => "ALL",
+            Quantifier::Some => "SOME",
+            Quantifier::Any => "ANY",
+        }
+    }
+}
+
+impl std::fmt::Display for Quantifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Clone, Copy, QuantifiedComparisonPredicate)]
+/// <row value predicand> <comp op> <quantifier> <table subquery>
+///
+/// See [self::quantified]
+pub struct QuantifiedCompare<Lhs, Query>
+where
+    Lhs: G::RowValuePredicand,
+    Query: G::TableSubquery,
+{
+    lhs: Lhs,
+    op: ComparisonKind,
+    quantifier: Quantifier,
+    subquery: Query,
+}
+
+impl<Lhs, Query> ::std::fmt::Display for QuantifiedCompare<Lhs, Query>
+where
+    Lhs: G::RowValuePredicand + std::fmt::Display,
+    Query: G::TableSubquery + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.lhs, self.op, self.quantifier, self.subquery
+        )
+    }
+}
+
+impl<'q, DB, Lhs, Query> ToQuery<'q, DB> for QuantifiedCompare<Lhs, Query>
+where
+    DB: Database,
+    Lhs: G::RowValuePredicand + ToQuery<'q, DB>,
+    Query: G::TableSubquery + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        self.lhs.write(ctx)?;
+        write!(ctx, " ")?;
+        self.op.write(ctx)?;
+        write!(ctx, " {} ", self.quantifier)?;
+        self.subquery.write(ctx)
+    }
+}
+
+#[inline]
+/// Compares the value with the rows returned by the query
+///
+/// # SQL
+/// ```sql
+/// <lhs> <op> <quantifier> (<query>)
+/// ```
+pub fn quantified<Lhs, Query>(
+    lhs: Lhs,
+    op: ComparisonKind,
+    quantifier: Quantifier,
+    query: Query,
+) -> QuantifiedCompare<Lhs, Subquery<Query>>
+where
+    Lhs: G::RowValuePredicand,
+    Query: G::QueryExpression,
+{
+    QuantifiedCompare {
+        lhs,
+        op,
+        quantifier,
+        subquery: Subquery::new(query),
+    }
+}
+
+#[inline]
+/// Checks if the value is equals to at least one of the rows of the query
+///
+/// # SQL
+/// ```sql
+/// <lhs> = ANY (<query>)
+/// ```
+pub fn eq_any<Lhs, Query>(lhs: Lhs, query: Query) -> QuantifiedCompare<Lhs, Subquery<Query>>
+where
+    Lhs: G::RowValuePredicand,
+    Query: G::QueryExpression,
+{
+    quantified(lhs, ComparisonKind::Equals, Quantifier::Any, query)
+}
+
+#[inline]
+/// Checks if the value is not equals to at least one of the rows of the query
+///
+/// # SQL
+/// ```sql
+/// <lhs> <> ANY (<query>)
+/// ```
+pub fn neq_any<Lhs, Query>(lhs: Lhs, query: Query) -> QuantifiedCompare<Lhs, Subquery<Query>>
+where
+    Lhs: G::RowValuePredicand,
+    Query: G::QueryExpression,
+{
+    quantified(lhs, ComparisonKind::NotEquals, Quantifier::Any, query)
+}
+
+#[inline]
+/// Checks if the value is less than at least one of the rows of the query
+///
+/// # SQL
+/// ```sql
+/// <lhs> < ANY (<query>)
+/// ```
+pub fn lt_any<Lhs, Query>(lhs: Lhs, query: Query) -> QuantifiedCompare<Lhs, Subquery<Query>>
+where
+    Lhs: G::RowValuePredicand,
+    Query: G::QueryExpression,
+{
+    quantified(lhs, ComparisonKind::LessThan, Quantifier::Any, query)
+}
+
+#[inline]
+/// Checks if the value is less than or equals to at least one of the rows of the query
+///
+/// # SQL
+/// ```sql
+/// <lhs> <= ANY (<query>)
+/// ```
+pub fn lte_any<Lhs, Query>(lhs: Lhs, query: Query) -> QuantifiedCompare<Lhs, Subquery<Query>>
+where
+    Lhs: G::RowValuePredicand,
+    Query: G::QueryExpression,
+{
+    quantified(
+        lhs,
+        ComparisonKind::LessThanOrEquals,
+        Quantifier::Any,
+        query,
+    )
+}
+
+#[inline]
+/// Checks if the value is greater than at least one of the rows of the query
+///
+/// # SQL
+/// ```sql
+/// <lhs> > ANY (<query>)
+/// ```
+pub fn gt_any<Lhs, Query>(lhs: Lhs, query: Query) -> QuantifiedCompare<Lhs, Subquery<Query>>
+where
+    Lhs: G::RowValuePredicand,
+    Query: G::QueryExpression,
+{
+    quantified(lhs, ComparisonKind::GreaterThan, Quantifier::Any, query)
+}
+
+#[inline]
+/// Checks if the value is greater than or equals to at least one of the rows of the query
+///
+/// # SQL
+/// ```sql
+/// <lhs> >= ANY (<query>)
+/// ```
+pub fn gte_any<Lhs, Query>(lhs: Lhs, query: Query) -> QuantifiedCompare<Lhs, Subquery<Query>>
+where
+    Lhs: G::RowValuePredicand,
+    Query: G::QueryExpression,
+{
+    quantified(
+        lhs,
+        ComparisonKind::GreaterThanOrEquals,
+        Quantifier::Any,
+        query,
+    )
+}
+
+#[inline]
+/// Checks if the value is equals to all the rows of the query
+///
+/// # SQL
+/// ```sql
+/// <lhs> = ALL (<query>)
+/// ```
+pub fn eq_all<Lhs, Query>(lhs: Lhs, query: Query) -> QuantifiedCompare<Lhs, Subquery<Query>>
+where
+    Lhs: G::RowValuePredicand,
+    Query: G::QueryExpression,
+{
+    quantified(lhs, ComparisonKind::Equals, Quantifier::All, query)
+}
+
+#[inline]
+/// Checks if the value is not equals to all the rows of the query
+///
+/// # SQL
+/// ```sql
+/// <lhs> <> ALL (<query>)
+/// ```
+pub fn neq_all<Lhs, Query>(lhs: Lhs, query: Query) -> QuantifiedCompare<Lhs, Subquery<Query>>
+where
+    Lhs: G::RowValuePredicand,
+    Query: G::QueryExpression,
+{
+    quantified(lhs, ComparisonKind::NotEquals, Quantifier::All, query)
+}
+
+#[inline]
+/// Checks if the value is less than all the rows of the query
+///
+/// # SQL
+/// ```sql
+/// <lhs> < ALL (<query>)
+/// ```
+pub fn lt_all<Lhs, Query>(lhs: Lhs, query: Query) -> QuantifiedCompare<Lhs, Subquery<Query>>
+where
+    Lhs: G::RowValuePredicand,
+    Query: G::QueryExpression,
+{
+    quantified(lhs, ComparisonKind::LessThan, Quantifier::All, query)
+}
+
+#[inline]
+/// Checks if the value is less than or equals to all the rows of the query
+///
+/// # SQL
+/// ```sql
+/// <lhs> <= ALL (<query>)
+/// ```
+pub fn lte_all<Lhs, Query>(lhs: Lhs, query: Query) -> QuantifiedCompare<Lhs, Subquery<Query>>
+where
+    Lhs: G::RowValuePredicand,
+    Query: G::QueryExpression,
+{
+    quantified(
+        lhs,
+        ComparisonKind::LessThanOrEquals,
+        Quantifier::All,
+        query,
+    )
+}
+
+#[inline]
+/// Checks if the value is greater than all the rows of the query
+///
+/// # SQL
+/// ```sql
+/// <lhs> > ALL (<query>)
+/// ```
+pub fn gt_all<Lhs, Query>(lhs: Lhs, query: Query) -> QuantifiedCompare<Lhs, Subquery<Query>>
+where
+    Lhs: G::RowValuePredicand,
+    Query: G::QueryExpression,
+{
+    quantified(lhs, ComparisonKind::GreaterThan, Quantifier::All, query)
+}
+
+#[inline]
+/// Checks if the value is greater than or equals to all the rows of the query
+///
+/// # SQL
+/// ```sql
+/// <lhs> >= ALL (<query>)
+/// ```
+pub fn gte_all<Lhs, Query>(lhs: Lhs, query: Query) -> QuantifiedCompare<Lhs, Subquery<Query>>
+where
+    Lhs: G::RowValuePredicand,
+    Query: G::QueryExpression,
+{
+    quantified(
+        lhs,
+        ComparisonKind::GreaterThanOrEquals,
+        Quantifier::All,
+        query,
+    )
+}
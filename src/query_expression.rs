@@ -0,0 +1,216 @@
+use crate::{either::Either, non_join_query_primary::NestedQueryExpression, ToQuery};
+use sql_builder_macros::QueryExpression;
+use std::fmt::Write;
+
+use crate::grammar as G;
+use crate::helpers as H;
+use crate::Database;
+
+#[derive(Clone, Copy, QueryExpression)]
+/// Represents a query expression, a query expression body with its optional
+/// common table expressions, ordering and row limitation.
+///
+/// See [crate::helpers::QueryExpression::order_by], [crate::helpers::QueryExpression::offset],
+/// [crate::helpers::QueryExpression::fetch_first] and [crate::with]
+pub struct QueryExpr<With, Body, OrderBy, Offset, Fetch>
+where
+    With: G::WithClause,
+    Body: G::QueryExpressionBody,
+    OrderBy: G::OrderByClause,
+    Offset: G::ResultOffsetClause,
+    Fetch: G::FetchFirstClause,
+{
+    pub with_clause: With,
+    pub body: Body,
+    pub order_by: OrderBy,
+    pub offset: Offset,
+    pub fetch: Fetch,
+}
+
+impl<With, Body, OrderBy, Offset, Fetch> H::QueryExpression
+    for QueryExpr<With, Body, OrderBy, Offset, Fetch>
+where
+    With: G::WithClause,
+    Body: G::QueryExpressionBody,
+    OrderBy: G::OrderByClause,
+    Offset: G::ResultOffsetClause,
+    Fetch: G::FetchFirstClause,
+{
+    type WithClause = With;
+    type Body = Body;
+    type OrderByClause = OrderBy;
+    type ResultOffsetClause = Offset;
+    type FetchFirstClause = Fetch;
+    type IntoQueryExpressionBody = NestedQueryExpression<Self>;
+    type IntoQueryTerm = NestedQueryExpression<Self>;
+    type IntoQueryPrimary = NestedQueryExpression<Self>;
+
+    #[inline]
+    fn unwrap(self) -> Self {
+        self
+    }
+
+    #[inline]
+    fn into_query_expression_body(self) -> NestedQueryExpression<Self> {
+        NestedQueryExpression::new(self)
+    }
+
+    #[inline]
+    fn into_query_term(self) -> NestedQueryExpression<Self> {
+        NestedQueryExpression::new(self)
+    }
+
+    #[inline]
+    fn into_query_primary(self) -> NestedQueryExpression<Self> {
+        NestedQueryExpression::new(self)
+    }
+}
+
+impl<With, Body, OrderBy, Offset, Fetch> std::fmt::Display
+    for QueryExpr<With, Body, OrderBy, Offset, Fetch>
+where
+    With: G::WithClause + std::fmt::Display,
+    Body: G::QueryExpressionBody + std::fmt::Display,
+    OrderBy: G::OrderByClause + std::fmt::Display,
+    Offset: G::ResultOffsetClause + std::fmt::Display,
+    Fetch: G::FetchFirstClause + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if With::IS_IMPL {
+            write!(f, "{} ", self.with_clause)?;
+        }
+
+        write!(f, "{}", self.body)?;
+
+        if OrderBy::IS_IMPL {
+            write!(f, " {}", self.order_by)?;
+        }
+
+        if Offset::IS_IMPL {
+            write!(f, " {}", self.offset)?;
+        }
+
+        if Fetch::IS_IMPL {
+            write!(f, " {}", self.fetch)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'q, DB, With, Body, OrderBy, Offset, Fetch> ToQuery<'q, DB>
+    for QueryExpr<With, Body, OrderBy, Offset, Fetch>
+where
+    DB: Database,
+    With: G::WithClause + ToQuery<'q, DB>,
+    Body: G::QueryExpressionBody + ToQuery<'q, DB>,
+    OrderBy: G::OrderByClause + ToQuery<'q, DB>,
+    Offset: G::ResultOffsetClause + ToQuery<'q, DB>,
+    Fetch: G::FetchFirstClause + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        if With::IS_IMPL {
+            self.with_clause.write(ctx)?;
+            write!(ctx, " ")?;
+        }
+
+        self.body.write(ctx)?;
+
+        if OrderBy::IS_IMPL {
+            write!(ctx, " ")?;
+            self.order_by.write(ctx)?;
+        }
+
+        if Offset::IS_IMPL {
+            write!(ctx, " ")?;
+            self.offset.write(ctx)?;
+        }
+
+        if Fetch::IS_IMPL {
+            write!(ctx, " ")?;
+            self.fetch.write(ctx)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<Lhs, Rhs> H::QueryExpression for Either<Lhs, Rhs>
+where
+    Lhs: G::QueryExpression,
+    Rhs: G::QueryExpression,
+{
+    type WithClause = Either<Lhs::WithClause, Rhs::WithClause>;
+    type Body = Either<Lhs::Body, Rhs::Body>;
+    type OrderByClause = Either<Lhs::OrderByClause, Rhs::OrderByClause>;
+    type ResultOffsetClause = Either<Lhs::ResultOffsetClause, Rhs::ResultOffsetClause>;
+    type FetchFirstClause = Either<Lhs::FetchFirstClause, Rhs::FetchFirstClause>;
+    type IntoQueryExpressionBody =
+        Either<Lhs::IntoQueryExpressionBody, Rhs::IntoQueryExpressionBody>;
+    type IntoQueryTerm = Either<Lhs::IntoQueryTerm, Rhs::IntoQueryTerm>;
+    type IntoQueryPrimary = Either<Lhs::IntoQueryPrimary, Rhs::IntoQueryPrimary>;
+
+    fn unwrap(
+        self,
+    ) -> QueryExpr<
+        Self::WithClause,
+        Self::Body,
+        Self::OrderByClause,
+        Self::ResultOffsetClause,
+        Self::FetchFirstClause,
+    > {
+        match self {
+            Either::Left(lhs) => {
+                let QueryExpr {
+                    with_clause,
+                    body,
+                    order_by,
+                    offset,
+                    fetch,
+                } = lhs.unwrap();
+
+                QueryExpr {
+                    with_clause: Either::Left(with_clause),
+                    body: Either::Left(body),
+                    order_by: Either::Left(order_by),
+                    offset: Either::Left(offset),
+                    fetch: Either::Left(fetch),
+                }
+            }
+            Either::Right(rhs) => {
+                let QueryExpr {
+                    with_clause,
+                    body,
+                    order_by,
+                    offset,
+                    fetch,
+                } = rhs.unwrap();
+
+                QueryExpr {
+                    with_clause: Either::Right(with_clause),
+                    body: Either::Right(body),
+                    order_by: Either::Right(order_by),
+                    offset: Either::Right(offset),
+                    fetch: Either::Right(fetch),
+                }
+            }
+        }
+    }
+    fn into_query_expression_body(self) -> Self::IntoQueryExpressionBody {
+        self.apply(
+            |lhs| lhs.into_query_expression_body(),
+            |rhs| rhs.into_query_expression_body(),
+        )
+    }
+
+    fn into_query_term(self) -> Self::IntoQueryTerm {
+        self.apply(|lhs| lhs.into_query_term(), |rhs| rhs.into_query_term())
+    }
+
+    fn into_query_primary(self) -> Self::IntoQueryPrimary {
+        self.apply(
+            |lhs| lhs.into_query_primary(),
+            |rhs| rhs.into_query_primary(),
+        )
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{Database, ToQuery};
+use sql_builder_macros::WindowFunctionType;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, WindowFunctionType)]
+/// <rank function type> () | ROW_NUMBER ()
+///
+/// See [self::row_number], [self::rank], [self::dense_rank], [self::percent_rank] and [self::cume_dist]
+pub enum RankFunction {
+    RowNumber,
+    Rank,
+    DenseRank,
+    PercentRank,
+    CumeDist,
+}
+
+impl AsRef<str> for RankFunction {
+    fn as_ref(&self) -> &str {
+        match self {
+            RankFunction::RowNumber => "ROW_NUMBER",
+            RankFunction::Rank => "RANK",
+            RankFunction::DenseRank => "DENSE_RANK",
+            RankFunction::PercentRank => "PERCENT_RANK",
+            RankFunction::CumeDist => "CUME_DIST",
+        }
+    }
+}
+
+impl std::fmt::Display for RankFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}()", self.as_ref())
+    }
+}
+
+impl<'q, DB> ToQuery<'q, DB> for RankFunction
+where
+    DB: Database,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "{}", self)
+    }
+}
+
+#[inline]
+/// Numbers the rows of the window partition, starting from 1.
+///
+/// # Example
+/// ```
+/// use sql_builder::{id, row_number, window, prelude::*};
+///
+/// let expr = row_number().over(window().order_by(id!(created_at)));
+/// assert_eq!(expr.to_string(), "ROW_NUMBER() OVER (ORDER BY created_at)");
+/// ```
+pub fn row_number() -> RankFunction {
+    RankFunction::RowNumber
+}
+
+#[inline]
+/// Ranks the rows of the window partition, with gaps between peers.
+pub fn rank() -> RankFunction {
+    RankFunction::Rank
+}
+
+#[inline]
+/// Ranks the rows of the window partition, without gaps between peers.
+pub fn dense_rank() -> RankFunction {
+    RankFunction::DenseRank
+}
+
+#[inline]
+/// Returns the relative rank of the rows of the window partition.
+pub fn percent_rank() -> RankFunction {
+    RankFunction::PercentRank
+}
+
+#[inline]
+/// Returns the cumulative distribution of the rows of the window partition.
+pub fn cume_dist() -> RankFunction {
+    RankFunction::CumeDist
+}
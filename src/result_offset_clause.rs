@@ -0,0 +1,89 @@
+use crate::{
+    blank::Blank, either::Either, fetch_first_clause::Limit, grammar as G, helpers as H, Database,
+    ToQuery,
+};
+use sql_builder_macros::ResultOffsetClause;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, ResultOffsetClause)]
+/// OFFSET <offset row count> ROWS
+pub struct Offset<Count>
+where
+    Count: G::OffsetRowCount,
+{
+    count: Count,
+}
+
+impl<Count> Offset<Count>
+where
+    Count: G::OffsetRowCount,
+{
+    pub fn new(count: Count) -> Self {
+        Self { count }
+    }
+}
+
+impl<Count> ::std::fmt::Display for Offset<Count>
+where
+    Count: G::OffsetRowCount + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "OFFSET {} ROWS", self.count)
+    }
+}
+
+impl<'q, DB, Count> ToQuery<'q, DB> for Offset<Count>
+where
+    DB: Database,
+    Count: G::OffsetRowCount + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "OFFSET ")?;
+        self.count.write(ctx)?;
+        write!(ctx, " ROWS")
+    }
+}
+
+impl H::ResultOffsetClause for Blank {
+    type WithLimit<Count: G::FetchFirstRowCount> = Limit<Count, Blank>;
+
+    fn with_limit<Count>(self, count: Count) -> Limit<Count, Blank>
+    where
+        Count: G::FetchFirstRowCount,
+    {
+        Limit::new(count, self)
+    }
+}
+
+impl<OffsetCount> H::ResultOffsetClause for Offset<OffsetCount>
+where
+    OffsetCount: G::OffsetRowCount,
+{
+    type WithLimit<Count: G::FetchFirstRowCount> = Limit<Count, OffsetCount>;
+
+    fn with_limit<Count>(self, count: Count) -> Limit<Count, OffsetCount>
+    where
+        Count: G::FetchFirstRowCount,
+    {
+        Limit::new(count, self.count)
+    }
+}
+
+impl<Lhs, Rhs> H::ResultOffsetClause for Either<Lhs, Rhs>
+where
+    Lhs: G::ResultOffsetClause,
+    Rhs: G::ResultOffsetClause,
+{
+    type WithLimit<Count: G::FetchFirstRowCount> =
+        Either<Lhs::WithLimit<Count>, Rhs::WithLimit<Count>>;
+
+    fn with_limit<Count>(self, count: Count) -> Self::WithLimit<Count>
+    where
+        Count: G::FetchFirstRowCount,
+    {
+        match self {
+            Either::Left(lhs) => Either::Left(lhs.with_limit(count)),
+            Either::Right(rhs) => Either::Right(rhs.with_limit(count)),
+        }
+    }
+}
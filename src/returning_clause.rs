@@ -0,0 +1,43 @@
+use crate::grammar as G;
+use crate::Database;
+use crate::ToQuery;
+use sql_builder_macros::ReturningClause;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, ReturningClause)]
+/// RETURNING <select list>
+///
+/// See [crate::helpers::Insert::returning], [crate::helpers::UpdateStatement::returning]
+/// and [crate::helpers::DeleteStatement::returning]
+pub struct Returning<List: G::SelectList> {
+    list: List,
+}
+
+impl<List> Returning<List>
+where
+    List: G::SelectList,
+{
+    pub fn new(list: List) -> Self {
+        Self { list }
+    }
+}
+
+impl<List> ::std::fmt::Display for Returning<List>
+where
+    List: G::SelectList + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RETURNING {}", self.list)
+    }
+}
+
+impl<'q, DB, List> ToQuery<'q, DB> for Returning<List>
+where
+    DB: Database,
+    List: G::SelectList + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "RETURNING ")?;
+        self.list.write(ctx)
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::RollupList;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, RollupList)]
+/// ROLLUP <left paren> <ordinary grouping set list> <right paren>
+pub struct Rollup<List>(List)
+where
+    List: G::OrdinaryGroupingSetList;
+
+impl<List> ::std::fmt::Display for Rollup<List>
+where
+    List: G::OrdinaryGroupingSetList + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ROLLUP({})", self.0)
+    }
+}
+
+impl<'q, DB, List> ToQuery<'q, DB> for Rollup<List>
+where
+    DB: Database,
+    List: G::OrdinaryGroupingSetList + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "ROLLUP(")?;
+        self.0.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Groups the rows on each prefix of the grouping sets, up to the grand total.
+pub fn rollup<List>(list: List) -> Rollup<List>
+where
+    List: G::OrdinaryGroupingSetList,
+{
+    Rollup(list)
+}
@@ -1,7 +1,9 @@
 use crate::{grammar as G, ToQuery};
+use sql_builder_macros::RoutineInvocation;
 use sqlx::Database;
 use std::fmt::Write;
 
+#[derive(Clone, Copy, RoutineInvocation)]
 pub struct RoutineInvocation<Name, Args>
 where
     Name: G::RoutineName,
@@ -0,0 +1,126 @@
+use crate::{
+    blank::Blank, else_clause::Else, grammar as G, searched_when_clause::SearchedWhen,
+    searched_when_clause_list::SearchedWhenLink, Database, ToQuery,
+};
+use sql_builder_macros::SearchedCase;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, SearchedCase)]
+/// CASE <searched when clause>... [ <else clause> ] END
+///
+/// See [self::case]
+pub struct SearchedCase<Whens, ElseClause>
+where
+    Whens: G::SearchedWhenClauseList,
+    ElseClause: G::ElseClause,
+{
+    whens: Whens,
+    else_clause: ElseClause,
+}
+
+impl<Whens> SearchedCase<Whens, Blank>
+where
+    Whens: G::SearchedWhenClauseList,
+{
+    /// Add a condition and its result.
+    pub fn when<Cond, Result>(
+        self,
+        condition: Cond,
+        result: Result,
+    ) -> SearchedCase<SearchedWhenLink<Whens, SearchedWhen<Cond, Result>>, Blank>
+    where
+        Cond: G::SearchCondition,
+        Result: G::ValueExpression,
+    {
+        SearchedCase {
+            whens: SearchedWhenLink::new(self.whens, SearchedWhen::new(condition, result)),
+            else_clause: Blank,
+        }
+    }
+
+    /// Set the result if no condition is met.
+    pub fn else_<Result>(self, result: Result) -> SearchedCase<Whens, Else<Result>>
+    where
+        Result: G::ValueExpression,
+    {
+        SearchedCase {
+            whens: self.whens,
+            else_clause: Else::new(result),
+        }
+    }
+}
+
+impl<Whens, ElseClause> ::std::fmt::Display for SearchedCase<Whens, ElseClause>
+where
+    Whens: G::SearchedWhenClauseList + std::fmt::Display,
+    ElseClause: G::ElseClause + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CASE {}", self.whens)?;
+
+        if ElseClause::IS_IMPL {
+            write!(f, " {}", self.else_clause)?;
+        }
+
+        write!(f, " END")
+    }
+}
+
+impl<'q, DB, Whens, ElseClause> ToQuery<'q, DB> for SearchedCase<Whens, ElseClause>
+where
+    DB: Database,
+    Whens: G::SearchedWhenClauseList + ToQuery<'q, DB>,
+    ElseClause: G::ElseClause + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "CASE ")?;
+        self.whens.write(ctx)?;
+
+        if ElseClause::IS_IMPL {
+            write!(ctx, " ")?;
+            self.else_clause.write(ctx)?;
+        }
+
+        write!(ctx, " END")
+    }
+}
+
+#[derive(Clone, Copy)]
+/// A partial fragment to build a searched case, waiting for its first condition.
+pub struct CaseFragment;
+
+impl CaseFragment {
+    /// Add the first condition and its result.
+    pub fn when<Cond, Result>(
+        self,
+        condition: Cond,
+        result: Result,
+    ) -> SearchedCase<SearchedWhen<Cond, Result>, Blank>
+    where
+        Cond: G::SearchCondition,
+        Result: G::ValueExpression,
+    {
+        SearchedCase {
+            whens: SearchedWhen::new(condition, result),
+            else_clause: Blank,
+        }
+    }
+}
+
+#[inline]
+/// Creates a searched case expression.
+///
+/// # Example
+/// ```
+/// use sql_builder::{case, gt, id, lit, prelude::*};
+///
+/// let expr = case()
+///     .when(gt(id!(total), lit!(100)), lit!("large"))
+///     .else_(lit!("small"));
+///
+/// let sql = expr.to_string();
+/// assert_eq!(sql, "CASE WHEN total > 100 THEN 'large' ELSE 'small' END");
+/// ```
+pub fn case() -> CaseFragment {
+    CaseFragment
+}
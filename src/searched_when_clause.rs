@@ -0,0 +1,48 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::SearchedWhenClause;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, SearchedWhenClause)]
+/// WHEN <search condition> THEN <result>
+pub struct SearchedWhen<Cond, Result>
+where
+    Cond: G::SearchCondition,
+    Result: G::ValueExpression,
+{
+    condition: Cond,
+    result: Result,
+}
+
+impl<Cond, Result> SearchedWhen<Cond, Result>
+where
+    Cond: G::SearchCondition,
+    Result: G::ValueExpression,
+{
+    pub fn new(condition: Cond, result: Result) -> Self {
+        Self { condition, result }
+    }
+}
+
+impl<Cond, Result> ::std::fmt::Display for SearchedWhen<Cond, Result>
+where
+    Cond: G::SearchCondition + std::fmt::Display,
+    Result: G::ValueExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "WHEN {} THEN {}", self.condition, self.result)
+    }
+}
+
+impl<'q, DB, Cond, Result> ToQuery<'q, DB> for SearchedWhen<Cond, Result>
+where
+    DB: Database,
+    Cond: G::SearchCondition + ToQuery<'q, DB>,
+    Result: G::ValueExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "WHEN ")?;
+        self.condition.write(ctx)?;
+        write!(ctx, " THEN ")?;
+        self.result.write(ctx)
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::SearchedWhenClauseList;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, SearchedWhenClauseList)]
+pub struct SearchedWhenLink<Head, Tail>
+where
+    Head: G::SearchedWhenClauseList,
+    Tail: G::SearchedWhenClause,
+{
+    head: Head,
+    tail: Tail,
+}
+
+impl<Head, Tail> SearchedWhenLink<Head, Tail>
+where
+    Head: G::SearchedWhenClauseList,
+    Tail: G::SearchedWhenClause,
+{
+    pub fn new(head: Head, tail: Tail) -> Self {
+        Self { head, tail }
+    }
+}
+
+impl<Head, Tail> std::fmt::Display for SearchedWhenLink<Head, Tail>
+where
+    Head: G::SearchedWhenClauseList + std::fmt::Display,
+    Tail: G::SearchedWhenClause + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.head, self.tail)
+    }
+}
+
+impl<'q, DB, Head, Tail> ToQuery<'q, DB> for SearchedWhenLink<Head, Tail>
+where
+    DB: Database,
+    Head: G::SearchedWhenClauseList + ToQuery<'q, DB>,
+    Tail: G::SearchedWhenClause + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> ::std::fmt::Result {
+        self.head.write(ctx)?;
+        write!(ctx, " ")?;
+        self.tail.write(ctx)
+    }
+}
@@ -1,4 +1,6 @@
+use crate::blank::Blank;
 use crate::either::Either;
+use crate::query_expression::QueryExpr;
 use crate::{from_clause::From, ToQuery};
 use sql_builder_macros::QuerySpecification;
 use std::fmt::Write;
@@ -19,7 +21,7 @@ impl AsRef<str> for SetQuantifier {
     fn as_ref(&self) -> &str {
         match self {
             SetQuantifier::All => "ALL",
-            SetQuantifier::Distinct => "DISTINC",
+            SetQuantifier::Distinct => "DISTINCT",
         }
     }
 }
@@ -34,8 +36,8 @@ impl std::fmt::Display for SetQuantifier {
 /// Creates a select statement
 ///
 /// # Example
-/// ```ignore
-/// use sql_builder::{select, id};
+/// ```
+/// use sql_builder::{select, id, prelude::*};
 ///
 /// let selected_columns = id!(col1)
 /// .add_selection(id!(col2).alias_column(id!(aliased_column)))
@@ -44,7 +46,7 @@ impl std::fmt::Display for SetQuantifier {
 ///
 /// let stmt = select(selected_columns).from(table);
 ///
-/// let sql = sel.to_raw_query().unwrap();
+/// let sql = stmt.to_string();
 /// assert_eq!(sql, "SELECT col1, col2 AS aliased_column, col3 FROM my_table");
 /// ```
 pub fn select<Selection: G::SelectList>(select_list: Selection) -> BeginSelect<Selection> {
@@ -106,6 +108,47 @@ where
     }
 }
 
+impl<Selection, Table> H::QueryExpression for Select<Selection, Table>
+where
+    Selection: G::SelectList,
+    Table: G::TableExpression,
+{
+    type WithClause = Blank;
+    type Body = Self;
+    type OrderByClause = Blank;
+    type ResultOffsetClause = Blank;
+    type FetchFirstClause = Blank;
+    type IntoQueryExpressionBody = Self;
+    type IntoQueryTerm = Self;
+    type IntoQueryPrimary = Self;
+
+    #[inline]
+    fn unwrap(self) -> QueryExpr<Blank, Self, Blank, Blank, Blank> {
+        QueryExpr {
+            with_clause: Blank,
+            body: self,
+            order_by: Blank,
+            offset: Blank,
+            fetch: Blank,
+        }
+    }
+
+    #[inline]
+    fn into_query_expression_body(self) -> Self {
+        self
+    }
+
+    #[inline]
+    fn into_query_term(self) -> Self {
+        self
+    }
+
+    #[inline]
+    fn into_query_primary(self) -> Self {
+        self
+    }
+}
+
 impl<Selection, Table> std::fmt::Display for Select<Selection, Table>
 where
     Selection: G::SelectList + std::fmt::Display,
@@ -114,10 +157,9 @@ where
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "SELECT ")?;
 
-        match &self.quantifier {
-            Some(q) => write!(f, "{} ", q)?,
-            None => {}
-        };
+        if let Some(q) = &self.quantifier {
+            write!(f, "{} ", q)?;
+        }
 
         write!(f, "{} {}", self.select_list, self.table_expression)
     }
@@ -131,10 +173,9 @@ where
     fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
         write!(ctx, "SELECT ")?;
 
-        match &self.quantifier {
-            Some(q) => write!(ctx, "{}", q)?,
-            None => {}
-        };
+        if let Some(q) = &self.quantifier {
+            write!(ctx, "{} ", q)?;
+        }
 
         self.select_list.write(ctx)?;
         write!(ctx, " ")?;
@@ -157,7 +198,7 @@ where
                     quantifier,
                     select_list,
                     table_expression,
-                } = lhs.unwrap();
+                } = H::QuerySpecification::unwrap(lhs);
                 Select {
                     quantifier,
                     select_list: Either::Left(select_list),
@@ -169,7 +210,7 @@ where
                     quantifier,
                     select_list,
                     table_expression,
-                } = rhs.unwrap();
+                } = H::QuerySpecification::unwrap(rhs);
 
                 Select {
                     quantifier,
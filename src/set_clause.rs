@@ -0,0 +1,47 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::SetClause;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, SetClause)]
+/// <update target> = <update source>
+pub struct SetClause<Target, Source>
+where
+    Target: G::ColumnName,
+    Source: G::UpdateSource,
+{
+    target: Target,
+    source: Source,
+}
+
+impl<Target, Source> SetClause<Target, Source>
+where
+    Target: G::ColumnName,
+    Source: G::UpdateSource,
+{
+    pub fn new(target: Target, source: Source) -> Self {
+        Self { target, source }
+    }
+}
+
+impl<Target, Source> ::std::fmt::Display for SetClause<Target, Source>
+where
+    Target: G::ColumnName + std::fmt::Display,
+    Source: G::UpdateSource + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} = {}", self.target, self.source)
+    }
+}
+
+impl<'q, DB, Target, Source> ToQuery<'q, DB> for SetClause<Target, Source>
+where
+    DB: Database,
+    Target: G::ColumnName + ToQuery<'q, DB>,
+    Source: G::UpdateSource + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        self.target.write(ctx)?;
+        write!(ctx, " = ")?;
+        self.source.write(ctx)
+    }
+}
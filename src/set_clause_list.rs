@@ -0,0 +1,47 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::SetClauseList;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, SetClauseList)]
+/// A linked-list of set clauses.
+pub struct SetClauseLink<Head, Tail>
+where
+    Head: G::SetClauseList,
+    Tail: G::SetClause,
+{
+    head: Head,
+    tail: Tail,
+}
+
+impl<Head, Tail> SetClauseLink<Head, Tail>
+where
+    Head: G::SetClauseList,
+    Tail: G::SetClause,
+{
+    pub fn new(head: Head, tail: Tail) -> Self {
+        Self { head, tail }
+    }
+}
+
+impl<Head, Tail> ::std::fmt::Display for SetClauseLink<Head, Tail>
+where
+    Head: G::SetClauseList + std::fmt::Display,
+    Tail: G::SetClause + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}", self.head, self.tail)
+    }
+}
+
+impl<'q, DB, Head, Tail> ToQuery<'q, DB> for SetClauseLink<Head, Tail>
+where
+    DB: Database,
+    Head: G::SetClauseList + ToQuery<'q, DB>,
+    Tail: G::SetClause + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> ::std::fmt::Result {
+        self.head.write(ctx)?;
+        write!(ctx, ", ")?;
+        self.tail.write(ctx)
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{blank::Blank, grammar as G, Database, ToQuery};
+use sql_builder_macros::SimilarPredicate;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, SimilarPredicate)]
+/// <row value predicand> [ NOT ] SIMILAR TO <similar pattern> [ ESCAPE <escape character> ]
+///
+/// See [self::similar_to] and [self::not_similar_to]
+pub struct SimilarTo<Value, Pattern, Escape>
+where
+    Value: G::RowValuePredicand,
+    Pattern: G::CharacterValueExpression,
+    Escape: G::EscapeCharacter,
+{
+    value: Value,
+    pattern: Pattern,
+    escape: Escape,
+    negated: bool,
+}
+
+impl<Value, Pattern, Escape> SimilarTo<Value, Pattern, Escape>
+where
+    Value: G::RowValuePredicand,
+    Pattern: G::CharacterValueExpression,
+    Escape: G::EscapeCharacter,
+{
+    /// Set the character used to escape the special characters of the pattern.
+    pub fn escape<NewEscape>(self, escape: NewEscape) -> SimilarTo<Value, Pattern, NewEscape>
+    where
+        NewEscape: G::EscapeCharacter,
+    {
+        SimilarTo {
+            value: self.value,
+            pattern: self.pattern,
+            escape,
+            negated: self.negated,
+        }
+    }
+}
+
+impl<Value, Pattern, Escape> ::std::fmt::Display for SimilarTo<Value, Pattern, Escape>
+where
+    Value: G::RowValuePredicand + std::fmt::Display,
+    Pattern: G::CharacterValueExpression + std::fmt::Display,
+    Escape: G::EscapeCharacter + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ", self.value)?;
+
+        if self.negated {
+            write!(f, "NOT ")?;
+        }
+
+        write!(f, "SIMILAR TO {}", self.pattern)?;
+
+        if Escape::IS_IMPL {
+            write!(f, " ESCAPE {}", self.escape)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'q, DB, Value, Pattern, Escape> ToQuery<'q, DB> for SimilarTo<Value, Pattern, Escape>
+where
+    DB: Database,
+    Value: G::RowValuePredicand + ToQuery<'q, DB>,
+    Pattern: G::CharacterValueExpression + ToQuery<'q, DB>,
+    Escape: G::EscapeCharacter + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        self.value.write(ctx)?;
+        write!(ctx, " ")?;
+
+        if self.negated {
+            write!(ctx, "NOT ")?;
+        }
+
+        write!(ctx, "SIMILAR TO ")?;
+        self.pattern.write(ctx)?;
+
+        if Escape::IS_IMPL {
+            write!(ctx, " ESCAPE ")?;
+            self.escape.write(ctx)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[inline]
+/// Checks if the value matches the regular expression
+///
+/// # SQL
+/// ```sql
+/// <value> SIMILAR TO <pattern>
+/// ```
+pub fn similar_to<Value, Pattern>(
+    value: Value,
+    pattern: Pattern,
+) -> SimilarTo<Value, Pattern, Blank>
+where
+    Value: G::RowValuePredicand,
+    Pattern: G::CharacterValueExpression,
+{
+    SimilarTo {
+        value,
+        pattern,
+        escape: Blank,
+        negated: false,
+    }
+}
+
+#[inline]
+/// Checks if the value does not match the regular expression
+///
+/// # SQL
+/// ```sql
+/// <value> NOT SIMILAR TO <pattern>
+/// ```
+pub fn not_similar_to<Value, Pattern>(
+    value: Value,
+    pattern: Pattern,
+) -> SimilarTo<Value, Pattern, Blank>
+where
+    Value: G::RowValuePredicand,
+    Pattern: G::CharacterValueExpression,
+{
+    SimilarTo {
+        value,
+        pattern,
+        escape: Blank,
+        negated: true,
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{
+    blank::Blank, else_clause::Else, grammar as G, simple_when_clause::SimpleWhen,
+    simple_when_clause_list::SimpleWhenLink, Database, ToQuery,
+};
+use sql_builder_macros::SimpleCase;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, SimpleCase)]
+/// CASE <case operand> <simple when clause>... [ <else clause> ] END
+///
+/// See [self::simple_case]
+pub struct SimpleCase<Operand, Whens, ElseClause>
+where
+    Operand: G::RowValuePredicand,
+    Whens: G::SimpleWhenClauseList,
+    ElseClause: G::ElseClause,
+{
+    operand: Operand,
+    whens: Whens,
+    else_clause: ElseClause,
+}
+
+impl<Operand, Whens> SimpleCase<Operand, Whens, Blank>
+where
+    Operand: G::RowValuePredicand,
+    Whens: G::SimpleWhenClauseList,
+{
+    /// Add a value to compare the operand with, and its result.
+    pub fn when<Value, Result>(
+        self,
+        value: Value,
+        result: Result,
+    ) -> SimpleCase<Operand, SimpleWhenLink<Whens, SimpleWhen<Value, Result>>, Blank>
+    where
+        Value: G::RowValuePredicand,
+        Result: G::ValueExpression,
+    {
+        SimpleCase {
+            operand: self.operand,
+            whens: SimpleWhenLink::new(self.whens, SimpleWhen::new(value, result)),
+            else_clause: Blank,
+        }
+    }
+
+    /// Set the result if the operand matches none of the values.
+    pub fn else_<Result>(self, result: Result) -> SimpleCase<Operand, Whens, Else<Result>>
+    where
+        Result: G::ValueExpression,
+    {
+        SimpleCase {
+            operand: self.operand,
+            whens: self.whens,
+            else_clause: Else::new(result),
+        }
+    }
+}
+
+impl<Operand, Whens, ElseClause> ::std::fmt::Display for SimpleCase<Operand, Whens, ElseClause>
+where
+    Operand: G::RowValuePredicand + std::fmt::Display,
+    Whens: G::SimpleWhenClauseList + std::fmt::Display,
+    ElseClause: G::ElseClause + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CASE {} {}", self.operand, self.whens)?;
+
+        if ElseClause::IS_IMPL {
+            write!(f, " {}", self.else_clause)?;
+        }
+
+        write!(f, " END")
+    }
+}
+
+impl<'q, DB, Operand, Whens, ElseClause> ToQuery<'q, DB> for SimpleCase<Operand, Whens, ElseClause>
+where
+    DB: Database,
+    Operand: G::RowValuePredicand + ToQuery<'q, DB>,
+    Whens: G::SimpleWhenClauseList + ToQuery<'q, DB>,
+    ElseClause: G::ElseClause + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "CASE ")?;
+        self.operand.write(ctx)?;
+        write!(ctx, " ")?;
+        self.whens.write(ctx)?;
+
+        if ElseClause::IS_IMPL {
+            write!(ctx, " ")?;
+            self.else_clause.write(ctx)?;
+        }
+
+        write!(ctx, " END")
+    }
+}
+
+#[derive(Clone, Copy)]
+/// A partial fragment to build a simple case, waiting for its first value.
+pub struct SimpleCaseFragment<Operand>(Operand)
+where
+    Operand: G::RowValuePredicand;
+
+impl<Operand> SimpleCaseFragment<Operand>
+where
+    Operand: G::RowValuePredicand,
+{
+    /// Add the first value to compare the operand with, and its result.
+    pub fn when<Value, Result>(
+        self,
+        value: Value,
+        result: Result,
+    ) -> SimpleCase<Operand, SimpleWhen<Value, Result>, Blank>
+    where
+        Value: G::RowValuePredicand,
+        Result: G::ValueExpression,
+    {
+        SimpleCase {
+            operand: self.0,
+            whens: SimpleWhen::new(value, result),
+            else_clause: Blank,
+        }
+    }
+}
+
+#[inline]
+/// Creates a simple case expression, comparing the operand with each value.
+///
+/// # Example
+/// ```
+/// use sql_builder::{simple_case, id, lit, prelude::*};
+///
+/// let expr = simple_case(id!(status))
+///     .when(lit!(1), lit!("active"))
+///     .when(lit!(2), lit!("banned"))
+///     .else_(lit!("unknown"));
+///
+/// let sql = expr.to_string();
+/// assert_eq!(sql, "CASE status WHEN 1 THEN 'active' WHEN 2 THEN 'banned' ELSE 'unknown' END");
+/// ```
+pub fn simple_case<Operand>(operand: Operand) -> SimpleCaseFragment<Operand>
+where
+    Operand: G::RowValuePredicand,
+{
+    SimpleCaseFragment(operand)
+}
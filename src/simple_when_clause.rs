@@ -0,0 +1,48 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::SimpleWhenClause;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, SimpleWhenClause)]
+/// WHEN <when operand> THEN <result>
+pub struct SimpleWhen<Operand, Result>
+where
+    Operand: G::RowValuePredicand,
+    Result: G::ValueExpression,
+{
+    operand: Operand,
+    result: Result,
+}
+
+impl<Operand, Result> SimpleWhen<Operand, Result>
+where
+    Operand: G::RowValuePredicand,
+    Result: G::ValueExpression,
+{
+    pub fn new(operand: Operand, result: Result) -> Self {
+        Self { operand, result }
+    }
+}
+
+impl<Operand, Result> ::std::fmt::Display for SimpleWhen<Operand, Result>
+where
+    Operand: G::RowValuePredicand + std::fmt::Display,
+    Result: G::ValueExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "WHEN {} THEN {}", self.operand, self.result)
+    }
+}
+
+impl<'q, DB, Operand, Result> ToQuery<'q, DB> for SimpleWhen<Operand, Result>
+where
+    DB: Database,
+    Operand: G::RowValuePredicand + ToQuery<'q, DB>,
+    Result: G::ValueExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "WHEN ")?;
+        self.operand.write(ctx)?;
+        write!(ctx, " THEN ")?;
+        self.result.write(ctx)
+    }
+}
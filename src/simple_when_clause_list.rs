@@ -0,0 +1,46 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::SimpleWhenClauseList;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, SimpleWhenClauseList)]
+pub struct SimpleWhenLink<Head, Tail>
+where
+    Head: G::SimpleWhenClauseList,
+    Tail: G::SimpleWhenClause,
+{
+    head: Head,
+    tail: Tail,
+}
+
+impl<Head, Tail> SimpleWhenLink<Head, Tail>
+where
+    Head: G::SimpleWhenClauseList,
+    Tail: G::SimpleWhenClause,
+{
+    pub fn new(head: Head, tail: Tail) -> Self {
+        Self { head, tail }
+    }
+}
+
+impl<Head, Tail> std::fmt::Display for SimpleWhenLink<Head, Tail>
+where
+    Head: G::SimpleWhenClauseList + std::fmt::Display,
+    Tail: G::SimpleWhenClause + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.head, self.tail)
+    }
+}
+
+impl<'q, DB, Head, Tail> ToQuery<'q, DB> for SimpleWhenLink<Head, Tail>
+where
+    DB: Database,
+    Head: G::SimpleWhenClauseList + ToQuery<'q, DB>,
+    Tail: G::SimpleWhenClause + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> ::std::fmt::Result {
+        self.head.write(ctx)?;
+        write!(ctx, " ")?;
+        self.tail.write(ctx)
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{
+    blank::Blank,
+    collate_clause::Collate,
+    grammar as G,
+    null_ordering::{NullsFirst, NullsLast},
+    ordering_specification::{Asc, Desc},
+    Database, ToQuery,
+};
+use sql_builder_macros::SortSpecification;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, SortSpecification)]
+/// <sort key> [ <collate clause> ] [ <ordering specification> ] [ <null ordering> ]
+///
+/// See [crate::helpers::ValueExpression::asc], [crate::helpers::ValueExpression::desc]
+pub struct SortSpec<Key, Collation, Ordering, Nulls>
+where
+    Key: G::SortKey,
+    Collation: G::CollateClause,
+    Ordering: G::OrderingSpecification,
+    Nulls: G::NullOrdering,
+{
+    key: Key,
+    collation: Collation,
+    ordering: Ordering,
+    nulls: Nulls,
+}
+
+impl<Key> SortSpec<Key, Blank, Blank, Blank>
+where
+    Key: G::SortKey,
+{
+    pub fn new(key: Key) -> Self {
+        Self {
+            key,
+            collation: Blank,
+            ordering: Blank,
+            nulls: Blank,
+        }
+    }
+}
+
+impl<Key, Collation, Ordering, Nulls> SortSpec<Key, Collation, Ordering, Nulls>
+where
+    Key: G::SortKey,
+    Collation: G::CollateClause,
+    Ordering: G::OrderingSpecification,
+    Nulls: G::NullOrdering,
+{
+    /// Sort in ascending order.
+    pub fn asc(self) -> SortSpec<Key, Collation, Asc, Nulls> {
+        self.ordering(Asc)
+    }
+
+    /// Sort in descending order.
+    pub fn desc(self) -> SortSpec<Key, Collation, Desc, Nulls> {
+        self.ordering(Desc)
+    }
+
+    /// Put the null values before the non-null ones.
+    pub fn nulls_first(self) -> SortSpec<Key, Collation, Ordering, NullsFirst> {
+        self.null_ordering(NullsFirst)
+    }
+
+    /// Put the null values after the non-null ones.
+    pub fn nulls_last(self) -> SortSpec<Key, Collation, Ordering, NullsLast> {
+        self.null_ordering(NullsLast)
+    }
+
+    /// Compare the sort keys with the collation.
+    pub fn collate<Name>(self, name: Name) -> SortSpec<Key, Collate<Name>, Ordering, Nulls>
+    where
+        Name: G::CollationName,
+    {
+        SortSpec {
+            key: self.key,
+            collation: Collate::new(name),
+            ordering: self.ordering,
+            nulls: self.nulls,
+        }
+    }
+
+    /// Replace the ordering specification.
+    pub fn ordering<NewOrdering>(
+        self,
+        ordering: NewOrdering,
+    ) -> SortSpec<Key, Collation, NewOrdering, Nulls>
+    where
+        NewOrdering: G::OrderingSpecification,
+    {
+        SortSpec {
+            key: self.key,
+            collation: self.collation,
+            ordering,
+            nulls: self.nulls,
+        }
+    }
+
+    /// Replace the null ordering.
+    pub fn null_ordering<NewNulls>(
+        self,
+        nulls: NewNulls,
+    ) -> SortSpec<Key, Collation, Ordering, NewNulls>
+    where
+        NewNulls: G::NullOrdering,
+    {
+        SortSpec {
+            key: self.key,
+            collation: self.collation,
+            ordering: self.ordering,
+            nulls,
+        }
+    }
+}
+
+impl<Key, Collation, Ordering, Nulls> ::std::fmt::Display
+    for SortSpec<Key, Collation, Ordering, Nulls>
+where
+    Key: G::SortKey + std::fmt::Display,
+    Collation: G::CollateClause + std::fmt::Display,
+    Ordering: G::OrderingSpecification + std::fmt::Display,
+    Nulls: G::NullOrdering + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.key)?;
+
+        if Collation::IS_IMPL {
+            write!(f, " {}", self.collation)?;
+        }
+
+        if Ordering::IS_IMPL {
+            write!(f, " {}", self.ordering)?;
+        }
+
+        if Nulls::IS_IMPL {
+            write!(f, " {}", self.nulls)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'q, DB, Key, Collation, Ordering, Nulls> ToQuery<'q, DB>
+    for SortSpec<Key, Collation, Ordering, Nulls>
+where
+    DB: Database,
+    Key: G::SortKey + ToQuery<'q, DB>,
+    Collation: G::CollateClause + ToQuery<'q, DB>,
+    Ordering: G::OrderingSpecification + ToQuery<'q, DB>,
+    Nulls: G::NullOrdering + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        self.key.write(ctx)?;
+
+        if Collation::IS_IMPL {
+            write!(ctx, " ")?;
+            self.collation.write(ctx)?;
+        }
+
+        if Ordering::IS_IMPL {
+            write!(ctx, " ")?;
+            self.ordering.write(ctx)?;
+        }
+
+        if Nulls::IS_IMPL {
+            write!(ctx, " ")?;
+            self.nulls.write(ctx)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::SortSpecificationList;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, SortSpecificationList)]
+/// A linked-list of sort specifications.
+pub struct SortSpecificationLink<Head, Tail>
+where
+    Head: G::SortSpecificationList,
+    Tail: G::SortSpecification,
+{
+    head: Head,
+    tail: Tail,
+}
+
+impl<Head, Tail> SortSpecificationLink<Head, Tail>
+where
+    Head: G::SortSpecificationList,
+    Tail: G::SortSpecification,
+{
+    pub fn new(head: Head, tail: Tail) -> Self {
+        Self { head, tail }
+    }
+}
+
+impl<Head, Tail> ::std::fmt::Display for SortSpecificationLink<Head, Tail>
+where
+    Head: G::SortSpecificationList + std::fmt::Display,
+    Tail: G::SortSpecification + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}", self.head, self.tail)
+    }
+}
+
+impl<'q, DB, Head, Tail> ToQuery<'q, DB> for SortSpecificationLink<Head, Tail>
+where
+    DB: Database,
+    Head: G::SortSpecificationList + ToQuery<'q, DB>,
+    Tail: G::SortSpecification + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> ::std::fmt::Result {
+        self.head.write(ctx)?;
+        write!(ctx, ", ")?;
+        self.tail.write(ctx)
+    }
+}
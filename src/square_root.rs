@@ -0,0 +1,44 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::SquareRoot;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, SquareRoot)]
+/// SQRT(<numeric value expression>)
+///
+/// See [self::sqrt]
+pub struct Sqrt<Value>
+where
+    Value: G::NumericValueExpression,
+{
+    value: Value,
+}
+
+impl<Value> ::std::fmt::Display for Sqrt<Value>
+where
+    Value: G::NumericValueExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SQRT({})", self.value)
+    }
+}
+
+impl<'q, DB, Value> ToQuery<'q, DB> for Sqrt<Value>
+where
+    DB: Database,
+    Value: G::NumericValueExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "SQRT(")?;
+        self.value.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Returns the square root of a number.
+pub fn sqrt<Value>(value: Value) -> Sqrt<Value>
+where
+    Value: G::NumericValueExpression,
+{
+    Sqrt { value }
+}
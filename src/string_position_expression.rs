@@ -0,0 +1,60 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::StringPositionExpression;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, StringPositionExpression)]
+/// POSITION(<string value expression> IN <string value expression>)
+///
+/// See [self::position]
+pub struct Position<Needle, Haystack>
+where
+    Needle: G::StringValueExpression,
+    Haystack: G::StringValueExpression,
+{
+    needle: Needle,
+    haystack: Haystack,
+}
+
+impl<Needle, Haystack> ::std::fmt::Display for Position<Needle, Haystack>
+where
+    Needle: G::StringValueExpression + std::fmt::Display,
+    Haystack: G::StringValueExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "POSITION({} IN {})", self.needle, self.haystack)
+    }
+}
+
+impl<'q, DB, Needle, Haystack> ToQuery<'q, DB> for Position<Needle, Haystack>
+where
+    DB: Database,
+    Needle: G::StringValueExpression + ToQuery<'q, DB>,
+    Haystack: G::StringValueExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "POSITION(")?;
+        self.needle.write(ctx)?;
+        write!(ctx, " IN ")?;
+        self.haystack.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Returns the position of the first occurrence of the needle in the
+/// haystack, starting from 1, or 0 if it is not found.
+///
+/// # Example
+/// ```
+/// use sql_builder::{id, lit, position, prelude::*};
+///
+/// let expr = position(lit!("@"), id!(email));
+/// assert_eq!(expr.to_string(), "POSITION('@' IN email)");
+/// ```
+pub fn position<Needle, Haystack>(needle: Needle, haystack: Haystack) -> Position<Needle, Haystack>
+where
+    Needle: G::StringValueExpression,
+    Haystack: G::StringValueExpression,
+{
+    Position { needle, haystack }
+}
@@ -0,0 +1,42 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::Subquery;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, Subquery)]
+/// (<query expression>)
+///
+/// Can be used as a scalar value, a row value or as the operand of a predicate.
+/// See [crate::helpers::QueryExpression::subquery]
+pub struct Subquery<Query>(Query)
+where
+    Query: G::QueryExpression;
+
+impl<Query> Subquery<Query>
+where
+    Query: G::QueryExpression,
+{
+    pub fn new(query: Query) -> Self {
+        Self(query)
+    }
+}
+
+impl<Query> ::std::fmt::Display for Subquery<Query>
+where
+    Query: G::QueryExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({})", self.0)
+    }
+}
+
+impl<'q, DB, Query> ToQuery<'q, DB> for Subquery<Query>
+where
+    DB: Database,
+    Query: G::QueryExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "(")?;
+        self.0.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
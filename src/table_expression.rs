@@ -12,39 +12,51 @@ pub struct TableExpr<
     Where: G::WhereClause,
     GroupBy: G::GroupByClause,
     Having: G::HavingClause,
+    Window: G::WindowClause,
 > {
     pub from_clause: From,
     pub where_clause: Where,
     pub group_by: GroupBy,
     pub having: Having,
+    pub window: Window,
 }
 
-impl<From, Where, GroupBy, Having> H::TableExpression for TableExpr<From, Where, GroupBy, Having>
+impl<From, Where, GroupBy, Having, Window> H::TableExpression
+    for TableExpr<From, Where, GroupBy, Having, Window>
 where
     From: G::FromClause,
     Where: G::WhereClause,
     GroupBy: G::GroupByClause,
     Having: G::HavingClause,
+    Window: G::WindowClause,
 {
     type FromClause = From;
     type WhereClause = Where;
     type GroupByClause = GroupBy;
     type HavingClause = Having;
+    type WindowClause = Window;
 
     fn unwrap(
         self,
-    ) -> TableExpr<Self::FromClause, Self::WhereClause, Self::GroupByClause, Self::HavingClause>
-    {
+    ) -> TableExpr<
+        Self::FromClause,
+        Self::WhereClause,
+        Self::GroupByClause,
+        Self::HavingClause,
+        Self::WindowClause,
+    > {
         self
     }
 }
 
-impl<From, Where, GroupBy, Having> std::fmt::Display for TableExpr<From, Where, GroupBy, Having>
+impl<From, Where, GroupBy, Having, Window> std::fmt::Display
+    for TableExpr<From, Where, GroupBy, Having, Window>
 where
     From: G::FromClause + std::fmt::Display,
     Where: G::WhereClause + std::fmt::Display,
     GroupBy: G::GroupByClause + std::fmt::Display,
     Having: G::HavingClause + std::fmt::Display,
+    Window: G::WindowClause + std::fmt::Display,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.from_clause)?;
@@ -61,18 +73,23 @@ where
             write!(f, " {}", self.having)?;
         }
 
+        if Window::IS_IMPL {
+            write!(f, " {}", self.window)?;
+        }
+
         Ok(())
     }
 }
 
-impl<'q, DB, From, Where, GroupBy, Having> ToQuery<'q, DB>
-    for TableExpr<From, Where, GroupBy, Having>
+impl<'q, DB, From, Where, GroupBy, Having, Window> ToQuery<'q, DB>
+    for TableExpr<From, Where, GroupBy, Having, Window>
 where
     DB: Database,
     From: G::FromClause + ToQuery<'q, DB>,
     Where: G::WhereClause + ToQuery<'q, DB>,
     GroupBy: G::GroupByClause + ToQuery<'q, DB>,
     Having: G::HavingClause + ToQuery<'q, DB>,
+    Window: G::WindowClause + ToQuery<'q, DB>,
 {
     fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
         self.from_clause.write(ctx)?;
@@ -92,6 +109,11 @@ where
             self.having.write(ctx)?;
         }
 
+        if Window::IS_IMPL {
+            write!(ctx, " ")?;
+            self.window.write(ctx)?;
+        }
+
         Ok(())
     }
 }
@@ -101,16 +123,23 @@ impl H::TableExpression for Blank {
     type WhereClause = Blank;
     type HavingClause = Blank;
     type GroupByClause = Blank;
+    type WindowClause = Blank;
 
     fn unwrap(
         self,
-    ) -> TableExpr<Self::FromClause, Self::WhereClause, Self::GroupByClause, Self::HavingClause>
-    {
+    ) -> TableExpr<
+        Self::FromClause,
+        Self::WhereClause,
+        Self::GroupByClause,
+        Self::HavingClause,
+        Self::WindowClause,
+    > {
         TableExpr {
             from_clause: Blank,
             where_clause: Blank,
             group_by: Blank,
             having: Blank,
+            window: Blank,
         }
     }
 }
@@ -124,11 +153,17 @@ where
     type WhereClause = Either<Lhs::WhereClause, Rhs::WhereClause>;
     type GroupByClause = Either<Lhs::GroupByClause, Rhs::GroupByClause>;
     type HavingClause = Either<Lhs::HavingClause, Rhs::HavingClause>;
+    type WindowClause = Either<Lhs::WindowClause, Rhs::WindowClause>;
 
     fn unwrap(
         self,
-    ) -> TableExpr<Self::FromClause, Self::WhereClause, Self::GroupByClause, Self::HavingClause>
-    {
+    ) -> TableExpr<
+        Self::FromClause,
+        Self::WhereClause,
+        Self::GroupByClause,
+        Self::HavingClause,
+        Self::WindowClause,
+    > {
         match self {
             Either::Left(left) => {
                 let TableExpr {
@@ -136,12 +171,14 @@ where
                     where_clause,
                     group_by,
                     having,
+                    window,
                 } = left.unwrap();
                 TableExpr {
                     from_clause: Either::Left(from_clause),
                     where_clause: Either::Left(where_clause),
                     group_by: Either::Left(group_by),
                     having: Either::Left(having),
+                    window: Either::Left(window),
                 }
             }
             Either::Right(right) => {
@@ -150,12 +187,14 @@ where
                     where_clause,
                     group_by,
                     having,
+                    window,
                 } = right.unwrap();
                 TableExpr {
                     from_clause: Either::Right(from_clause),
                     where_clause: Either::Right(where_clause),
                     group_by: Either::Right(group_by),
                     having: Either::Right(having),
+                    window: Either::Right(window),
                 }
             }
         }
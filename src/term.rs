@@ -42,7 +42,7 @@ where
     Rhs: G::Factor + std::fmt::Display,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} {} {}", self.lhs, self.kind, self.rhs)
+        write!(f, "{} {} {}", self.lhs, self.kind, self.rhs)
     }
 }
 impl<'q, DB, Lhs, Rhs> ToQuery<'q, DB> for TermOperand<Lhs, Rhs>
@@ -59,7 +59,11 @@ where
 }
 
 #[inline]
-pub fn mult(lhs: impl G::Term, rhs: impl G::Factor) -> impl G::Term {
+pub fn mult<Lhs, Rhs>(lhs: Lhs, rhs: Rhs) -> TermOperand<Lhs, Rhs>
+where
+    Lhs: G::Term,
+    Rhs: G::Factor,
+{
     TermOperand {
         lhs,
         rhs,
@@ -68,7 +72,11 @@ pub fn mult(lhs: impl G::Term, rhs: impl G::Factor) -> impl G::Term {
 }
 
 #[inline]
-pub fn div(lhs: impl G::Term, rhs: impl G::Factor) -> impl G::Term {
+pub fn div<Lhs, Rhs>(lhs: Lhs, rhs: Rhs) -> TermOperand<Lhs, Rhs>
+where
+    Lhs: G::Term,
+    Rhs: G::Factor,
+{
     TermOperand {
         lhs,
         rhs,
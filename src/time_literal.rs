@@ -0,0 +1,79 @@
+use crate::{Database, ToQuery};
+use sql_builder_macros::TimeLiteral;
+use std::fmt::Write;
+
+#[derive(Debug, Clone, PartialEq, Eq, TimeLiteral)]
+/// TIME '<hours value>:<minutes value>:<seconds value>[<time zone interval>]'
+///
+/// See [self::time_lit]
+pub struct TimeLiteral(pub(crate) String);
+
+impl ::std::fmt::Display for TimeLiteral {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TIME '{}'", self.0.replace('\'', "''"))
+    }
+}
+
+impl<'q, DB> ToQuery<'q, DB> for TimeLiteral
+where
+    DB: Database,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "{}", self)
+    }
+}
+
+impl From<&str> for TimeLiteral {
+    fn from(value: &str) -> Self {
+        Self(value.to_owned())
+    }
+}
+
+impl From<String> for TimeLiteral {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveTime> for TimeLiteral {
+    fn from(value: chrono::NaiveTime) -> Self {
+        Self(value.format("%H:%M:%S%.f").to_string())
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Time> for TimeLiteral {
+    fn from(value: time::Time) -> Self {
+        let mut repr = format!(
+            "{:02}:{:02}:{:02}",
+            value.hour(),
+            value.minute(),
+            value.second()
+        );
+
+        if value.nanosecond() != 0 {
+            let nanos = format!("{:09}", value.nanosecond());
+            repr.push('.');
+            repr.push_str(nanos.trim_end_matches('0'));
+        }
+
+        Self(repr)
+    }
+}
+
+#[inline]
+/// Creates a time literal.
+///
+/// # Example
+/// ```
+/// use sql_builder::time_lit;
+///
+/// assert_eq!(time_lit("12:30:00").to_string(), "TIME '12:30:00'");
+/// ```
+pub fn time_lit<V>(value: V) -> TimeLiteral
+where
+    TimeLiteral: From<V>,
+{
+    TimeLiteral::from(value)
+}
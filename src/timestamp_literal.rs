@@ -0,0 +1,103 @@
+use crate::{Database, ToQuery};
+use sql_builder_macros::TimestampLiteral;
+use std::fmt::Write;
+
+#[derive(Debug, Clone, PartialEq, Eq, TimestampLiteral)]
+/// TIMESTAMP '<date value> <time value>[<time zone interval>]'
+///
+/// See [self::timestamp_lit]
+pub struct TimestampLiteral(String);
+
+impl ::std::fmt::Display for TimestampLiteral {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TIMESTAMP '{}'", self.0.replace('\'', "''"))
+    }
+}
+
+impl<'q, DB> ToQuery<'q, DB> for TimestampLiteral
+where
+    DB: Database,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "{}", self)
+    }
+}
+
+impl From<&str> for TimestampLiteral {
+    fn from(value: &str) -> Self {
+        Self(value.to_owned())
+    }
+}
+
+impl From<String> for TimestampLiteral {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDateTime> for TimestampLiteral {
+    fn from(value: chrono::NaiveDateTime) -> Self {
+        Self(value.format("%Y-%m-%d %H:%M:%S%.f").to_string())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz> From<chrono::DateTime<Tz>> for TimestampLiteral
+where
+    Tz: chrono::TimeZone,
+    Tz::Offset: std::fmt::Display,
+{
+    fn from(value: chrono::DateTime<Tz>) -> Self {
+        Self(value.format("%Y-%m-%d %H:%M:%S%.f%:z").to_string())
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::PrimitiveDateTime> for TimestampLiteral {
+    fn from(value: time::PrimitiveDateTime) -> Self {
+        let date = crate::date_literal::DateLiteral::from(value.date());
+        let time = crate::time_literal::TimeLiteral::from(value.time());
+        Self(format!("{} {}", date.0, time.0))
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for TimestampLiteral {
+    fn from(value: time::OffsetDateTime) -> Self {
+        let Self(repr) = Self::from(time::PrimitiveDateTime::new(value.date(), value.time()));
+        let (hours, minutes, _) = value.offset().as_hms();
+        let sign = if value.offset().is_negative() {
+            '-'
+        } else {
+            '+'
+        };
+
+        Self(format!(
+            "{}{}{:02}:{:02}",
+            repr,
+            sign,
+            hours.unsigned_abs(),
+            minutes.unsigned_abs()
+        ))
+    }
+}
+
+#[inline]
+/// Creates a timestamp literal.
+///
+/// # Example
+/// ```
+/// use sql_builder::timestamp_lit;
+///
+/// assert_eq!(
+///     timestamp_lit("2024-02-29 12:30:00").to_string(),
+///     "TIMESTAMP '2024-02-29 12:30:00'"
+/// );
+/// ```
+pub fn timestamp_lit<V>(value: V) -> TimestampLiteral
+where
+    TimestampLiteral: From<V>,
+{
+    TimestampLiteral::from(value)
+}
@@ -0,0 +1,104 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::TrimFunction;
+use std::fmt::Write;
+
+#[derive(Clone, Copy)]
+/// <trim specification>
+pub enum TrimSpecification {
+    Leading,
+    Trailing,
+    Both,
+}
+
+impl AsRef<str> for TrimSpecification {
+    fn as_ref(&self) -> &str {
+        match self {
+            TrimSpecification::Leading => "LEADING",
+            TrimSpecification::Trailing => "TRAILING",
+            TrimSpecification::Both => "BOTH",
+        }
+    }
+}
+
+impl std::fmt::Display for TrimSpecification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Clone, Copy, TrimFunction)]
+/// TRIM(<trim specification> [ <trim character> ] FROM <trim source>)
+///
+/// See [self::trim]
+pub struct Trim<Character, Source>
+where
+    Character: G::TrimCharacter,
+    Source: G::CharacterValueExpression,
+{
+    specification: TrimSpecification,
+    character: Character,
+    source: Source,
+}
+
+impl<Character, Source> ::std::fmt::Display for Trim<Character, Source>
+where
+    Character: G::TrimCharacter + std::fmt::Display,
+    Source: G::CharacterValueExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TRIM({} ", self.specification)?;
+
+        if Character::IS_IMPL {
+            write!(f, "{} ", self.character)?;
+        }
+
+        write!(f, "FROM {})", self.source)
+    }
+}
+
+impl<'q, DB, Character, Source> ToQuery<'q, DB> for Trim<Character, Source>
+where
+    DB: Database,
+    Character: G::TrimCharacter + ToQuery<'q, DB>,
+    Source: G::CharacterValueExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "TRIM({} ", self.specification)?;
+
+        if Character::IS_IMPL {
+            self.character.write(ctx)?;
+            write!(ctx, " ")?;
+        }
+
+        write!(ctx, "FROM ")?;
+        self.source.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Removes the leading and/or trailing characters of the source, the spaces
+/// if the character is [crate::blank::Blank].
+///
+/// # Example
+/// ```
+/// use sql_builder::{blank::Blank, id, lit, trim, trim_function::TrimSpecification, prelude::*};
+///
+/// assert_eq!(trim(TrimSpecification::Leading, lit!("0"), id!(code)).to_string(), "TRIM(LEADING '0' FROM code)");
+/// assert_eq!(trim(TrimSpecification::Both, Blank, id!(name)).to_string(), "TRIM(BOTH FROM name)");
+/// ```
+pub fn trim<Character, Source>(
+    specification: TrimSpecification,
+    character: Character,
+    source: Source,
+) -> Trim<Character, Source>
+where
+    Character: G::TrimCharacter,
+    Source: G::CharacterValueExpression,
+{
+    Trim {
+        specification,
+        character,
+        source,
+    }
+}
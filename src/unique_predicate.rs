@@ -0,0 +1,45 @@
+use crate::{grammar as G, subquery::Subquery, Database, ToQuery};
+use sql_builder_macros::UniquePredicate;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, UniquePredicate)]
+/// UNIQUE <table subquery>
+///
+/// See [self::unique]
+pub struct Unique<Query>(Query)
+where
+    Query: G::TableSubquery;
+
+impl<Query> ::std::fmt::Display for Unique<Query>
+where
+    Query: G::TableSubquery + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "UNIQUE {}", self.0)
+    }
+}
+
+impl<'q, DB, Query> ToQuery<'q, DB> for Unique<Query>
+where
+    DB: Database,
+    Query: G::TableSubquery + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "UNIQUE ")?;
+        self.0.write(ctx)
+    }
+}
+
+#[inline]
+/// Checks if the query returns no duplicated rows
+///
+/// # SQL
+/// ```sql
+/// UNIQUE (<query>)
+/// ```
+pub fn unique<Query>(query: Query) -> Unique<Subquery<Query>>
+where
+    Query: G::QueryExpression,
+{
+    Unique(Subquery::new(query))
+}
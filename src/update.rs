@@ -0,0 +1,227 @@
+use crate::{blank::Blank, either::Either, set_clause::SetClause, ToQuery};
+use sql_builder_macros::UpdateStatement;
+use std::fmt::Write;
+
+use crate::grammar as G;
+use crate::helpers as H;
+use crate::Database;
+
+#[derive(Clone, Copy, UpdateStatement)]
+/// Represents a searched update statement.
+/// See [self::update]
+pub struct Update<With, Target, Sets, Where, Returning>
+where
+    With: G::WithClause,
+    Target: G::TableName,
+    Sets: G::SetClauseList,
+    Where: G::WhereClause,
+    Returning: G::ReturningClause,
+{
+    pub with_clause: With,
+    pub target: Target,
+    pub set_clause_list: Sets,
+    pub where_clause: Where,
+    pub returning: Returning,
+}
+
+impl<With, Target, Sets, Where, Returning> H::UpdateStatement
+    for Update<With, Target, Sets, Where, Returning>
+where
+    With: G::WithClause,
+    Target: G::TableName,
+    Sets: G::SetClauseList,
+    Where: G::WhereClause,
+    Returning: G::ReturningClause,
+{
+    type WithClause = With;
+    type Target = Target;
+    type SetClauseList = Sets;
+    type WhereClause = Where;
+    type ReturningClause = Returning;
+
+    #[inline]
+    fn unwrap(self) -> Self {
+        self
+    }
+}
+
+impl<With, Target, Sets, Where, Returning> std::fmt::Display
+    for Update<With, Target, Sets, Where, Returning>
+where
+    With: G::WithClause + std::fmt::Display,
+    Target: G::TableName + std::fmt::Display,
+    Sets: G::SetClauseList + std::fmt::Display,
+    Where: G::WhereClause + std::fmt::Display,
+    Returning: G::ReturningClause + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if With::IS_IMPL {
+            write!(f, "{} ", self.with_clause)?;
+        }
+
+        write!(f, "UPDATE {} SET {}", self.target, self.set_clause_list)?;
+
+        if Where::IS_IMPL {
+            write!(f, " {}", self.where_clause)?;
+        }
+
+        if Returning::IS_IMPL {
+            write!(f, " {}", self.returning)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'q, DB, With, Target, Sets, Where, Returning> ToQuery<'q, DB>
+    for Update<With, Target, Sets, Where, Returning>
+where
+    DB: Database,
+    With: G::WithClause + ToQuery<'q, DB>,
+    Target: G::TableName + ToQuery<'q, DB>,
+    Sets: G::SetClauseList + ToQuery<'q, DB>,
+    Where: G::WhereClause + ToQuery<'q, DB>,
+    Returning: G::ReturningClause + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        if With::IS_IMPL {
+            self.with_clause.write(ctx)?;
+            write!(ctx, " ")?;
+        }
+
+        write!(ctx, "UPDATE ")?;
+        self.target.write(ctx)?;
+        write!(ctx, " SET ")?;
+        self.set_clause_list.write(ctx)?;
+
+        if Where::IS_IMPL {
+            write!(ctx, " ")?;
+            self.where_clause.write(ctx)?;
+        }
+
+        if Returning::IS_IMPL {
+            write!(ctx, " ")?;
+            self.returning.write(ctx)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<Lhs, Rhs> H::UpdateStatement for Either<Lhs, Rhs>
+where
+    Lhs: G::UpdateStatement,
+    Rhs: G::UpdateStatement,
+{
+    type WithClause = Either<Lhs::WithClause, Rhs::WithClause>;
+    type Target = Either<Lhs::Target, Rhs::Target>;
+    type SetClauseList = Either<Lhs::SetClauseList, Rhs::SetClauseList>;
+    type WhereClause = Either<Lhs::WhereClause, Rhs::WhereClause>;
+    type ReturningClause = Either<Lhs::ReturningClause, Rhs::ReturningClause>;
+
+    fn unwrap(
+        self,
+    ) -> Update<
+        Self::WithClause,
+        Self::Target,
+        Self::SetClauseList,
+        Self::WhereClause,
+        Self::ReturningClause,
+    > {
+        match self {
+            Either::Left(lhs) => {
+                let Update {
+                    with_clause,
+                    target,
+                    set_clause_list,
+                    where_clause,
+                    returning,
+                } = lhs.unwrap();
+
+                Update {
+                    with_clause: Either::Left(with_clause),
+                    target: Either::Left(target),
+                    set_clause_list: Either::Left(set_clause_list),
+                    where_clause: Either::Left(where_clause),
+                    returning: Either::Left(returning),
+                }
+            }
+            Either::Right(rhs) => {
+                let Update {
+                    with_clause,
+                    target,
+                    set_clause_list,
+                    where_clause,
+                    returning,
+                } = rhs.unwrap();
+
+                Update {
+                    with_clause: Either::Right(with_clause),
+                    target: Either::Right(target),
+                    set_clause_list: Either::Right(set_clause_list),
+                    where_clause: Either::Right(where_clause),
+                    returning: Either::Right(returning),
+                }
+            }
+        }
+    }
+}
+
+/// Begin an update command.
+///
+/// To get a valid update statement, [self::UpdateFragment::set] must be used.
+/// See [self::update]
+pub struct UpdateFragment<Target>
+where
+    Target: G::TableName,
+{
+    target: Target,
+}
+
+impl<Target> UpdateFragment<Target>
+where
+    Target: G::TableName,
+{
+    /// Set the first column to update.
+    pub fn set<Column, Value>(
+        self,
+        column: Column,
+        value: Value,
+    ) -> Update<Blank, Target, SetClause<Column, Value>, Blank, Blank>
+    where
+        Column: G::ColumnName,
+        Value: G::UpdateSource,
+    {
+        Update {
+            with_clause: Blank,
+            target: self.target,
+            set_clause_list: SetClause::new(column, value),
+            where_clause: Blank,
+            returning: Blank,
+        }
+    }
+}
+
+#[inline]
+/// Creates an update statement.
+///
+/// # Example
+/// ```
+/// use sql_builder::{update, id, lit, bind, eq, prelude::*};
+///
+/// # let value = 20;
+/// # let id = 1;
+/// let stmt = update(id!(my_table))
+///     .set(id!(col1), lit!(10))
+///     .set(id!(col2), bind!(value))
+///     .r#where(eq(id!(id), bind!(id)));
+///
+/// let sql = stmt.to_string();
+/// assert_eq!(sql, "UPDATE my_table SET col1 = 10, col2 = ? WHERE id = ?");
+/// ```
+pub fn update<Target>(target: Target) -> UpdateFragment<Target>
+where
+    Target: G::TableName,
+{
+    UpdateFragment { target }
+}
@@ -0,0 +1,90 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::WidthBucketFunction;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, WidthBucketFunction)]
+/// WIDTH_BUCKET(<width bucket operand>, <width bucket bound 1>, <width bucket bound 2>, <width bucket count>)
+///
+/// See [self::width_bucket]
+pub struct WidthBucket<Operand, Bound1, Bound2, Count>
+where
+    Operand: G::NumericValueExpression,
+    Bound1: G::NumericValueExpression,
+    Bound2: G::NumericValueExpression,
+    Count: G::NumericValueExpression,
+{
+    operand: Operand,
+    bound1: Bound1,
+    bound2: Bound2,
+    count: Count,
+}
+
+impl<Operand, Bound1, Bound2, Count> ::std::fmt::Display
+    for WidthBucket<Operand, Bound1, Bound2, Count>
+where
+    Operand: G::NumericValueExpression + std::fmt::Display,
+    Bound1: G::NumericValueExpression + std::fmt::Display,
+    Bound2: G::NumericValueExpression + std::fmt::Display,
+    Count: G::NumericValueExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "WIDTH_BUCKET({}, {}, {}, {})",
+            self.operand, self.bound1, self.bound2, self.count
+        )
+    }
+}
+
+impl<'q, DB, Operand, Bound1, Bound2, Count> ToQuery<'q, DB>
+    for WidthBucket<Operand, Bound1, Bound2, Count>
+where
+    DB: Database,
+    Operand: G::NumericValueExpression + ToQuery<'q, DB>,
+    Bound1: G::NumericValueExpression + ToQuery<'q, DB>,
+    Bound2: G::NumericValueExpression + ToQuery<'q, DB>,
+    Count: G::NumericValueExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "WIDTH_BUCKET(")?;
+        self.operand.write(ctx)?;
+        write!(ctx, ", ")?;
+        self.bound1.write(ctx)?;
+        write!(ctx, ", ")?;
+        self.bound2.write(ctx)?;
+        write!(ctx, ", ")?;
+        self.count.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Returns the number of the bucket the operand falls in, the range between
+/// both bounds being split into `count` buckets of equal width.
+///
+/// # Example
+/// ```
+/// use sql_builder::{id, lit, width_bucket, prelude::*};
+///
+/// let expr = width_bucket(id!(age), lit!(0), lit!(100), lit!(10));
+/// assert_eq!(expr.to_string(), "WIDTH_BUCKET(age, 0, 100, 10)");
+/// ```
+pub fn width_bucket<Operand, Bound1, Bound2, Count>(
+    operand: Operand,
+    bound1: Bound1,
+    bound2: Bound2,
+    count: Count,
+) -> WidthBucket<Operand, Bound1, Bound2, Count>
+where
+    Operand: G::NumericValueExpression,
+    Bound1: G::NumericValueExpression,
+    Bound2: G::NumericValueExpression,
+    Count: G::NumericValueExpression,
+{
+    WidthBucket {
+        operand,
+        bound1,
+        bound2,
+        count,
+    }
+}
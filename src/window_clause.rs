@@ -0,0 +1,41 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::WindowClause;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, WindowClause)]
+/// WINDOW <window definition list>
+pub struct WindowClause<Definitions>
+where
+    Definitions: G::WindowDefinitionList,
+{
+    definitions: Definitions,
+}
+
+impl<Definitions> WindowClause<Definitions>
+where
+    Definitions: G::WindowDefinitionList,
+{
+    pub fn new(definitions: Definitions) -> Self {
+        Self { definitions }
+    }
+}
+
+impl<Definitions> ::std::fmt::Display for WindowClause<Definitions>
+where
+    Definitions: G::WindowDefinitionList + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "WINDOW {}", self.definitions)
+    }
+}
+
+impl<'q, DB, Definitions> ToQuery<'q, DB> for WindowClause<Definitions>
+where
+    DB: Database,
+    Definitions: G::WindowDefinitionList + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "WINDOW ")?;
+        self.definitions.write(ctx)
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::WindowDefinition;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, WindowDefinition)]
+/// <new window name> AS <window specification>
+///
+/// See [self::define_window]
+pub struct WindowDef<Name, Spec>
+where
+    Name: G::WindowName,
+    Spec: G::WindowSpecification,
+{
+    name: Name,
+    spec: Spec,
+}
+
+impl<Name, Spec> ::std::fmt::Display for WindowDef<Name, Spec>
+where
+    Name: G::WindowName + std::fmt::Display,
+    Spec: G::WindowSpecification + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} AS {}", self.name, self.spec)
+    }
+}
+
+impl<'q, DB, Name, Spec> ToQuery<'q, DB> for WindowDef<Name, Spec>
+where
+    DB: Database,
+    Name: G::WindowName + ToQuery<'q, DB>,
+    Spec: G::WindowSpecification + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        self.name.write(ctx)?;
+        write!(ctx, " AS ")?;
+        self.spec.write(ctx)
+    }
+}
+
+#[inline]
+/// Defines a named window, to be added in the WINDOW clause of a query.
+///
+/// See [crate::helpers::QuerySpecification::window]
+pub fn define_window<Name, Spec>(name: Name, spec: Spec) -> WindowDef<Name, Spec>
+where
+    Name: G::WindowName,
+    Spec: G::WindowSpecification,
+{
+    WindowDef { name, spec }
+}
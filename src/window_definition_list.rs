@@ -0,0 +1,47 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::WindowDefinitionList;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, WindowDefinitionList)]
+/// A linked-list of window definitions.
+pub struct WindowDefinitionLink<Head, Tail>
+where
+    Head: G::WindowDefinitionList,
+    Tail: G::WindowDefinition,
+{
+    head: Head,
+    tail: Tail,
+}
+
+impl<Head, Tail> WindowDefinitionLink<Head, Tail>
+where
+    Head: G::WindowDefinitionList,
+    Tail: G::WindowDefinition,
+{
+    pub fn new(head: Head, tail: Tail) -> Self {
+        Self { head, tail }
+    }
+}
+
+impl<Head, Tail> ::std::fmt::Display for WindowDefinitionLink<Head, Tail>
+where
+    Head: G::WindowDefinitionList + std::fmt::Display,
+    Tail: G::WindowDefinition + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}", self.head, self.tail)
+    }
+}
+
+impl<'q, DB, Head, Tail> ToQuery<'q, DB> for WindowDefinitionLink<Head, Tail>
+where
+    DB: Database,
+    Head: G::WindowDefinitionList + ToQuery<'q, DB>,
+    Tail: G::WindowDefinition + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> ::std::fmt::Result {
+        self.head.write(ctx)?;
+        write!(ctx, ", ")?;
+        self.tail.write(ctx)
+    }
+}
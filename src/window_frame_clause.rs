@@ -0,0 +1,150 @@
+use crate::{Database, ToQuery};
+use sql_builder_macros::WindowFrameClause;
+use std::fmt::Write;
+
+#[derive(Clone, Copy)]
+/// <window frame units> ::= ROWS | RANGE
+pub enum WindowFrameUnits {
+    Rows,
+    Range,
+}
+
+impl AsRef<str> for WindowFrameUnits {
+    fn as_ref(&self) -> &str {
+        match self {
+            WindowFrameUnits::Rows => "ROWS",
+            WindowFrameUnits::Range => "RANGE",
+        }
+    }
+}
+
+impl std::fmt::Display for WindowFrameUnits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Clone, Copy)]
+/// <window frame start> ::= UNBOUNDED PRECEDING | <n> PRECEDING | CURRENT ROW
+pub enum WindowFrameStart {
+    /// UNBOUNDED PRECEDING
+    UnboundedPreceding,
+    /// <n> PRECEDING
+    Preceding(u64),
+    /// CURRENT ROW
+    CurrentRow,
+}
+
+impl From<WindowFrameStart> for WindowFrameBound {
+    fn from(start: WindowFrameStart) -> Self {
+        match start {
+            WindowFrameStart::UnboundedPreceding => WindowFrameBound::UnboundedPreceding,
+            WindowFrameStart::Preceding(n) => WindowFrameBound::Preceding(n),
+            WindowFrameStart::CurrentRow => WindowFrameBound::CurrentRow,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+/// <window frame bound>
+pub enum WindowFrameBound {
+    /// UNBOUNDED PRECEDING
+    UnboundedPreceding,
+    /// <n> PRECEDING
+    Preceding(u64),
+    /// CURRENT ROW
+    CurrentRow,
+    /// <n> FOLLOWING
+    Following(u64),
+    /// UNBOUNDED FOLLOWING
+    UnboundedFollowing,
+}
+
+impl WindowFrameBound {
+    /// The position of the bound relative to the current row, regardless of its offset.
+    fn rank(&self) -> u8 {
+        match self {
+            WindowFrameBound::UnboundedPreceding => 0,
+            WindowFrameBound::Preceding(_) => 1,
+            WindowFrameBound::CurrentRow => 2,
+            WindowFrameBound::Following(_) => 3,
+            WindowFrameBound::UnboundedFollowing => 4,
+        }
+    }
+}
+
+impl std::fmt::Display for WindowFrameBound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WindowFrameBound::UnboundedPreceding => write!(f, "UNBOUNDED PRECEDING"),
+            WindowFrameBound::Preceding(n) => write!(f, "{} PRECEDING", n),
+            WindowFrameBound::CurrentRow => write!(f, "CURRENT ROW"),
+            WindowFrameBound::Following(n) => write!(f, "{} FOLLOWING", n),
+            WindowFrameBound::UnboundedFollowing => write!(f, "UNBOUNDED FOLLOWING"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, WindowFrameClause)]
+/// <window frame units> { <window frame start> | BETWEEN <window frame bound> AND <window frame bound> }
+///
+/// See [crate::window_specification::WindowSpec::rows] and [crate::window_specification::WindowSpec::range]
+pub struct WindowFrame {
+    units: WindowFrameUnits,
+    start: WindowFrameBound,
+    end: Option<WindowFrameBound>,
+}
+
+impl WindowFrame {
+    pub fn new(units: WindowFrameUnits, start: WindowFrameStart) -> Self {
+        Self {
+            units,
+            start: start.into(),
+            end: None,
+        }
+    }
+
+    /// A frame between the two bounds.
+    ///
+    /// The start bound cannot be UNBOUNDED FOLLOWING, the end bound cannot be
+    /// UNBOUNDED PRECEDING, and the start bound cannot come after the end bound.
+    pub fn between(
+        units: WindowFrameUnits,
+        start: WindowFrameBound,
+        end: WindowFrameBound,
+    ) -> Result<Self, crate::Error> {
+        if matches!(start, WindowFrameBound::UnboundedFollowing)
+            || matches!(end, WindowFrameBound::UnboundedPreceding)
+            || start.rank() > end.rank()
+        {
+            return Err(crate::Error::invalid_window_frame(format!(
+                "{} BETWEEN {} AND {}",
+                units, start, end
+            )));
+        }
+
+        Ok(Self {
+            units,
+            start,
+            end: Some(end),
+        })
+    }
+}
+
+impl std::fmt::Display for WindowFrame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.end {
+            Some(end) => write!(f, "{} BETWEEN {} AND {}", self.units, self.start, end),
+            None => write!(f, "{} {}", self.units, self.start),
+        }
+    }
+}
+
+impl<'q, DB> ToQuery<'q, DB> for WindowFrame
+where
+    DB: Database,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "{}", self)
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::WindowFunction;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, WindowFunction)]
+/// <window function type> OVER <window name or specification>
+///
+/// See [crate::helpers::WindowFunctionType::over]
+pub struct Over<Function, Window>
+where
+    Function: G::WindowFunctionType,
+    Window: G::WindowNameOrSpecification,
+{
+    function: Function,
+    window: Window,
+}
+
+impl<Function, Window> Over<Function, Window>
+where
+    Function: G::WindowFunctionType,
+    Window: G::WindowNameOrSpecification,
+{
+    pub fn new(function: Function, window: Window) -> Self {
+        Self { function, window }
+    }
+}
+
+impl<Function, Window> ::std::fmt::Display for Over<Function, Window>
+where
+    Function: G::WindowFunctionType + std::fmt::Display,
+    Window: G::WindowNameOrSpecification + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} OVER {}", self.function, self.window)
+    }
+}
+
+impl<'q, DB, Function, Window> ToQuery<'q, DB> for Over<Function, Window>
+where
+    DB: Database,
+    Function: G::WindowFunctionType + ToQuery<'q, DB>,
+    Window: G::WindowNameOrSpecification + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        self.function.write(ctx)?;
+        write!(ctx, " OVER ")?;
+        self.window.write(ctx)
+    }
+}
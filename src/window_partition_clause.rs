@@ -0,0 +1,41 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::WindowPartitionClause;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, WindowPartitionClause)]
+/// PARTITION BY <window partition column reference list>
+pub struct PartitionBy<Columns>
+where
+    Columns: G::WindowPartitionColumnReferenceList,
+{
+    columns: Columns,
+}
+
+impl<Columns> PartitionBy<Columns>
+where
+    Columns: G::WindowPartitionColumnReferenceList,
+{
+    pub fn new(columns: Columns) -> Self {
+        Self { columns }
+    }
+}
+
+impl<Columns> ::std::fmt::Display for PartitionBy<Columns>
+where
+    Columns: G::WindowPartitionColumnReferenceList + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PARTITION BY {}", self.columns)
+    }
+}
+
+impl<'q, DB, Columns> ToQuery<'q, DB> for PartitionBy<Columns>
+where
+    DB: Database,
+    Columns: G::WindowPartitionColumnReferenceList + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "PARTITION BY ")?;
+        self.columns.write(ctx)
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::WindowPartitionColumnReferenceList;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, WindowPartitionColumnReferenceList)]
+/// A linked-list of partition columns.
+pub struct WindowPartitionColumnLink<Head, Tail>
+where
+    Head: G::WindowPartitionColumnReferenceList,
+    Tail: G::ColumnReference,
+{
+    head: Head,
+    tail: Tail,
+}
+
+impl<Head, Tail> WindowPartitionColumnLink<Head, Tail>
+where
+    Head: G::WindowPartitionColumnReferenceList,
+    Tail: G::ColumnReference,
+{
+    pub fn new(head: Head, tail: Tail) -> Self {
+        Self { head, tail }
+    }
+}
+
+impl<Head, Tail> ::std::fmt::Display for WindowPartitionColumnLink<Head, Tail>
+where
+    Head: G::WindowPartitionColumnReferenceList + std::fmt::Display,
+    Tail: G::ColumnReference + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}", self.head, self.tail)
+    }
+}
+
+impl<'q, DB, Head, Tail> ToQuery<'q, DB> for WindowPartitionColumnLink<Head, Tail>
+where
+    DB: Database,
+    Head: G::WindowPartitionColumnReferenceList + ToQuery<'q, DB>,
+    Tail: G::ColumnReference + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> ::std::fmt::Result {
+        self.head.write(ctx)?;
+        write!(ctx, ", ")?;
+        self.tail.write(ctx)
+    }
+}
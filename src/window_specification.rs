@@ -0,0 +1,230 @@
+use crate::{
+    blank::Blank,
+    grammar as G,
+    order_by::OrderBy,
+    window_frame_clause::{WindowFrame, WindowFrameBound, WindowFrameStart, WindowFrameUnits},
+    window_partition_clause::PartitionBy,
+    Database, ToQuery,
+};
+use sql_builder_macros::WindowSpecification;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, WindowSpecification)]
+/// ([ <existing window name> ] [ <window partition clause> ] [ <window order clause> ] [ <window frame clause> ])
+///
+/// See [self::window]
+pub struct WindowSpec<Existing, Partition, Order, Frame>
+where
+    Existing: G::ExistingWindowName,
+    Partition: G::WindowPartitionClause,
+    Order: G::OrderByClause,
+    Frame: G::WindowFrameClause,
+{
+    existing: Existing,
+    partition: Partition,
+    order: Order,
+    frame: Frame,
+}
+
+impl<Existing, Partition, Order, Frame> WindowSpec<Existing, Partition, Order, Frame>
+where
+    Existing: G::ExistingWindowName,
+    Partition: G::WindowPartitionClause,
+    Order: G::OrderByClause,
+    Frame: G::WindowFrameClause,
+{
+    /// Refine an existing window.
+    pub fn existing<Name>(self, name: Name) -> WindowSpec<Name, Partition, Order, Frame>
+    where
+        Name: G::ExistingWindowName,
+    {
+        WindowSpec {
+            existing: name,
+            partition: self.partition,
+            order: self.order,
+            frame: self.frame,
+        }
+    }
+
+    /// Split the rows into partitions.
+    pub fn partition_by<Columns>(
+        self,
+        columns: Columns,
+    ) -> WindowSpec<Existing, PartitionBy<Columns>, Order, Frame>
+    where
+        Columns: G::WindowPartitionColumnReferenceList,
+    {
+        WindowSpec {
+            existing: self.existing,
+            partition: PartitionBy::new(columns),
+            order: self.order,
+            frame: self.frame,
+        }
+    }
+
+    /// Order the rows of each partition.
+    pub fn order_by<Specs>(
+        self,
+        specs: Specs,
+    ) -> WindowSpec<Existing, Partition, OrderBy<Specs>, Frame>
+    where
+        Specs: G::SortSpecificationList,
+    {
+        WindowSpec {
+            existing: self.existing,
+            partition: self.partition,
+            order: OrderBy::new(specs),
+            frame: self.frame,
+        }
+    }
+
+    /// Replace the window frame clause.
+    pub fn frame<NewFrame>(
+        self,
+        frame: NewFrame,
+    ) -> WindowSpec<Existing, Partition, Order, NewFrame>
+    where
+        NewFrame: G::WindowFrameClause,
+    {
+        WindowSpec {
+            existing: self.existing,
+            partition: self.partition,
+            order: self.order,
+            frame,
+        }
+    }
+
+    /// Frame the rows from the start bound to the current row.
+    pub fn rows(
+        self,
+        start: WindowFrameStart,
+    ) -> WindowSpec<Existing, Partition, Order, WindowFrame> {
+        self.frame(WindowFrame::new(WindowFrameUnits::Rows, start))
+    }
+
+    /// Frame the rows between the two bounds.
+    ///
+    /// Fails if the bounds are out of order, see [WindowFrame::between].
+    pub fn rows_between(
+        self,
+        start: WindowFrameBound,
+        end: WindowFrameBound,
+    ) -> Result<WindowSpec<Existing, Partition, Order, WindowFrame>, crate::Error> {
+        Ok(self.frame(WindowFrame::between(WindowFrameUnits::Rows, start, end)?))
+    }
+
+    /// Frame the peer rows from the start bound to the current row.
+    pub fn range(
+        self,
+        start: WindowFrameStart,
+    ) -> WindowSpec<Existing, Partition, Order, WindowFrame> {
+        self.frame(WindowFrame::new(WindowFrameUnits::Range, start))
+    }
+
+    /// Frame the peer rows between the two bounds.
+    ///
+    /// Fails if the bounds are out of order, see [WindowFrame::between].
+    pub fn range_between(
+        self,
+        start: WindowFrameBound,
+        end: WindowFrameBound,
+    ) -> Result<WindowSpec<Existing, Partition, Order, WindowFrame>, crate::Error> {
+        Ok(self.frame(WindowFrame::between(WindowFrameUnits::Range, start, end)?))
+    }
+}
+
+impl<Existing, Partition, Order, Frame> ::std::fmt::Display
+    for WindowSpec<Existing, Partition, Order, Frame>
+where
+    Existing: G::ExistingWindowName + std::fmt::Display,
+    Partition: G::WindowPartitionClause + std::fmt::Display,
+    Order: G::OrderByClause + std::fmt::Display,
+    Frame: G::WindowFrameClause + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut sep = "";
+
+        write!(f, "(")?;
+
+        if Existing::IS_IMPL {
+            write!(f, "{}{}", sep, self.existing)?;
+            sep = " ";
+        }
+
+        if Partition::IS_IMPL {
+            write!(f, "{}{}", sep, self.partition)?;
+            sep = " ";
+        }
+
+        if Order::IS_IMPL {
+            write!(f, "{}{}", sep, self.order)?;
+            sep = " ";
+        }
+
+        if Frame::IS_IMPL {
+            write!(f, "{}{}", sep, self.frame)?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+impl<'q, DB, Existing, Partition, Order, Frame> ToQuery<'q, DB>
+    for WindowSpec<Existing, Partition, Order, Frame>
+where
+    DB: Database,
+    Existing: G::ExistingWindowName + ToQuery<'q, DB>,
+    Partition: G::WindowPartitionClause + ToQuery<'q, DB>,
+    Order: G::OrderByClause + ToQuery<'q, DB>,
+    Frame: G::WindowFrameClause + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        let mut sep = "";
+
+        write!(ctx, "(")?;
+
+        if Existing::IS_IMPL {
+            write!(ctx, "{}", sep)?;
+            self.existing.write(ctx)?;
+            sep = " ";
+        }
+
+        if Partition::IS_IMPL {
+            write!(ctx, "{}", sep)?;
+            self.partition.write(ctx)?;
+            sep = " ";
+        }
+
+        if Order::IS_IMPL {
+            write!(ctx, "{}", sep)?;
+            self.order.write(ctx)?;
+            sep = " ";
+        }
+
+        if Frame::IS_IMPL {
+            write!(ctx, "{}", sep)?;
+            self.frame.write(ctx)?;
+        }
+
+        write!(ctx, ")")
+    }
+}
+
+#[inline]
+/// Creates an empty window specification.
+///
+/// # Example
+/// ```
+/// use sql_builder::{id, window, prelude::*};
+///
+/// let spec = window().partition_by(id!(dept)).order_by(id!(salary).desc());
+/// assert_eq!(spec.to_string(), "(PARTITION BY dept ORDER BY salary DESC)");
+/// ```
+pub fn window() -> WindowSpec<Blank, Blank, Blank, Blank> {
+    WindowSpec {
+        existing: Blank,
+        partition: Blank,
+        order: Blank,
+        frame: Blank,
+    }
+}
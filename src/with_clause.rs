@@ -0,0 +1,193 @@
+use crate::{blank::Blank, with_list::WithListLink, with_list_element::WithElement, ToQuery};
+use sql_builder_macros::WithClause;
+use std::fmt::Write;
+
+use crate::grammar as G;
+use crate::helpers as H;
+use crate::Database;
+
+#[derive(Clone, Copy, WithClause)]
+/// WITH [ RECURSIVE ] <with list>
+///
+/// See [self::with] or [self::with_recursive]
+pub struct With<List>
+where
+    List: G::WithList,
+{
+    recursive: bool,
+    list: List,
+}
+
+impl<List> With<List>
+where
+    List: G::WithList,
+{
+    /// Add another common table expression.
+    pub fn with<Name, Columns, Query>(
+        self,
+        name: Name,
+        columns: Columns,
+        query: Query,
+    ) -> With<WithListLink<List, WithElement<Name, Columns, Query>>>
+    where
+        Name: G::QueryName,
+        Columns: G::WithColumnList,
+        Query: G::QueryExpression,
+    {
+        With {
+            recursive: self.recursive,
+            list: H::WithList::add_with_element(self.list, WithElement::new(name, columns, query)),
+        }
+    }
+
+    /// Prefix the query with the common table expressions.
+    ///
+    /// The query must not have a with clause already, the common table
+    /// expressions are added to this one with [With::with] instead.
+    ///
+    /// ```compile_fail
+    /// use sql_builder::{blank::Blank, id, select, with, prelude::*};
+    ///
+    /// let query = with(id!(a), Blank, select(id!(x)).from(id!(t1))).query(select(id!(x)).from(id!(a)));
+    /// with(id!(b), Blank, select(id!(x)).from(id!(t2))).query(query);
+    /// ```
+    pub fn query<Query>(self, query: Query) -> H::QueryExpressionWithTransformedWith<Query, Self>
+    where
+        Query: G::QueryExpression + H::QueryExpression<WithClause = Blank>,
+    {
+        query.transform_with(|_| self)
+    }
+
+    /// Prefix the insert statement with the common table expressions.
+    ///
+    /// The statement must not have a with clause already, see [With::query].
+    pub fn insert<Stmt>(
+        self,
+        stmt: Stmt,
+    ) -> crate::insert::Insert<Self, Stmt::Target, Stmt::ColumnsAndSources, Stmt::ReturningClause>
+    where
+        Stmt: G::Insert + H::Insert<WithClause = Blank>,
+    {
+        stmt.transform_with(|_| self)
+    }
+
+    /// Prefix the update statement with the common table expressions.
+    ///
+    /// The statement must not have a with clause already, see [With::query].
+    pub fn update<Stmt>(
+        self,
+        stmt: Stmt,
+    ) -> crate::update::Update<
+        Self,
+        Stmt::Target,
+        Stmt::SetClauseList,
+        Stmt::WhereClause,
+        Stmt::ReturningClause,
+    >
+    where
+        Stmt: G::UpdateStatement + H::UpdateStatement<WithClause = Blank>,
+    {
+        stmt.transform_with(|_| self)
+    }
+
+    /// Prefix the delete statement with the common table expressions.
+    ///
+    /// The statement must not have a with clause already, see [With::query].
+    pub fn delete<Stmt>(
+        self,
+        stmt: Stmt,
+    ) -> crate::delete::Delete<Self, Stmt::Target, Stmt::WhereClause, Stmt::ReturningClause>
+    where
+        Stmt: G::DeleteStatement + H::DeleteStatement<WithClause = Blank>,
+    {
+        stmt.transform_with(|_| self)
+    }
+}
+
+impl<List> ::std::fmt::Display for With<List>
+where
+    List: G::WithList + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "WITH ")?;
+
+        if self.recursive {
+            write!(f, "RECURSIVE ")?;
+        }
+
+        write!(f, "{}", self.list)
+    }
+}
+
+impl<'q, DB, List> ToQuery<'q, DB> for With<List>
+where
+    DB: Database,
+    List: G::WithList + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        write!(ctx, "WITH ")?;
+
+        if self.recursive {
+            write!(ctx, "RECURSIVE ")?;
+        }
+
+        self.list.write(ctx)
+    }
+}
+
+#[inline]
+/// Creates a with clause, defining a common table expression.
+///
+/// The name of the common table expression can then be used as a table
+/// reference in the main statement.
+///
+/// # Example
+/// ```
+/// use sql_builder::{with, select, columns, id, prelude::*};
+///
+/// let stmt = with(
+///     id!(recent),
+///     columns!(id!(id)),
+///     select(id!(id)).from(id!(orders)),
+/// )
+/// .query(select(id!(id)).from(id!(recent)));
+///
+/// let sql = stmt.to_string();
+/// assert_eq!(sql, "WITH recent (id) AS (SELECT id FROM orders) SELECT id FROM recent");
+/// ```
+pub fn with<Name, Columns, Query>(
+    name: Name,
+    columns: Columns,
+    query: Query,
+) -> With<WithElement<Name, Columns, Query>>
+where
+    Name: G::QueryName,
+    Columns: G::WithColumnList,
+    Query: G::QueryExpression,
+{
+    With {
+        recursive: false,
+        list: WithElement::new(name, columns, query),
+    }
+}
+
+#[inline]
+/// Creates a recursive with clause, the query of a common table expression
+/// can reference itself.
+///
+/// See [self::with]
+pub fn with_recursive<Name, Columns, Query>(
+    name: Name,
+    columns: Columns,
+    query: Query,
+) -> With<WithElement<Name, Columns, Query>>
+where
+    Name: G::QueryName,
+    Columns: G::WithColumnList,
+    Query: G::QueryExpression,
+{
+    With {
+        recursive: true,
+        list: WithElement::new(name, columns, query),
+    }
+}
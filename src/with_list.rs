@@ -0,0 +1,47 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::WithList;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, WithList)]
+/// A linked-list of with list elements.
+pub struct WithListLink<Head, Tail>
+where
+    Head: G::WithList,
+    Tail: G::WithListElement,
+{
+    head: Head,
+    tail: Tail,
+}
+
+impl<Head, Tail> WithListLink<Head, Tail>
+where
+    Head: G::WithList,
+    Tail: G::WithListElement,
+{
+    pub fn new(head: Head, tail: Tail) -> Self {
+        Self { head, tail }
+    }
+}
+
+impl<Head, Tail> ::std::fmt::Display for WithListLink<Head, Tail>
+where
+    Head: G::WithList + std::fmt::Display,
+    Tail: G::WithListElement + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}", self.head, self.tail)
+    }
+}
+
+impl<'q, DB, Head, Tail> ToQuery<'q, DB> for WithListLink<Head, Tail>
+where
+    DB: Database,
+    Head: G::WithList + ToQuery<'q, DB>,
+    Tail: G::WithListElement + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> ::std::fmt::Result {
+        self.head.write(ctx)?;
+        write!(ctx, ", ")?;
+        self.tail.write(ctx)
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{grammar as G, Database, ToQuery};
+use sql_builder_macros::WithListElement;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, WithListElement)]
+/// <query name> [ (<with column list>) ] AS (<query expression>)
+pub struct WithElement<Name, Columns, Query>
+where
+    Name: G::QueryName,
+    Columns: G::WithColumnList,
+    Query: G::QueryExpression,
+{
+    name: Name,
+    columns: Columns,
+    query: Query,
+}
+
+impl<Name, Columns, Query> WithElement<Name, Columns, Query>
+where
+    Name: G::QueryName,
+    Columns: G::WithColumnList,
+    Query: G::QueryExpression,
+{
+    pub fn new(name: Name, columns: Columns, query: Query) -> Self {
+        Self {
+            name,
+            columns,
+            query,
+        }
+    }
+}
+
+impl<Name, Columns, Query> ::std::fmt::Display for WithElement<Name, Columns, Query>
+where
+    Name: G::QueryName + std::fmt::Display,
+    Columns: G::WithColumnList + std::fmt::Display,
+    Query: G::QueryExpression + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+
+        if Columns::IS_IMPL {
+            write!(f, " ({})", self.columns)?;
+        }
+
+        write!(f, " AS ({})", self.query)
+    }
+}
+
+impl<'q, DB, Name, Columns, Query> ToQuery<'q, DB> for WithElement<Name, Columns, Query>
+where
+    DB: Database,
+    Name: G::QueryName + ToQuery<'q, DB>,
+    Columns: G::WithColumnList + ToQuery<'q, DB>,
+    Query: G::QueryExpression + ToQuery<'q, DB>,
+{
+    fn write(&'q self, ctx: &mut crate::ToQueryContext<'q, DB>) -> std::fmt::Result {
+        self.name.write(ctx)?;
+
+        if Columns::IS_IMPL {
+            write!(ctx, " (")?;
+            self.columns.write(ctx)?;
+            write!(ctx, ")")?;
+        }
+
+        write!(ctx, " AS (")?;
+        self.query.write(ctx)?;
+        write!(ctx, ")")
+    }
+}
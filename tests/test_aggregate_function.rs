@@ -0,0 +1,45 @@
+use sql_builder::{
+    asterisk::Asterisk, avg, bind, count, eq, gt, id, lit, max, min, prelude::*, select,
+    select_columns, sum,
+};
+
+#[test]
+fn test_count() {
+    assert_eq!(count(Asterisk).to_string(), "COUNT(*)");
+    assert_eq!(count(id!(email)).to_string(), "COUNT(email)");
+    assert_eq!(
+        count(id!(email)).distinct().to_string(),
+        "COUNT(DISTINCT email)"
+    );
+}
+
+#[test]
+fn test_aggregates_with_quantifier_and_filter() {
+    assert_eq!(sum(id!(total)).all().to_string(), "SUM(ALL total)");
+    assert_eq!(avg(id!(total)).to_string(), "AVG(total)");
+    assert_eq!(
+        count(Asterisk)
+            .filter(eq(id!(status), lit!("paid")))
+            .to_string(),
+        "COUNT(*) FILTER (WHERE status = 'paid')"
+    );
+}
+
+#[test]
+fn test_aggregates_in_select() {
+    let stmt = select(select_columns!(
+        id!(customer_id),
+        count(Asterisk).alias_column(id!(orders)),
+        min(id!(total)),
+        max(id!(total)).filter(gt(id!(total), bind(0)))
+    ))
+    .from(id!(orders))
+    .group_by(id!(customer_id))
+    .having(gt(sum(id!(total)), lit!(1000)));
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT customer_id, COUNT(*) AS orders, MIN(total), MAX(total) FILTER (WHERE total > ?) FROM orders GROUP BY customer_id HAVING SUM(total) > 1000"
+    );
+}
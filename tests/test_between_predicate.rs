@@ -0,0 +1,29 @@
+use sql_builder::{between, bind, id, lit, not_between, prelude::*, select};
+
+#[test]
+fn test_between() {
+    let pred = between(id!(price), lit!(10), lit!(20));
+    let sql = pred.to_string();
+    assert_eq!(sql, "price BETWEEN 10 AND 20");
+}
+
+#[test]
+fn test_not_between_symmetric() {
+    let pred = not_between(id!(price), bind(20), bind(10)).symmetric();
+    let sql = pred.to_string();
+    assert_eq!(sql, "price NOT BETWEEN SYMMETRIC ? AND ?");
+
+    let pred = between(id!(price), lit!(10), lit!(20)).asymmetric();
+    let sql = pred.to_string();
+    assert_eq!(sql, "price BETWEEN ASYMMETRIC 10 AND 20");
+}
+
+#[test]
+fn test_between_in_where_clause() {
+    let stmt = select(id!(id))
+        .from(id!(products))
+        .r#where(between(id!(price), lit!(10), lit!(20)));
+
+    let sql = stmt.to_string();
+    assert_eq!(sql, "SELECT id FROM products WHERE price BETWEEN 10 AND 20");
+}
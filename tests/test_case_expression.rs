@@ -0,0 +1,68 @@
+use sql_builder::{
+    bind, case, coalesce, eq, gt, id, lit, nullif, prelude::*, select, select_columns, simple_case,
+};
+
+#[test]
+fn test_searched_case() {
+    let expr = case()
+        .when(gt(id!(total), lit!(1000)), lit!("large"))
+        .when(gt(id!(total), lit!(100)), lit!("medium"))
+        .else_(lit!("small"));
+
+    let sql = expr.to_string();
+    assert_eq!(
+        sql,
+        "CASE WHEN total > 1000 THEN 'large' WHEN total > 100 THEN 'medium' ELSE 'small' END"
+    );
+}
+
+#[test]
+fn test_simple_case() {
+    let expr = simple_case(id!(status))
+        .when(lit!(1), lit!("active"))
+        .when(lit!(2), lit!("banned"));
+
+    let sql = expr.to_string();
+    assert_eq!(
+        sql,
+        "CASE status WHEN 1 THEN 'active' WHEN 2 THEN 'banned' END"
+    );
+}
+
+#[test]
+fn test_case_aliased_and_in_predicate() {
+    let label = case()
+        .when(eq(id!(kind), bind(1)), lit!("a"))
+        .else_(lit!("b"))
+        .alias_column(id!(label));
+
+    let stmt = select(select_columns!(id!(id), label))
+        .from(id!(items))
+        .r#where(eq(
+            simple_case(id!(kind)).when(lit!(1), id!(a)).else_(id!(b)),
+            bind(10),
+        ));
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT id, CASE WHEN kind = ? THEN 'a' ELSE 'b' END AS label FROM items WHERE CASE kind WHEN 1 THEN a ELSE b END = ?"
+    );
+}
+
+#[test]
+fn test_nullif_and_coalesce() {
+    let expr = nullif(id!(amount), lit!(0));
+    assert_eq!(expr.to_string(), "NULLIF(amount, 0)");
+
+    let expr = coalesce(
+        id!(nickname)
+            .add_sql_argument(id!(name))
+            .add_sql_argument(lit!("anonymous")),
+    )
+    .alias_column(id!(display_name));
+    assert_eq!(
+        expr.to_string(),
+        "COALESCE(nickname, name, 'anonymous') AS display_name"
+    );
+}
@@ -0,0 +1,26 @@
+use sql_builder::{bind, cast, eq, id, lit, prelude::*, select, select_columns, DataType};
+
+#[test]
+fn test_cast() {
+    let expr = cast(id!(price), DataType::numeric(10, 2));
+    assert_eq!(expr.to_string(), "CAST(price AS NUMERIC(10, 2))");
+
+    let expr = cast(lit!("2024-01-01"), DataType::Date);
+    assert_eq!(expr.to_string(), "CAST('2024-01-01' AS DATE)");
+}
+
+#[test]
+fn test_cast_in_statement() {
+    let stmt = select(select_columns!(
+        id!(id),
+        cast(id!(created_at), DataType::timestamp_with_time_zone()).alias_column(id!(created))
+    ))
+    .from(id!(events))
+    .r#where(eq(cast(id!(code), DataType::varchar(10)), bind("A1")));
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT id, CAST(created_at AS TIMESTAMP WITH TIME ZONE) AS created FROM events WHERE CAST(code AS CHARACTER VARYING(10)) = ?"
+    );
+}
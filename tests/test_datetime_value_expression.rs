@@ -0,0 +1,128 @@
+use sql_builder::{
+    add_interval, and, current_date, current_timestamp,
+    data_type::{DatetimeField, IntervalQualifier},
+    date_lit, extract, gte, id, interval_lit, lit, localtimestamp, lt,
+    prelude::*,
+    select, select_columns, sub_interval, time_lit, timestamp_lit,
+};
+
+#[test]
+fn test_datetime_literals() {
+    assert_eq!(date_lit("2024-02-29").to_string(), "DATE '2024-02-29'");
+    assert_eq!(time_lit("12:30:00").to_string(), "TIME '12:30:00'");
+    assert_eq!(
+        timestamp_lit("2024-02-29 12:30:00").to_string(),
+        "TIMESTAMP '2024-02-29 12:30:00'"
+    );
+    assert_eq!(
+        interval_lit(
+            "1 12:00:00",
+            IntervalQualifier::range(DatetimeField::Day, DatetimeField::Second).unwrap()
+        )
+        .to_string(),
+        "INTERVAL '1 12:00:00' DAY TO SECOND"
+    );
+}
+
+#[test]
+fn test_datetime_literals_with_macro() {
+    assert_eq!(lit!(DATE "2024-02-29").to_string(), "DATE '2024-02-29'");
+    assert_eq!(lit!(TIME "12:30:00").to_string(), "TIME '12:30:00'");
+    assert_eq!(
+        lit!(TIMESTAMP "2024-02-29 12:30:00").to_string(),
+        "TIMESTAMP '2024-02-29 12:30:00'"
+    );
+    assert_eq!(lit!(INTERVAL "7" DAY).to_string(), "INTERVAL '7' DAY");
+    assert_eq!(
+        lit!(INTERVAL "1 12:00:00" DAY TO SECOND).to_string(),
+        "INTERVAL '1 12:00:00' DAY TO SECOND"
+    );
+}
+
+#[test]
+fn test_datetime_arithmetic_and_extract() {
+    assert_eq!(
+        add_interval(current_timestamp(), lit!(INTERVAL "7" DAY)).to_string(),
+        "CURRENT_TIMESTAMP + INTERVAL '7' DAY"
+    );
+    assert_eq!(
+        sub_interval(id!(expires_at), id!(grace_period)).to_string(),
+        "expires_at - grace_period"
+    );
+    assert_eq!(
+        extract(DatetimeField::Year, current_date()).to_string(),
+        "EXTRACT(YEAR FROM CURRENT_DATE)"
+    );
+}
+
+#[test]
+fn test_datetime_in_select() {
+    let stmt = select(select_columns!(
+        id!(id),
+        extract(DatetimeField::Month, id!(created_at)).alias_column(id!(month))
+    ))
+    .from(id!(orders))
+    .r#where(and(
+        gte(
+            id!(created_at),
+            sub_interval(localtimestamp(), lit!(INTERVAL "30" DAY)),
+        ),
+        lt(id!(created_at), lit!(DATE "2025-01-01")),
+    ));
+
+    assert_eq!(
+        stmt.to_string(),
+        "SELECT id, EXTRACT(MONTH FROM created_at) AS month FROM orders WHERE created_at >= LOCALTIMESTAMP - INTERVAL '30' DAY AND created_at < DATE '2025-01-01'"
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono_literals() {
+    use chrono::{NaiveDate, TimeDelta};
+    use sql_builder::interval_literal::IntervalLiteral;
+
+    let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    let datetime = date.and_hms_milli_opt(12, 30, 0, 250).unwrap();
+
+    assert_eq!(date_lit(date).to_string(), "DATE '2024-02-29'");
+    assert_eq!(time_lit(datetime.time()).to_string(), "TIME '12:30:00.250'");
+    assert_eq!(
+        timestamp_lit(datetime).to_string(),
+        "TIMESTAMP '2024-02-29 12:30:00.250'"
+    );
+    assert_eq!(
+        timestamp_lit(datetime.and_utc()).to_string(),
+        "TIMESTAMP '2024-02-29 12:30:00.250+00:00'"
+    );
+    assert_eq!(
+        IntervalLiteral::from(-TimeDelta::seconds(90_061)).to_string(),
+        "INTERVAL '-1 01:01:01' DAY TO SECOND"
+    );
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_time_literals() {
+    use sql_builder::interval_literal::IntervalLiteral;
+    use time::{Date, Duration, Month, PrimitiveDateTime, Time, UtcOffset};
+
+    let date = Date::from_calendar_date(2024, Month::February, 29).unwrap();
+    let time = Time::from_hms_milli(8, 5, 0, 500).unwrap();
+    let datetime = PrimitiveDateTime::new(date, time);
+
+    assert_eq!(date_lit(date).to_string(), "DATE '2024-02-29'");
+    assert_eq!(time_lit(time).to_string(), "TIME '08:05:00.5'");
+    assert_eq!(
+        timestamp_lit(datetime).to_string(),
+        "TIMESTAMP '2024-02-29 08:05:00.5'"
+    );
+    assert_eq!(
+        timestamp_lit(datetime.assume_offset(UtcOffset::from_hms(-5, -30, 0).unwrap())).to_string(),
+        "TIMESTAMP '2024-02-29 08:05:00.5-05:30'"
+    );
+    assert_eq!(
+        IntervalLiteral::from(Duration::milliseconds(3_723_500)).to_string(),
+        "INTERVAL '0 01:02:03.5' DAY TO SECOND"
+    );
+}
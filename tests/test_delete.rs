@@ -0,0 +1,63 @@
+use sql_builder::{
+    add, asterisk::Asterisk, bind, delete_from, eq, id, lit, lt, or, prelude::*, select_columns,
+};
+use sqlx::{Arguments as _, Postgres};
+
+#[test]
+fn test_delete_all() {
+    let stmt = delete_from(id!(my_table));
+
+    let sql = stmt.to_string();
+    assert_eq!(sql, "DELETE FROM my_table");
+}
+
+#[test]
+fn test_delete_where() {
+    let stmt = delete_from(id!(my_table)).r#where(eq(id!(id), bind(10)));
+
+    let sql = stmt.to_string();
+    assert_eq!(sql, "DELETE FROM my_table WHERE id = ?");
+}
+
+#[test]
+fn test_delete_transform_if() {
+    let stmt =
+        delete_from(id!(my_table)).transform_if(true, |stmt| stmt.r#where(eq(id!(id), lit!(10))));
+
+    let sql = stmt.to_string();
+    assert_eq!(sql, "DELETE FROM my_table WHERE id = 10");
+
+    let stmt = delete_from(id!(my_table))
+        .transform_if(false, |stmt| stmt.r#where(eq(id!(id), lit!(10))))
+        .r#where(eq(id!(col), lit!(20)));
+
+    let sql = stmt.to_string();
+    assert_eq!(sql, "DELETE FROM my_table WHERE col = 20");
+}
+
+#[test]
+fn test_delete_returning() {
+    let stmt = delete_from(id!(my_table))
+        .returning(Asterisk)
+        .r#where(eq(id!(id), bind(10)));
+
+    let sql = stmt.to_string();
+    assert_eq!(sql, "DELETE FROM my_table WHERE id = ? RETURNING *");
+}
+
+#[test]
+fn test_delete_to_query_bind_order() {
+    let stmt = delete_from(id!(my_table))
+        .r#where(or(eq(id!(id), bind(10)), lt(id!(expires_at), bind(20))))
+        .returning(select_columns!(
+            id!(id),
+            add(id!(version), bind(1)).alias_column(id!(next))
+        ));
+
+    let (sql, args) = ToQuery::<Postgres>::to_query(&stmt).unwrap();
+    assert_eq!(
+        sql,
+        "DELETE FROM my_table WHERE id = $1 OR expires_at < $2 RETURNING id, version + $3 AS next"
+    );
+    assert_eq!(args.len(), 3);
+}
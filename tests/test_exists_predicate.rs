@@ -0,0 +1,47 @@
+use sql_builder::{and, bind, eq, exists, gt, id, not, prelude::*, select, unique};
+use sqlx::{Any, Arguments as _};
+
+#[test]
+fn test_exists_correlated() {
+    let stmt = select(id!(name)).from(id!(customers)).r#where(exists(
+        select(id!(id))
+            .from(id!(orders))
+            .r#where(eq(id!(orders.customer_id), id!(customers.id))),
+    ));
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT name FROM customers WHERE EXISTS (SELECT id FROM orders WHERE orders.customer_id = customers.id)"
+    );
+}
+
+#[test]
+fn test_not_exists() {
+    let pred = not(exists(select(id!(id)).from(id!(orders))));
+    assert_eq!(pred.to_string(), "NOT EXISTS (SELECT id FROM orders)");
+}
+
+#[test]
+fn test_unique() {
+    let pred = unique(select(id!(email)).from(id!(users)));
+    assert_eq!(pred.to_string(), "UNIQUE (SELECT email FROM users)");
+}
+
+#[test]
+fn test_exists_arguments_order() {
+    let stmt = select(id!(name)).from(id!(customers)).r#where(and(
+        exists(select(id!(id)).from(id!(orders)).r#where(and(
+            eq(id!(orders.customer_id), id!(customers.id)),
+            gt(id!(orders.total), bind(100)),
+        ))),
+        eq(id!(customers.active), bind(true)),
+    ));
+
+    let (sql, args) = ToQuery::<Any>::to_query(&stmt).unwrap();
+    assert_eq!(
+        sql,
+        "SELECT name FROM customers WHERE EXISTS (SELECT id FROM orders WHERE orders.customer_id = customers.id AND orders.total > ?) AND customers.active = ?"
+    );
+    assert_eq!(args.len(), 2);
+}
@@ -0,0 +1,51 @@
+use sql_builder::{
+    id, in_bound, in_list, in_subquery, in_values, lit, not_in, not_in_bound, not_in_subquery,
+    prelude::*, select,
+};
+use sqlx::Any;
+
+#[test]
+fn test_in_list() {
+    let pred = in_list(id!(status), in_values!(lit!("new"), lit!("pending")));
+    let sql = pred.to_string();
+    assert_eq!(sql, "status IN ('new', 'pending')");
+
+    let pred = not_in(id!(id), in_values!(lit!(1), lit!(2), lit!(3)));
+    let sql = pred.to_string();
+    assert_eq!(sql, "id NOT IN (1, 2, 3)");
+}
+
+#[test]
+fn test_in_subquery() {
+    let pred = in_subquery(id!(customer_id), select(id!(id)).from(id!(customers)));
+    let sql = pred.to_string();
+    assert_eq!(sql, "customer_id IN (SELECT id FROM customers)");
+
+    let pred = not_in_subquery(id!(customer_id), select(id!(id)).from(id!(customers)));
+    let sql = pred.to_string();
+    assert_eq!(sql, "customer_id NOT IN (SELECT id FROM customers)");
+}
+
+#[test]
+fn test_in_bound() {
+    let ids = vec![1, 2, 3];
+    let stmt = select(id!(name))
+        .from(id!(users))
+        .r#where(in_bound(id!(id), ids));
+
+    let (sql, _) = ToQuery::<Any>::to_query(&stmt).unwrap();
+    assert_eq!(sql, "SELECT name FROM users WHERE id IN (?, ?, ?)");
+
+    let names = ["alice", "bob"];
+    let pred = not_in_bound(id!(name), &names);
+    assert_eq!(pred.to_string(), "name NOT IN (?, ?)");
+}
+
+#[test]
+fn test_in_bound_empty() {
+    let pred = in_bound(id!(id), Vec::<i32>::new());
+    assert_eq!(pred.to_string(), "1 = 0");
+
+    let pred = not_in_bound(id!(id), Vec::<i32>::new());
+    assert_eq!(pred.to_string(), "1 = 1");
+}
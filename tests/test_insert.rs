@@ -1,4 +1,13 @@
-use sql_builder::{bind, columns, id, insert, lit, prelude::*, row_value};
+use sql_builder::{
+    asterisk::Asterisk,
+    bind, columns,
+    contextually_typed_row_value_expression_list::{BoundRow, BoundRowArguments},
+    default, eq, gt, id, insert, lit,
+    prelude::*,
+    row_value, select, select_columns, ToQueryContext,
+};
+use sqlx::{Any, Arguments as _};
+use std::fmt::Write as _;
 
 #[test]
 pub fn test_insert_simple() {
@@ -9,5 +18,237 @@ pub fn test_insert_simple() {
 
     let stmt = insert(table).columns(columns).values(values);
 
-    println!("{}", stmt);
+    assert_eq!(
+        stmt.to_string(),
+        "INSERT INTO my_table (col1, col2, col3) VALUES (10, 20, ?)"
+    );
+}
+
+#[test]
+fn test_insert_single_column() {
+    let stmt = insert(id!(audit))
+        .columns(columns!(id!(message)))
+        .values(row_value!(lit!("purge")));
+
+    assert_eq!(
+        stmt.to_string(),
+        "INSERT INTO audit (message) VALUES ('purge')"
+    );
+}
+
+#[test]
+fn test_insert_default_values() {
+    let stmt = insert(id!(users)).default_values();
+
+    assert_eq!(stmt.to_string(), "INSERT INTO users DEFAULT VALUES");
+}
+
+#[test]
+fn test_insert_default_in_row_value() {
+    let stmt = insert(id!(users))
+        .columns(columns!(id!(id), id!(name), id!(created_at)))
+        .values(row_value!(default(), bind("jdoe"), default()));
+
+    let (sql, args) = ToQuery::<Any>::to_query(&stmt).unwrap();
+    assert_eq!(
+        sql,
+        "INSERT INTO users (id, name, created_at) VALUES (DEFAULT, ?, DEFAULT)"
+    );
+    assert_eq!(args.len(), 1);
+}
+
+#[test]
+fn test_insert_select_with_columns() {
+    let stmt = insert(id!(archive))
+        .columns(columns!(id!(id), id!(name)))
+        .select(select(select_columns!(id!(id), id!(name))).from(id!(users)));
+
+    assert_eq!(
+        stmt.to_string(),
+        "INSERT INTO archive (id, name) SELECT id, name FROM users"
+    );
+}
+
+#[test]
+fn test_insert_select_without_columns() {
+    let stmt = insert(id!(archive)).select(select(Asterisk).from(id!(users)));
+
+    assert_eq!(stmt.to_string(), "INSERT INTO archive SELECT * FROM users");
+}
+
+#[test]
+fn test_insert_select_arguments() {
+    let stmt = insert(id!(archive)).columns(columns!(id!(id))).select(
+        select(id!(id))
+            .from(id!(users))
+            .r#where(gt(id!(age), bind(18)))
+            .union(
+                select(id!(id))
+                    .from(id!(admins))
+                    .r#where(eq(id!(level), bind(2))),
+            ),
+    );
+
+    let (sql, args) = ToQuery::<Any>::to_query(&stmt).unwrap();
+    assert_eq!(
+        sql,
+        "INSERT INTO archive (id) SELECT id FROM users WHERE age > ? UNION SELECT id FROM admins WHERE level = ?"
+    );
+    assert_eq!(args.len(), 2);
+}
+
+struct User {
+    name: String,
+    age: i32,
+}
+
+impl BoundRow for User {
+    const LEN: usize = 2;
+}
+
+impl<'q> BoundRowArguments<'q, Any> for User {
+    fn write_arguments(&'q self, ctx: &mut ToQueryContext<'q, Any>) -> std::fmt::Result {
+        ctx.write_argument(&self.name)?;
+        write!(ctx, ", ")?;
+        ctx.write_argument(&self.age)
+    }
+}
+
+#[test]
+fn test_insert_values_from_iter_tuples() {
+    let rows = vec![("jdoe", 32), ("asmith", 27), ("bwayne", 41)];
+
+    let stmt = insert(id!(users))
+        .columns(columns!(id!(name), id!(age)))
+        .values_from_iter(rows)
+        .unwrap();
+
+    let (sql, args) = ToQuery::<Any>::to_query(&stmt).unwrap();
+    assert_eq!(
+        sql,
+        "INSERT INTO users (name, age) VALUES (?, ?), (?, ?), (?, ?)"
+    );
+    assert_eq!(args.len(), 6);
+}
+
+#[test]
+fn test_insert_values_from_iter_structs() {
+    let users = (0..2).map(|i| User {
+        name: format!("user{i}"),
+        age: 20 + i,
+    });
+
+    let stmt = insert(id!(users))
+        .columns(columns!(id!(name), id!(age)))
+        .values_from_iter(users)
+        .unwrap();
+
+    let (sql, args) = ToQuery::<Any>::to_query(&stmt).unwrap();
+    assert_eq!(sql, "INSERT INTO users (name, age) VALUES (?, ?), (?, ?)");
+    assert_eq!(args.len(), 4);
+}
+
+#[test]
+fn test_insert_values_from_iter_batches() {
+    let stmts = insert(id!(points))
+        .columns(columns!(id!(x), id!(y), id!(z)))
+        .values_from_iter((0..5).map(|i| (i, i * 2, i * 3)))
+        .unwrap()
+        .batches(7)
+        .unwrap();
+
+    assert_eq!(stmts.len(), 3);
+    assert_eq!(
+        stmts[0].to_string(),
+        "INSERT INTO points (x, y, z) VALUES (?, ?, ?), (?, ?, ?)"
+    );
+    assert_eq!(
+        stmts[2].to_string(),
+        "INSERT INTO points (x, y, z) VALUES (?, ?, ?)"
+    );
+
+    let args = stmts
+        .iter()
+        .map(|stmt| ToQuery::<Any>::to_query(stmt).unwrap().1.len())
+        .collect::<Vec<_>>();
+    assert_eq!(args, vec![6, 6, 3]);
+}
+
+#[test]
+fn test_insert_values_from_iter_batches_at_limit() {
+    let stmts = insert(id!(points))
+        .columns(columns!(id!(x), id!(y), id!(z)))
+        .values_from_iter((0..4).map(|i| (i, i * 2, i * 3)))
+        .unwrap()
+        .batches(6)
+        .unwrap();
+
+    let stmts = stmts
+        .into_iter()
+        .map(|stmt| stmt.returning(id!(id)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        stmts[1].to_string(),
+        "INSERT INTO points (x, y, z) VALUES (?, ?, ?), (?, ?, ?) RETURNING id"
+    );
+
+    let args = stmts
+        .iter()
+        .map(|stmt| ToQuery::<Any>::to_query(stmt).unwrap().1.len())
+        .collect::<Vec<_>>();
+    assert_eq!(args, vec![6, 6]);
+}
+
+#[test]
+fn test_insert_values_from_iter_batches_for_database() {
+    let stmts = insert(id!(points))
+        .columns(columns!(id!(x), id!(y), id!(z)))
+        .values_from_iter((0..11000).map(|i| (i, i * 2, i * 3)))
+        .unwrap()
+        .batches_for::<Any>()
+        .unwrap();
+
+    let args = stmts
+        .iter()
+        .map(|stmt| ToQuery::<Any>::to_query(stmt).unwrap().1.len())
+        .collect::<Vec<_>>();
+    assert_eq!(args, vec![32766, 234]);
+}
+
+#[test]
+fn test_insert_values_from_iter_empty() {
+    let stmt = insert(id!(points))
+        .columns(columns!(id!(x)))
+        .values_from_iter(Vec::<(i32,)>::new());
+
+    assert!(stmt.is_none());
+}
+
+#[test]
+fn test_insert_values_from_iter_row_wider_than_limit() {
+    let stmts = insert(id!(points))
+        .columns(columns!(id!(x), id!(y), id!(z)))
+        .values_from_iter((0..5).map(|i| (i, i * 2, i * 3)))
+        .unwrap()
+        .batches(2);
+
+    assert!(stmts.is_err());
+}
+
+#[test]
+fn test_insert_returning() {
+    let stmt = insert(id!(users))
+        .columns(columns!(id!(name)))
+        .values(row_value!(bind("jdoe")))
+        .returning(id!(id));
+
+    let (sql, args) = ToQuery::<Any>::to_query(&stmt).unwrap();
+    assert_eq!(sql, "INSERT INTO users (name) VALUES (?) RETURNING id");
+    assert_eq!(args.len(), 1);
+
+    let stmt = insert(id!(users)).default_values().returning(Asterisk);
+    assert_eq!(
+        stmt.to_string(),
+        "INSERT INTO users DEFAULT VALUES RETURNING *"
+    );
 }
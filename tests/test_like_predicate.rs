@@ -0,0 +1,42 @@
+use sql_builder::{
+    and, id, like, lit, not, not_like, not_similar_to, or, prelude::*, select, similar_to,
+};
+
+#[test]
+fn test_like() {
+    let pred = like(id!(name), lit!("John%"));
+    let sql = pred.to_string();
+    assert_eq!(sql, "name LIKE 'John%'");
+
+    let pred = not_like(id!(name), lit!("100!%")).escape(lit!("!"));
+    let sql = pred.to_string();
+    assert_eq!(sql, "name NOT LIKE '100!%' ESCAPE '!'");
+}
+
+#[test]
+fn test_similar_to() {
+    let pred = similar_to(id!(code), lit!("[A-Z]{3}"));
+    let sql = pred.to_string();
+    assert_eq!(sql, "code SIMILAR TO '[A-Z]{3}'");
+
+    let pred = not_similar_to(id!(code), lit!("%#_%")).escape(lit!("#"));
+    let sql = pred.to_string();
+    assert_eq!(sql, "code NOT SIMILAR TO '%#_%' ESCAPE '#'");
+}
+
+#[test]
+fn test_pattern_predicates_composition() {
+    let stmt = select(id!(id)).from(id!(users)).r#where(or(
+        and(
+            like(id!(name), lit!("A%")),
+            not(like(id!(name), lit!("Ab%"))),
+        ),
+        similar_to(id!(email), lit!("%@example.(com|org)")),
+    ));
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT id FROM users WHERE name LIKE 'A%' AND NOT name LIKE 'Ab%' OR email SIMILAR TO '%@example.(com|org)'"
+    );
+}
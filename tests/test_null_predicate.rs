@@ -0,0 +1,52 @@
+use sql_builder::{
+    and, bind, eq, id, is_distinct_from, is_not_distinct_from, is_not_null, is_null, lit,
+    prelude::*, select,
+};
+
+#[test]
+fn test_is_null() {
+    let pred = is_null(id!(deleted_at));
+    assert_eq!(pred.to_string(), "deleted_at IS NULL");
+
+    let pred = is_not_null(id!(users.email));
+    assert_eq!(pred.to_string(), "users.email IS NOT NULL");
+}
+
+#[test]
+fn test_is_null_helpers() {
+    let stmt = select(id!(id))
+        .from(id!(users))
+        .r#where(and(id!(deleted_at).is_null(), id!(email).is_not_null()));
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT id FROM users WHERE deleted_at IS NULL AND email IS NOT NULL"
+    );
+}
+
+#[test]
+fn test_is_distinct_from() {
+    let pred = is_distinct_from(id!(a), bind(10));
+    assert_eq!(pred.to_string(), "a IS DISTINCT FROM ?");
+
+    let pred = is_not_distinct_from(id!(a), lit!(10));
+    assert_eq!(pred.to_string(), "a IS NOT DISTINCT FROM 10");
+
+    let pred = id!(a).is_not_distinct_from(id!(b));
+    assert_eq!(pred.to_string(), "a IS NOT DISTINCT FROM b");
+}
+
+#[test]
+fn test_row_subquery_is_null() {
+    let pred = select(id!(deleted_at))
+        .from(id!(users))
+        .r#where(eq(id!(id), bind(1)))
+        .subquery()
+        .is_null();
+
+    assert_eq!(
+        pred.to_string(),
+        "(SELECT deleted_at FROM users WHERE id = ?) IS NULL"
+    );
+}
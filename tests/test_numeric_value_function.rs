@@ -0,0 +1,58 @@
+use sql_builder::{
+    abs, add, bind, ceiling, div, exp, floor, gt, id, lit, ln, mult, neg, power, prelude::*, r#mod,
+    select, select_columns, sqrt, sub, width_bucket,
+};
+use sqlx::{Any, Arguments as _};
+
+#[test]
+fn test_single_argument_functions() {
+    assert_eq!(abs(id!(delta)).to_string(), "ABS(delta)");
+    assert_eq!(ceiling(id!(price)).to_string(), "CEILING(price)");
+    assert_eq!(floor(id!(price)).to_string(), "FLOOR(price)");
+    assert_eq!(sqrt(id!(area)).to_string(), "SQRT(area)");
+    assert_eq!(ln(id!(growth)).to_string(), "LN(growth)");
+    assert_eq!(exp(id!(rate)).to_string(), "EXP(rate)");
+}
+
+#[test]
+fn test_multiple_arguments_functions() {
+    assert_eq!(r#mod(id!(id), lit!(2)).to_string(), "MOD(id, 2)");
+    assert_eq!(power(id!(side), lit!(3)).to_string(), "POWER(side, 3)");
+    assert_eq!(
+        width_bucket(id!(age), lit!(0), lit!(100), lit!(10)).to_string(),
+        "WIDTH_BUCKET(age, 0, 100, 10)"
+    );
+}
+
+#[test]
+fn test_functions_compose_with_arithmetic() {
+    assert_eq!(
+        abs(sub(id!(expected), id!(actual))).to_string(),
+        "ABS(expected - actual)"
+    );
+    assert_eq!(
+        add(mult(neg(id!(price)), abs(id!(quantity))), sqrt(id!(fee))).to_string(),
+        "-price * ABS(quantity) + SQRT(fee)"
+    );
+    assert_eq!(
+        div(floor(mult(id!(total), lit!(100))), lit!(100)).to_string(),
+        "FLOOR(total * 100) / 100"
+    );
+}
+
+#[test]
+fn test_functions_in_select() {
+    let stmt = select(select_columns!(
+        id!(id),
+        power(id!(side), bind(2)).alias_column(id!(area))
+    ))
+    .from(id!(squares))
+    .r#where(gt(abs(neg(id!(offset))), bind(10)));
+
+    let (sql, args) = ToQuery::<Any>::to_query(&stmt).unwrap();
+    assert_eq!(
+        sql,
+        "SELECT id, POWER(side, ?) AS area FROM squares WHERE ABS(-offset) > ?"
+    );
+    assert_eq!(args.len(), 2);
+}
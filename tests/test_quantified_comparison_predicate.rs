@@ -0,0 +1,47 @@
+use sql_builder::{
+    bind, comparison_predicate::ComparisonKind, eq, eq_any, gt_all, id, lt_any, prelude::*,
+    quantified, quantified_comparison_predicate::Quantifier, select,
+};
+
+#[test]
+fn test_gt_all() {
+    let pred = gt_all(
+        id!(price),
+        select(id!(price)).from(id!(discounted_products)),
+    );
+
+    let sql = pred.to_string();
+    assert_eq!(sql, "price > ALL (SELECT price FROM discounted_products)");
+}
+
+#[test]
+fn test_eq_any() {
+    let stmt = select(id!(id)).from(id!(orders)).r#where(eq_any(
+        id!(customer_id),
+        select(id!(id))
+            .from(id!(customers))
+            .r#where(eq(id!(country), bind("FR"))),
+    ));
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT id FROM orders WHERE customer_id = ANY (SELECT id FROM customers WHERE country = ?)"
+    );
+
+    let pred = lt_any(id!(a), select(id!(b)).from(id!(t)));
+    assert_eq!(pred.to_string(), "a < ANY (SELECT b FROM t)");
+}
+
+#[test]
+fn test_quantified() {
+    let pred = quantified(
+        id!(a),
+        ComparisonKind::NotEquals,
+        Quantifier::Some,
+        select(id!(b)).from(id!(t)),
+    );
+
+    let sql = pred.to_string();
+    assert_eq!(sql, "a <> SOME (SELECT b FROM t)");
+}
@@ -0,0 +1,242 @@
+use sql_builder::{
+    and, bind, blank::Blank, columns, delete_from, eq, gt, id, insert, lit, lt, prelude::*,
+    row_value, select, select_columns, update, with, with_recursive,
+};
+
+#[test]
+fn test_union() {
+    let stmt = select(id!(col1))
+        .from(id!(table1))
+        .union(select(id!(col1)).from(id!(table2)))
+        .union_all(select(id!(col1)).from(id!(table3)));
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT col1 FROM table1 UNION SELECT col1 FROM table2 UNION ALL SELECT col1 FROM table3"
+    );
+}
+
+#[test]
+fn test_except_distinct_corresponding() {
+    let stmt = select(id!(col1))
+        .from(id!(table1))
+        .except(select(id!(col1)).from(id!(table2)))
+        .distinct()
+        .corresponding_by(columns!(id!(col1), id!(col2)));
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT col1 FROM table1 EXCEPT DISTINCT CORRESPONDING BY (col1, col2) SELECT col1 FROM table2"
+    );
+}
+
+#[test]
+fn test_intersect_precedence() {
+    // INTERSECT binds tighter than UNION, no parenthesis required.
+    let stmt = select(id!(col1)).from(id!(table1)).union(
+        select(id!(col1))
+            .from(id!(table2))
+            .intersect_all(select(id!(col1)).from(id!(table3)))
+            .corresponding(),
+    );
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT col1 FROM table1 UNION SELECT col1 FROM table2 INTERSECT ALL CORRESPONDING SELECT col1 FROM table3"
+    );
+
+    // An UNION operand of INTERSECT is parenthesized.
+    let stmt = select(id!(col1)).from(id!(table1)).intersect(
+        select(id!(col1))
+            .from(id!(table2))
+            .union(select(id!(col1)).from(id!(table3))),
+    );
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT col1 FROM table1 INTERSECT (SELECT col1 FROM table2 UNION SELECT col1 FROM table3)"
+    );
+}
+
+#[test]
+fn test_set_operation_with_ordered_operand() {
+    let stmt = select(id!(col1))
+        .from(id!(table1))
+        .order_by(id!(col1))
+        .fetch_first(lit!(10))
+        .union(
+            select(id!(col1))
+                .from(id!(table2))
+                .r#where(eq(id!(col2), lit!(1))),
+        );
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "(SELECT col1 FROM table1 ORDER BY col1 FETCH FIRST 10 ROWS ONLY) UNION SELECT col1 FROM table2 WHERE col2 = 1"
+    );
+}
+
+#[test]
+fn test_set_operation_order_by() {
+    let stmt = select(id!(col1))
+        .from(id!(table1))
+        .union(select(id!(col1)).from(id!(table2)))
+        .order_by(id!(col1).desc())
+        .limit(lit!(10));
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT col1 FROM table1 UNION SELECT col1 FROM table2 ORDER BY col1 DESC LIMIT 10"
+    );
+}
+
+#[test]
+fn test_with_recursive() {
+    let root_id = 1;
+
+    let stmt = with_recursive(
+        id!(tree),
+        columns!(id!(id), id!(parent_id)),
+        select(select_columns!(id!(id), id!(parent_id)))
+            .from(id!(nodes))
+            .r#where(eq(id!(id), bind!(root_id)))
+            .union_all(
+                select(select_columns!(id!(nodes.id), id!(nodes.parent_id))).from(
+                    id!(nodes)
+                        .inner_join(id!(tree))
+                        .on(eq(id!(nodes.parent_id), id!(tree.id))),
+                ),
+            ),
+    )
+    .query(select(id!(id)).from(id!(tree)));
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "WITH RECURSIVE tree (id, parent_id) AS (\
+            SELECT id, parent_id FROM nodes WHERE id = ? \
+            UNION ALL \
+            SELECT nodes.id, nodes.parent_id FROM nodes INNER JOIN tree ON nodes.parent_id = tree.id\
+        ) SELECT id FROM tree"
+    );
+}
+
+#[test]
+fn test_with_multiple_elements() {
+    let stmt = with(id!(a), Blank, select(id!(col1)).from(id!(table1)))
+        .with(id!(b), Blank, select(id!(col1)).from(id!(a)))
+        .query(
+            select(id!(col1))
+                .from(id!(b))
+                .order_by(id!(col1))
+                .union(select(id!(col1)).from(id!(a))),
+        );
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "WITH a AS (SELECT col1 FROM table1), b AS (SELECT col1 FROM a) \
+        (SELECT col1 FROM b ORDER BY col1) UNION SELECT col1 FROM a"
+    );
+}
+
+#[test]
+fn test_with_data_change_statements() {
+    let cte = with(
+        id!(expired),
+        Blank,
+        select(id!(id))
+            .from(id!(sessions))
+            .r#where(lt(id!(expires_at), lit!(100))),
+    );
+
+    let stmt = cte.delete(delete_from(id!(tokens)).r#where(eq(id!(session_id), lit!(1))));
+    assert_eq!(
+        stmt.to_string(),
+        "WITH expired AS (SELECT id FROM sessions WHERE expires_at < 100) DELETE FROM tokens WHERE session_id = 1"
+    );
+
+    let stmt = cte.update(update(id!(sessions)).set(id!(active), lit!(0)));
+    assert_eq!(
+        stmt.to_string(),
+        "WITH expired AS (SELECT id FROM sessions WHERE expires_at < 100) UPDATE sessions SET active = 0"
+    );
+
+    let stmt = cte.insert(
+        insert(id!(audit))
+            .columns(id!(message))
+            .values(row_value!(lit!("purge"))),
+    );
+    assert_eq!(
+        stmt.to_string(),
+        "WITH expired AS (SELECT id FROM sessions WHERE expires_at < 100) INSERT INTO audit (message) VALUES ('purge')"
+    );
+}
+
+#[test]
+fn test_with_chained_common_table_expressions() {
+    let stmt = with(id!(a), Blank, select(id!(x)).from(id!(t1)))
+        .with(id!(b), Blank, select(id!(x)).from(id!(a)))
+        .query(select(id!(x)).from(id!(b)));
+    assert_eq!(
+        stmt.to_string(),
+        "WITH a AS (SELECT x FROM t1), b AS (SELECT x FROM a) SELECT x FROM b"
+    );
+
+    let stmt = with(id!(a), Blank, select(id!(x)).from(id!(t1)))
+        .with(id!(b), Blank, select(id!(x)).from(id!(t2)))
+        .delete(delete_from(id!(tokens)).r#where(eq(id!(x), lit!(1))));
+    assert_eq!(
+        stmt.to_string(),
+        "WITH a AS (SELECT x FROM t1), b AS (SELECT x FROM t2) DELETE FROM tokens WHERE x = 1"
+    );
+}
+
+#[test]
+fn test_scalar_subquery() {
+    let max_price = select(id!(price)).from(id!(products)).subquery();
+    let stmt = select(select_columns!(id!(id), max_price)).from(id!(orders));
+
+    let sql = stmt.to_string();
+    assert_eq!(sql, "SELECT id, (SELECT price FROM products) FROM orders");
+}
+
+#[test]
+fn test_derived_table() {
+    let stmt = select(select_columns!(id!(a), id!(b))).from(
+        select(select_columns!(id!(col1), id!(col2)))
+            .from(id!(table1))
+            .alias_table(id!(t))
+            .columns(columns!(id!(a), id!(b))),
+    );
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT a, b FROM (SELECT col1, col2 FROM table1) AS t (a, b)"
+    );
+}
+
+#[test]
+fn test_subquery_as_predicate_operand() {
+    let threshold = select(id!(price))
+        .from(id!(products))
+        .r#where(eq(id!(id), bind(10)))
+        .subquery();
+
+    let stmt = select(id!(id))
+        .from(id!(orders))
+        .r#where(and(gt(id!(total), threshold), lt(id!(total), bind(100))));
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT id FROM orders WHERE total > (SELECT price FROM products WHERE id = ?) AND total < ?"
+    );
+}
@@ -1,5 +1,9 @@
-use sql_builder::{eq, id, lit, or, select, select_columns};
+use sql_builder::{
+    bind, eq, grouping_columns, grouping_sets, gt, id, invoke, lit, or, select, select_columns,
+    EmptyGroupingSet,
+};
 use sql_builder::{lt, prelude::*};
+use sqlx::{Any, Arguments as _};
 
 #[test]
 fn test_select_basic() {
@@ -9,7 +13,7 @@ fn test_select_basic() {
         id!(col3)
     );
 
-    let table = id!(table);
+    let table = id!(my_table);
     let stmt = select(selected_columns).from(table);
 
     let sql = stmt.to_string();
@@ -39,3 +43,188 @@ fn test_select_distinct() {
     let sql = stmt.to_string();
     assert_eq!(sql, "SELECT DISTINCT col1 FROM my_table");
 }
+
+#[test]
+fn test_select_group_by() {
+    let stmt = select(select_columns!(id!(col1), id!(col2)))
+        .from(id!(my_table))
+        .r#where(eq(id!(col3), lit!(10)))
+        .group_by(id!(col1).add_grouping_element(id!(col2)));
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT col1, col2 FROM my_table WHERE col3 = 10 GROUP BY col1, col2"
+    );
+}
+
+#[test]
+fn test_select_group_by_grouping_sets() {
+    let stmt = select(select_columns!(id!(col1), id!(col2)))
+        .from(id!(my_table))
+        .group_by(grouping_sets(
+            grouping_columns(id!(col1).add_grouping_column(id!(col2)))
+                .add_grouping_element(id!(col1))
+                .add_grouping_element(EmptyGroupingSet),
+        ));
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT col1, col2 FROM my_table GROUP BY GROUPING SETS((col1, col2), col1, ())"
+    );
+}
+
+#[test]
+fn test_select_group_by_having() {
+    let stmt = select(select_columns!(id!(col1), invoke!(COUNT(id!(col2)))))
+        .from(id!(my_table))
+        .having(gt(invoke!(COUNT(id!(col2))), lit!(1)))
+        .r#where(eq(id!(col3), lit!(10)))
+        .group_by(id!(col1));
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT col1, COUNT(col2) FROM my_table WHERE col3 = 10 GROUP BY col1 HAVING COUNT(col2) > 1"
+    );
+}
+
+#[test]
+fn test_select_order_by() {
+    let stmt = select(select_columns!(id!(col1), id!(col2)))
+        .from(id!(my_table))
+        .r#where(eq(id!(col3), lit!(10)))
+        .order_by(
+            id!(col1)
+                .desc()
+                .nulls_last()
+                .add_sort_specification(id!(col2).asc()),
+        );
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT col1, col2 FROM my_table WHERE col3 = 10 ORDER BY col1 DESC NULLS LAST, col2 ASC"
+    );
+}
+
+#[test]
+fn test_select_order_by_alias_with_collation() {
+    let stmt = select(id!(col1).alias_column(id!(name)))
+        .from(id!(my_table))
+        .order_by(id!(name).collate(id!(fr_FR)).nulls_first());
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT col1 AS name FROM my_table ORDER BY name COLLATE fr_FR NULLS FIRST"
+    );
+}
+
+#[test]
+fn test_select_order_by_replaces_previous_one() {
+    let stmt = select(id!(col1))
+        .from(id!(my_table))
+        .order_by(id!(col1))
+        .order_by(id!(col1).desc());
+
+    let sql = stmt.to_string();
+    assert_eq!(sql, "SELECT col1 FROM my_table ORDER BY col1 DESC");
+}
+
+#[test]
+fn test_select_offset_fetch_first() {
+    let page = 20u64;
+    let page_size = 10u64;
+
+    let stmt = select(id!(col1))
+        .from(id!(my_table))
+        .order_by(id!(col1))
+        .offset(bind!(page))
+        .fetch_first(bind!(page_size));
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT col1 FROM my_table ORDER BY col1 OFFSET ? ROWS FETCH FIRST ? ROWS ONLY"
+    );
+}
+
+#[test]
+fn test_select_fetch_first_with_ties() {
+    let stmt = select(id!(col1))
+        .from(id!(my_table))
+        .order_by(id!(col1).desc())
+        .fetch_first_with_ties(lit!(3));
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT col1 FROM my_table ORDER BY col1 DESC FETCH FIRST 3 ROWS WITH TIES"
+    );
+}
+
+#[test]
+fn test_select_limit_offset() {
+    let stmt = select(id!(col1))
+        .from(id!(my_table))
+        .offset(lit!(5))
+        .limit_offset(lit!(10), lit!(20));
+
+    let sql = stmt.to_string();
+    assert_eq!(sql, "SELECT col1 FROM my_table LIMIT 10 OFFSET 20");
+
+    let stmt = select(id!(col1)).from(id!(my_table)).limit(lit!(10));
+
+    let sql = stmt.to_string();
+    assert_eq!(sql, "SELECT col1 FROM my_table LIMIT 10");
+}
+
+#[test]
+fn test_select_offset_then_limit() {
+    let stmt = select(id!(col1))
+        .from(id!(my_table))
+        .order_by(id!(col1))
+        .offset(lit!(10))
+        .limit(lit!(5));
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT col1 FROM my_table ORDER BY col1 LIMIT 5 OFFSET 10"
+    );
+
+    let stmt = select(id!(col1))
+        .from(id!(my_table))
+        .offset(bind(10))
+        .limit(bind(5));
+
+    let (sql, args) = ToQuery::<Any>::to_query(&stmt).unwrap();
+    assert_eq!(sql, "SELECT col1 FROM my_table LIMIT ? OFFSET ?");
+    assert_eq!(args.len(), 2);
+}
+
+#[test]
+fn test_select_limit_then_offset() {
+    let stmt = select(id!(col1))
+        .from(id!(my_table))
+        .order_by(id!(col1))
+        .limit(lit!(10))
+        .offset(lit!(5));
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "SELECT col1 FROM my_table ORDER BY col1 LIMIT 10 OFFSET 5"
+    );
+
+    let stmt = select(id!(col1))
+        .from(id!(my_table))
+        .limit_offset(lit!(10), lit!(20))
+        .offset(bind(5));
+
+    let (sql, args) = ToQuery::<Any>::to_query(&stmt).unwrap();
+    assert_eq!(sql, "SELECT col1 FROM my_table LIMIT 10 OFFSET ?");
+    assert_eq!(args.len(), 1);
+}
@@ -0,0 +1,77 @@
+use sql_builder::{
+    and, bind, blank::Blank, char_length, concat, eq, gt, id, lit, lower, octet_length, overlay,
+    position, prelude::*, select, select_columns, substring, trim,
+    trim_function::TrimSpecification, upper,
+};
+use sqlx::{Any, Arguments as _};
+
+#[test]
+fn test_concatenation() {
+    assert_eq!(
+        concat(concat(id!(first_name), lit!(" ")), id!(last_name)).to_string(),
+        "first_name || ' ' || last_name"
+    );
+}
+
+#[test]
+fn test_character_value_functions() {
+    assert_eq!(
+        substring(id!(code), lit!(1), lit!(3)).to_string(),
+        "SUBSTRING(code FROM 1 FOR 3)"
+    );
+    assert_eq!(
+        substring(id!(code), lit!(4), Blank).to_string(),
+        "SUBSTRING(code FROM 4)"
+    );
+    assert_eq!(upper(id!(name)).to_string(), "UPPER(name)");
+    assert_eq!(lower(id!(name)).to_string(), "LOWER(name)");
+    assert_eq!(
+        trim(TrimSpecification::Leading, lit!("0"), id!(code)).to_string(),
+        "TRIM(LEADING '0' FROM code)"
+    );
+    assert_eq!(
+        trim(TrimSpecification::Both, Blank, id!(name)).to_string(),
+        "TRIM(BOTH FROM name)"
+    );
+    assert_eq!(
+        overlay(id!(phone), lit!("***"), lit!(4), Blank).to_string(),
+        "OVERLAY(phone PLACING '***' FROM 4)"
+    );
+    assert_eq!(
+        overlay(id!(phone), lit!("*"), lit!(4), lit!(3)).to_string(),
+        "OVERLAY(phone PLACING '*' FROM 4 FOR 3)"
+    );
+}
+
+#[test]
+fn test_numeric_string_functions() {
+    assert_eq!(char_length(id!(name)).to_string(), "CHAR_LENGTH(name)");
+    assert_eq!(octet_length(id!(name)).to_string(), "OCTET_LENGTH(name)");
+    assert_eq!(
+        position(lit!("@"), id!(email)).to_string(),
+        "POSITION('@' IN email)"
+    );
+}
+
+#[test]
+fn test_string_functions_compose() {
+    let stmt = select(select_columns!(
+        upper(concat(id!(first_name), id!(last_name))).alias_column(id!(full_name)),
+        substring(id!(email), position(lit!("@"), id!(email)), Blank)
+    ))
+    .from(id!(users))
+    .r#where(and(
+        gt(
+            char_length(trim(TrimSpecification::Both, Blank, id!(name))),
+            lit!(2),
+        ),
+        eq(lower(id!(email)), bind("a@b.c")),
+    ));
+
+    let (sql, args) = ToQuery::<Any>::to_query(&stmt).unwrap();
+    assert_eq!(
+        sql,
+        "SELECT UPPER(first_name || last_name) AS full_name, SUBSTRING(email FROM POSITION('@' IN email)) FROM users WHERE CHAR_LENGTH(TRIM(BOTH FROM name)) > 2 AND LOWER(email) = ?"
+    );
+    assert_eq!(args.len(), 1);
+}
@@ -0,0 +1,76 @@
+use sql_builder::{add, and, bind, default, eq, gt, id, lit, prelude::*, select_columns, update};
+use sqlx::{Arguments as _, Postgres};
+
+#[test]
+fn test_update_simple() {
+    let stmt = update(id!(my_table))
+        .set(id!(col1), lit!(10))
+        .set(id!(col2), bind(20));
+
+    let sql = stmt.to_string();
+    assert_eq!(sql, "UPDATE my_table SET col1 = 10, col2 = ?");
+}
+
+#[test]
+fn test_update_where() {
+    let stmt = update(id!(my_table))
+        .set(id!(col1), lit!("value"))
+        .r#where(eq(id!(id), bind(1)));
+
+    let sql = stmt.to_string();
+    assert_eq!(sql, "UPDATE my_table SET col1 = 'value' WHERE id = ?");
+}
+
+#[test]
+fn test_update_transform_if() {
+    let stmt = update(id!(my_table))
+        .set(id!(col1), lit!(10))
+        .transform_if(true, |stmt| stmt.set(id!(col2), lit!(20)))
+        .r#where(eq(id!(id), lit!(1)));
+
+    let sql = stmt.to_string();
+    assert_eq!(sql, "UPDATE my_table SET col1 = 10, col2 = 20 WHERE id = 1");
+}
+
+#[test]
+fn test_update_set_default() {
+    let stmt = update(id!(my_table))
+        .set(id!(col1), default())
+        .r#where(eq(id!(id), bind(1)));
+
+    let sql = stmt.to_string();
+    assert_eq!(sql, "UPDATE my_table SET col1 = DEFAULT WHERE id = ?");
+}
+
+#[test]
+fn test_update_returning() {
+    let stmt = update(id!(my_table))
+        .set(id!(col1), lit!(10))
+        .r#where(eq(id!(id), bind(1)))
+        .returning(select_columns!(id!(id), id!(col1)));
+
+    let sql = stmt.to_string();
+    assert_eq!(
+        sql,
+        "UPDATE my_table SET col1 = 10 WHERE id = ? RETURNING id, col1"
+    );
+}
+
+#[test]
+fn test_update_to_query_bind_order() {
+    let stmt = update(id!(my_table))
+        .set(id!(col1), bind(10))
+        .set(id!(col2), bind("value"))
+        .r#where(and(eq(id!(id), bind(1)), gt(id!(version), bind(3))))
+        .returning(select_columns!(
+            id!(id),
+            add(id!(col1), bind(100)).alias_column(id!(next))
+        ));
+
+    let (sql, args) = ToQuery::<Postgres>::to_query(&stmt).unwrap();
+    assert_eq!(
+        sql,
+        "UPDATE my_table SET col1 = $1, col2 = $2 WHERE id = $3 AND version > $4 RETURNING id, col1 + $5 AS next"
+    );
+    assert_eq!(args.len(), 5);
+}
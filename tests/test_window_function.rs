@@ -0,0 +1,147 @@
+use sql_builder::{
+    bind, define_window, dense_rank, id, lag, lead, lit,
+    prelude::*,
+    rank, row_number, select, select_columns, sum, window,
+    window_frame_clause::{WindowFrameBound, WindowFrameStart},
+};
+use sqlx::{Any, Arguments as _};
+
+#[test]
+fn test_rank_functions_over_window() {
+    let spec = window()
+        .partition_by(id!(dept).add_partition_column(id!(team)))
+        .order_by(id!(salary).desc());
+
+    assert_eq!(
+        row_number().over(spec).to_string(),
+        "ROW_NUMBER() OVER (PARTITION BY dept, team ORDER BY salary DESC)"
+    );
+    assert_eq!(rank().over(window()).to_string(), "RANK() OVER ()");
+    assert_eq!(dense_rank().over(id!(w)).to_string(), "DENSE_RANK() OVER w");
+}
+
+#[test]
+fn test_lead_and_lag() {
+    let spec = window().order_by(id!(day));
+
+    assert_eq!(
+        lag(id!(price)).over(spec).to_string(),
+        "LAG(price) OVER (ORDER BY day)"
+    );
+    assert_eq!(
+        lead(id!(price)).offset(2).over(spec).to_string(),
+        "LEAD(price, 2) OVER (ORDER BY day)"
+    );
+    assert_eq!(
+        lead(id!(price))
+            .default_value(lit!(0))
+            .over(spec)
+            .to_string(),
+        "LEAD(price, 1, 0) OVER (ORDER BY day)"
+    );
+}
+
+#[test]
+fn test_aggregate_over_frame() {
+    let expr = sum(id!(amount)).over(
+        window()
+            .partition_by(id!(account))
+            .order_by(id!(day))
+            .rows_between(
+                WindowFrameBound::UnboundedPreceding,
+                WindowFrameBound::CurrentRow,
+            )
+            .unwrap(),
+    );
+
+    assert_eq!(
+        expr.to_string(),
+        "SUM(amount) OVER (PARTITION BY account ORDER BY day ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)"
+    );
+
+    let expr = sum(id!(amount)).over(
+        window()
+            .order_by(id!(day))
+            .range(WindowFrameStart::Preceding(3)),
+    );
+    assert_eq!(
+        expr.to_string(),
+        "SUM(amount) OVER (ORDER BY day RANGE 3 PRECEDING)"
+    );
+
+    let expr = sum(id!(amount)).over(
+        window()
+            .order_by(id!(day))
+            .rows_between(WindowFrameBound::CurrentRow, WindowFrameBound::Following(3))
+            .unwrap(),
+    );
+    assert_eq!(
+        expr.to_string(),
+        "SUM(amount) OVER (ORDER BY day ROWS BETWEEN CURRENT ROW AND 3 FOLLOWING)"
+    );
+}
+
+#[test]
+fn test_window_frame_bounds_out_of_order() {
+    assert!(window()
+        .rows_between(
+            WindowFrameBound::UnboundedFollowing,
+            WindowFrameBound::CurrentRow
+        )
+        .is_err());
+    assert!(window()
+        .rows_between(
+            WindowFrameBound::CurrentRow,
+            WindowFrameBound::UnboundedPreceding
+        )
+        .is_err());
+    assert!(window()
+        .range_between(
+            WindowFrameBound::Following(1),
+            WindowFrameBound::Preceding(1)
+        )
+        .is_err());
+}
+
+#[test]
+fn test_select_with_window_clause() {
+    let stmt = select(select_columns!(
+        id!(name),
+        rank().over(id!(w)).alias_column(id!(position)),
+        lag(id!(salary)).over(
+            window()
+                .existing(id!(w))
+                .rows(WindowFrameStart::UnboundedPreceding)
+        )
+    ))
+    .from(id!(employees))
+    .r#where(id!(salary).is_not_null())
+    .window(
+        define_window(
+            id!(w),
+            window()
+                .partition_by(id!(dept))
+                .order_by(id!(salary).desc()),
+        )
+        .add_window_definition(define_window(id!(v), window())),
+    );
+
+    assert_eq!(
+        stmt.to_string(),
+        "SELECT name, RANK() OVER w AS position, LAG(salary) OVER (w ROWS UNBOUNDED PRECEDING) FROM employees WHERE salary IS NOT NULL WINDOW w AS (PARTITION BY dept ORDER BY salary DESC), v AS ()"
+    );
+}
+
+#[test]
+fn test_window_function_arguments() {
+    let stmt = select(lead(id!(price)).default_value(bind(0)).over(window()))
+        .from(id!(prices))
+        .r#where(id!(day).is_not_null());
+
+    let (sql, args) = ToQuery::<Any>::to_query(&stmt).unwrap();
+    assert_eq!(
+        sql,
+        "SELECT LEAD(price, 1, ?) OVER () FROM prices WHERE day IS NOT NULL"
+    );
+    assert_eq!(args.len(), 1);
+}